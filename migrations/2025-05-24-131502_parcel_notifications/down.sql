@@ -0,0 +1,4 @@
+ALTER TABLE parcels
+    DROP COLUMN reminder_sent_at,
+    DROP COLUMN flagged_for_return,
+    DROP COLUMN flagged_for_return_at;
@@ -0,0 +1,4 @@
+ALTER TABLE parcels
+    ADD COLUMN reminder_sent_at TIMESTAMP,
+    ADD COLUMN flagged_for_return BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN flagged_for_return_at TIMESTAMP;
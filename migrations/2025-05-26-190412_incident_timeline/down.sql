@@ -0,0 +1,5 @@
+DROP TABLE incident_status_history;
+DROP TABLE incident_comments;
+
+ALTER TABLE incidents
+    DROP COLUMN assignee_id;
\ No newline at end of file
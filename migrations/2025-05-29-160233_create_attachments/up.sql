@@ -0,0 +1,13 @@
+CREATE TABLE attachments (
+    id UUID PRIMARY KEY,
+    entity_type VARCHAR(30) NOT NULL,
+    entity_id UUID NOT NULL,
+    file_name TEXT NOT NULL,
+    content_type VARCHAR(100) NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    storage_key TEXT NOT NULL,
+    uploaded_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX attachments_entity_idx ON attachments (entity_type, entity_id);
\ No newline at end of file
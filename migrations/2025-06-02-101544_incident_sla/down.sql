@@ -0,0 +1,8 @@
+ALTER TABLE incidents
+    DROP COLUMN resolution_sla_breached,
+    DROP COLUMN response_sla_breached,
+    DROP COLUMN response_date,
+    DROP COLUMN priority,
+    DROP COLUMN category_id;
+
+DROP TABLE incident_categories;
\ No newline at end of file
@@ -0,0 +1,15 @@
+CREATE TABLE incident_categories (
+    id UUID PRIMARY KEY,
+    community_id UUID NOT NULL REFERENCES communities(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    response_sla_hours INTEGER NOT NULL,
+    resolution_sla_hours INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+ALTER TABLE incidents
+    ADD COLUMN category_id UUID REFERENCES incident_categories(id) ON DELETE SET NULL,
+    ADD COLUMN priority VARCHAR(20) NOT NULL DEFAULT 'Medium',
+    ADD COLUMN response_date TIMESTAMP,
+    ADD COLUMN response_sla_breached BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN resolution_sla_breached BOOLEAN NOT NULL DEFAULT FALSE;
\ No newline at end of file
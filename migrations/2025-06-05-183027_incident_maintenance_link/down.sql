@@ -0,0 +1,2 @@
+ALTER TABLE maintenance_schedules
+    DROP COLUMN incident_id;
\ No newline at end of file
@@ -0,0 +1,2 @@
+ALTER TABLE maintenance_schedules
+    ADD COLUMN incident_id UUID UNIQUE REFERENCES incidents(id) ON DELETE SET NULL;
\ No newline at end of file
@@ -0,0 +1,4 @@
+ALTER TABLE maintenance_schedules
+    DROP COLUMN plan_id;
+
+DROP TABLE maintenance_plans;
\ No newline at end of file
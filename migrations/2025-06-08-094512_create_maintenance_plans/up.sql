@@ -0,0 +1,17 @@
+CREATE TABLE maintenance_plans (
+    id UUID PRIMARY KEY,
+    community_id UUID NOT NULL REFERENCES communities(id) ON DELETE CASCADE,
+    description TEXT NOT NULL,
+    details TEXT,
+    frequency VARCHAR(20) NOT NULL,
+    frequency_interval INTEGER NOT NULL DEFAULT 1,
+    start_date TIMESTAMP NOT NULL,
+    end_date TIMESTAMP,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    occurrence_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+ALTER TABLE maintenance_schedules
+    ADD COLUMN plan_id UUID REFERENCES maintenance_plans(id) ON DELETE SET NULL;
\ No newline at end of file
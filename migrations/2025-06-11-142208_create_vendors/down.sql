@@ -0,0 +1,10 @@
+ALTER TABLE maintenance_plans
+    DROP COLUMN vendor_id;
+
+ALTER TABLE maintenance_schedules
+    DROP COLUMN completed_at,
+    DROP COLUMN invoice_number,
+    DROP COLUMN cost,
+    DROP COLUMN vendor_id;
+
+DROP TABLE vendors;
\ No newline at end of file
@@ -0,0 +1,24 @@
+CREATE TABLE vendors (
+    id UUID PRIMARY KEY,
+    community_id UUID NOT NULL REFERENCES communities(id) ON DELETE CASCADE,
+    company_name TEXT NOT NULL,
+    contact_name TEXT,
+    email TEXT,
+    phone TEXT,
+    service_type TEXT NOT NULL,
+    contract_start_date DATE,
+    contract_end_date DATE,
+    insurance_expiry_date DATE,
+    notes TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+ALTER TABLE maintenance_schedules
+    ADD COLUMN vendor_id UUID REFERENCES vendors(id) ON DELETE SET NULL,
+    ADD COLUMN cost NUMERIC(10, 2),
+    ADD COLUMN invoice_number TEXT,
+    ADD COLUMN completed_at TIMESTAMP;
+
+ALTER TABLE maintenance_plans
+    ADD COLUMN vendor_id UUID REFERENCES vendors(id) ON DELETE SET NULL;
\ No newline at end of file
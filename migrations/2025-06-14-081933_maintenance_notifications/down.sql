@@ -0,0 +1,2 @@
+ALTER TABLE maintenance_schedules
+    DROP COLUMN reminder_sent_at;
\ No newline at end of file
@@ -0,0 +1,2 @@
+ALTER TABLE maintenance_schedules
+    ADD COLUMN reminder_sent_at TIMESTAMP;
\ No newline at end of file
@@ -0,0 +1,5 @@
+ALTER TABLE vehicles
+    DROP COLUMN authorized,
+    DROP COLUMN parking_spot_id;
+
+DROP TABLE parking_spots;
\ No newline at end of file
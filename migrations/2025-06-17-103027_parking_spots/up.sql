@@ -0,0 +1,17 @@
+CREATE TABLE parking_spots (
+    id UUID PRIMARY KEY,
+    community_id UUID NOT NULL REFERENCES communities(id) ON DELETE CASCADE,
+    code VARCHAR(20) NOT NULL,
+    unit_number VARCHAR(20),
+    notes TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (community_id, code)
+);
+
+ALTER TABLE vehicles
+    ADD COLUMN parking_spot_id UUID REFERENCES parking_spots(id) ON DELETE SET NULL,
+    ADD COLUMN authorized BOOLEAN NOT NULL DEFAULT TRUE;
+
+UPDATE vehicles
+    SET license_plate = upper(regexp_replace(license_plate, '[^A-Za-z0-9]', '', 'g'));
\ No newline at end of file
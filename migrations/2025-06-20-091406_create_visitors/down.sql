@@ -0,0 +1 @@
+DROP TABLE visitors;
\ No newline at end of file
@@ -0,0 +1,18 @@
+CREATE TABLE visitors (
+    id UUID PRIMARY KEY,
+    resident_id UUID NOT NULL REFERENCES residents(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    document VARCHAR(30) NOT NULL,
+    license_plate VARCHAR(20),
+    expected_from TIMESTAMP NOT NULL,
+    expected_until TIMESTAMP NOT NULL,
+    access_code VARCHAR(8) NOT NULL,
+    status TEXT NOT NULL DEFAULT 'Expected',
+    checked_in_at TIMESTAMP,
+    checked_in_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    checked_out_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX visitors_pending_access_code ON visitors (access_code) WHERE status = 'Expected';
\ No newline at end of file
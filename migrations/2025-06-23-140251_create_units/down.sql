@@ -0,0 +1,27 @@
+ALTER TABLE residents
+    ADD COLUMN unit_number VARCHAR(20);
+
+UPDATE residents
+    SET unit_number = linked.number
+    FROM (
+        SELECT DISTINCT ON (unit_residents.resident_id) unit_residents.resident_id, units.number
+        FROM unit_residents
+        JOIN units ON units.id = unit_residents.unit_id
+        ORDER BY unit_residents.resident_id, unit_residents.created_at
+    ) AS linked
+    WHERE linked.resident_id = residents.id;
+
+ALTER TABLE parking_spots
+    ADD COLUMN unit_number VARCHAR(20);
+
+UPDATE parking_spots
+    SET unit_number = units.number
+    FROM units
+    WHERE units.id = parking_spots.unit_id;
+
+ALTER TABLE parking_spots
+    DROP COLUMN unit_id;
+
+DROP TABLE unit_residents;
+
+DROP TABLE units;
\ No newline at end of file
@@ -0,0 +1,63 @@
+CREATE TABLE units (
+    id UUID PRIMARY KEY,
+    community_id UUID NOT NULL REFERENCES communities(id) ON DELETE CASCADE,
+    block VARCHAR(20),
+    number VARCHAR(20) NOT NULL,
+    ideal_fraction NUMERIC(9, 6) NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX units_community_block_number ON units (community_id, COALESCE(block, ''), number);
+
+CREATE TABLE unit_residents (
+    id UUID PRIMARY KEY,
+    unit_id UUID NOT NULL REFERENCES units(id) ON DELETE CASCADE,
+    resident_id UUID NOT NULL REFERENCES residents(id) ON DELETE CASCADE,
+    relation TEXT NOT NULL DEFAULT 'Owner',
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (unit_id, resident_id)
+);
+
+-- Every distinct unit number already in use becomes a unit of its community, and the residents
+-- that declared it are linked to it as owners.
+INSERT INTO units (id, community_id, number)
+SELECT gen_random_uuid(), community_id, number
+FROM (
+    SELECT DISTINCT user_roles.community_id, trim(residents.unit_number) AS number
+    FROM residents
+    JOIN users ON users.entity_id = residents.id
+    JOIN user_roles ON user_roles.user_id = users.id
+    WHERE user_roles.community_id IS NOT NULL
+        AND trim(coalesce(residents.unit_number, '')) <> ''
+    UNION
+    SELECT DISTINCT community_id, trim(unit_number) AS number
+    FROM parking_spots
+    WHERE trim(coalesce(unit_number, '')) <> ''
+) AS existing_units;
+
+INSERT INTO unit_residents (id, unit_id, resident_id, relation)
+SELECT gen_random_uuid(), units.id, residents.id, 'Owner'
+FROM residents
+JOIN users ON users.entity_id = residents.id
+JOIN user_roles ON user_roles.user_id = users.id
+JOIN units ON units.community_id = user_roles.community_id
+    AND units.block IS NULL
+    AND units.number = trim(residents.unit_number)
+ON CONFLICT DO NOTHING;
+
+ALTER TABLE parking_spots
+    ADD COLUMN unit_id UUID REFERENCES units(id) ON DELETE SET NULL;
+
+UPDATE parking_spots
+    SET unit_id = units.id
+    FROM units
+    WHERE units.community_id = parking_spots.community_id
+        AND units.block IS NULL
+        AND units.number = trim(parking_spots.unit_number);
+
+ALTER TABLE parking_spots
+    DROP COLUMN unit_number;
+
+ALTER TABLE residents
+    DROP COLUMN unit_number;
\ No newline at end of file
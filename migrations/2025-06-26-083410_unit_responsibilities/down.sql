@@ -0,0 +1,18 @@
+ALTER TABLE announcements
+    DROP COLUMN audience;
+
+ALTER TABLE invoices
+    DROP COLUMN charge_type,
+    DROP COLUMN unit_id;
+
+DELETE FROM unit_residents a
+    USING unit_residents b
+    WHERE a.unit_id = b.unit_id
+        AND a.resident_id = b.resident_id
+        AND a.created_at < b.created_at;
+
+ALTER TABLE unit_residents
+    DROP COLUMN voting_proxy,
+    DROP COLUMN end_date,
+    DROP COLUMN start_date,
+    ADD CONSTRAINT unit_residents_unit_id_resident_id_key UNIQUE (unit_id, resident_id);
\ No newline at end of file
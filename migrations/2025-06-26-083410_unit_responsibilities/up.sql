@@ -0,0 +1,15 @@
+ALTER TABLE unit_residents
+    DROP CONSTRAINT unit_residents_unit_id_resident_id_key,
+    ADD COLUMN start_date DATE NOT NULL DEFAULT CURRENT_DATE,
+    ADD COLUMN end_date DATE,
+    ADD COLUMN voting_proxy BOOLEAN NOT NULL DEFAULT FALSE;
+
+UPDATE unit_residents
+    SET start_date = created_at::date;
+
+ALTER TABLE invoices
+    ADD COLUMN unit_id UUID REFERENCES units(id) ON DELETE SET NULL,
+    ADD COLUMN charge_type TEXT NOT NULL DEFAULT 'Ordinary';
+
+ALTER TABLE announcements
+    ADD COLUMN audience TEXT NOT NULL DEFAULT 'AllResidents';
\ No newline at end of file
@@ -0,0 +1,7 @@
+DROP TABLE resident_checklist_items;
+
+UPDATE reservations SET status = 'Finished' WHERE status = 'Cancelled';
+
+ALTER TABLE residents
+    DROP COLUMN moved_out_at,
+    DROP COLUMN moved_in_at;
\ No newline at end of file
@@ -0,0 +1,16 @@
+ALTER TABLE residents
+    ADD COLUMN moved_in_at DATE,
+    ADD COLUMN moved_out_at DATE;
+
+UPDATE residents SET moved_in_at = created_at::DATE;
+
+CREATE TABLE resident_checklist_items (
+    id UUID PRIMARY KEY,
+    resident_id UUID NOT NULL REFERENCES residents(id) ON DELETE CASCADE,
+    item VARCHAR(150) NOT NULL,
+    completed_at TIMESTAMP,
+    completed_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX resident_checklist_items_resident_id ON resident_checklist_items (resident_id);
\ No newline at end of file
@@ -0,0 +1,9 @@
+DELETE FROM resident_invites WHERE status = 'Accepted';
+
+DROP INDEX resident_invites_email;
+
+ALTER TABLE resident_invites
+    DROP COLUMN accepted_at,
+    DROP COLUMN opened_at,
+    DROP COLUMN expires_at,
+    DROP COLUMN status;
\ No newline at end of file
@@ -0,0 +1,9 @@
+ALTER TABLE resident_invites
+    ADD COLUMN status TEXT NOT NULL DEFAULT 'Sent',
+    ADD COLUMN expires_at TIMESTAMP NOT NULL DEFAULT (CURRENT_TIMESTAMP + INTERVAL '7 days'),
+    ADD COLUMN opened_at TIMESTAMP,
+    ADD COLUMN accepted_at TIMESTAMP;
+
+UPDATE resident_invites SET expires_at = created_at + INTERVAL '7 days';
+
+CREATE INDEX resident_invites_email ON resident_invites (email);
\ No newline at end of file
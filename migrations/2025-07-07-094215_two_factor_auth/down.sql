@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS auth_challenges;
+DROP TABLE IF EXISTS user_recovery_codes;
+DROP TABLE IF EXISTS user_totp;
+
+ALTER TABLE communities DROP COLUMN IF EXISTS require_admin_2fa;
\ No newline at end of file
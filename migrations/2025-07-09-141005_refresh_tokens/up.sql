@@ -0,0 +1,10 @@
+CREATE TABLE refresh_tokens (
+    id UUID PRIMARY KEY,
+    auth_token_id UUID NOT NULL REFERENCES auth_tokens(id) ON DELETE CASCADE,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    used_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX idx_refresh_tokens_auth_token_id ON refresh_tokens(auth_token_id);
\ No newline at end of file
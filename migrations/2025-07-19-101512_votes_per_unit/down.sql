@@ -0,0 +1,4 @@
+ALTER TABLE votes
+    DROP CONSTRAINT votes_election_id_unit_id_key,
+    DROP COLUMN unit_id,
+    ADD CONSTRAINT votes_election_id_resident_id_key UNIQUE (election_id, resident_id);
\ No newline at end of file
@@ -0,0 +1,4 @@
+ALTER TABLE votes
+    DROP CONSTRAINT votes_election_id_resident_id_key,
+    ADD COLUMN unit_id UUID REFERENCES units(id) ON DELETE SET NULL,
+    ADD CONSTRAINT votes_election_id_unit_id_key UNIQUE (election_id, unit_id);
\ No newline at end of file
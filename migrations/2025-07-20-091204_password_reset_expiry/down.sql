@@ -0,0 +1 @@
+ALTER TABLE password_reset DROP COLUMN expires_at;
\ No newline at end of file
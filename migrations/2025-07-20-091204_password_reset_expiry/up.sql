@@ -0,0 +1,5 @@
+ALTER TABLE password_reset ADD COLUMN expires_at TIMESTAMP;
+
+UPDATE password_reset SET expires_at = created_at + INTERVAL '15 minutes';
+
+ALTER TABLE password_reset ALTER COLUMN expires_at SET NOT NULL;
\ No newline at end of file
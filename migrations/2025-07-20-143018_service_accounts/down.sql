@@ -0,0 +1,20 @@
+DELETE FROM users WHERE entity_type = 'service';
+
+ALTER TABLE users
+    DROP CONSTRAINT users_entity_type_check,
+    DROP CONSTRAINT users_entity_one_type_only,
+    DROP CONSTRAINT users_entity_id_matches,
+    DROP COLUMN service_account_id,
+    ADD CONSTRAINT users_entity_type_check CHECK (entity_type IN ('admin', 'resident')),
+    ADD CONSTRAINT users_entity_one_type_only CHECK (
+        (entity_type = 'admin' AND admin_id IS NOT NULL AND resident_id IS NULL)
+            OR
+        (entity_type = 'resident' AND resident_id IS NOT NULL AND admin_id IS NULL)
+    ),
+    ADD CONSTRAINT users_entity_id_matches CHECK (
+        (entity_type = 'admin' AND entity_id = admin_id)
+            OR
+        (entity_type = 'resident' AND entity_id = resident_id)
+    );
+
+DROP TABLE service_accounts;
\ No newline at end of file
@@ -0,0 +1,29 @@
+CREATE TABLE service_accounts (
+    id UUID PRIMARY KEY,
+    community_id UUID NOT NULL REFERENCES communities(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX service_accounts_community_id_idx ON service_accounts (community_id);
+
+ALTER TABLE users
+    ADD COLUMN service_account_id UUID REFERENCES service_accounts(id) ON DELETE CASCADE,
+    DROP CONSTRAINT users_entity_type_check,
+    DROP CONSTRAINT users_entity_one_type_only,
+    DROP CONSTRAINT users_entity_id_matches,
+    ADD CONSTRAINT users_entity_type_check CHECK (entity_type IN ('admin', 'resident', 'service')),
+    ADD CONSTRAINT users_entity_one_type_only CHECK (
+        (entity_type = 'admin' AND admin_id IS NOT NULL AND resident_id IS NULL AND service_account_id IS NULL)
+            OR
+        (entity_type = 'resident' AND resident_id IS NOT NULL AND admin_id IS NULL AND service_account_id IS NULL)
+            OR
+        (entity_type = 'service' AND service_account_id IS NOT NULL AND admin_id IS NULL AND resident_id IS NULL)
+    ),
+    ADD CONSTRAINT users_entity_id_matches CHECK (
+        (entity_type = 'admin' AND entity_id = admin_id)
+            OR
+        (entity_type = 'resident' AND entity_id = resident_id)
+            OR
+        (entity_type = 'service' AND entity_id = service_account_id)
+    );
\ No newline at end of file
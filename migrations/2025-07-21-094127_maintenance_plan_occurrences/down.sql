@@ -0,0 +1 @@
+DROP INDEX maintenance_schedules_plan_id_scheduled_date_key;
\ No newline at end of file
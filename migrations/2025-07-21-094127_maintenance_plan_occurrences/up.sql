@@ -0,0 +1,10 @@
+-- Occurrences scheduled twice by concurrent runs, keeping the first one created.
+DELETE FROM maintenance_schedules duplicate
+    USING maintenance_schedules original
+    WHERE duplicate.plan_id = original.plan_id
+        AND duplicate.scheduled_date = original.scheduled_date
+        AND duplicate.status = 'Scheduled'
+        AND (duplicate.created_at, duplicate.id) > (original.created_at, original.id);
+
+CREATE UNIQUE INDEX maintenance_schedules_plan_id_scheduled_date_key
+    ON maintenance_schedules (plan_id, scheduled_date);
\ No newline at end of file
@@ -19,6 +19,31 @@ pub struct MyCondominiumConfig {
 
     #[serde(rename = "rabbitmq")]
     pub rabbitmq: ConfigRabbitmq,
+
+    #[serde(rename = "parcel", default)]
+    pub parcel: ConfigParcel,
+
+    #[serde(rename = "attachment", default)]
+    pub attachment: ConfigAttachment,
+
+    #[serde(rename = "maintenance", default)]
+    pub maintenance: ConfigMaintenance,
+
+    #[serde(rename = "invite", default)]
+    pub invite: ConfigInvite,
+
+    #[serde(rename = "lockout", default)]
+    pub lockout: ConfigLockout,
+
+    #[serde(rename = "password_policy", default)]
+    pub password_policy: ConfigPasswordPolicy,
+
+    #[serde(rename = "magic_link", default)]
+    pub magic_link: ConfigMagicLink,
+
+    /// External identity providers users can sign in with.
+    #[serde(rename = "oidc", default)]
+    pub oidc: Vec<ConfigOidcProvider>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -42,6 +67,18 @@ pub struct ConfigMinio {
     pub bucket_name: String,
     pub access_key: String,
     pub secret_key: String,
+    #[serde(default = "default_minio_region")]
+    pub region: String,
+    /// Stores objects in this local directory instead of MinIO when set.
+    #[serde(default)]
+    pub local_path: Option<String>,
+    /// Allows a plain `http://` endpoint, for a MinIO reachable only on a private network.
+    #[serde(default)]
+    pub allow_http: bool,
+}
+
+fn default_minio_region() -> String {
+    "us-east-1".to_string()
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -53,9 +90,41 @@ pub struct ConfigServer {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ConfigAuth {
+    /// Days a session can be kept alive with refresh tokens without signing in again.
     pub token_expiration_days: u32,
     #[serde(rename = "token_secret_key")]
     pub token_secret_key: String,
+    /// Minutes an access token is valid before it has to be refreshed.
+    #[serde(default = "default_access_token_expiration_minutes")]
+    pub access_token_expiration_minutes: u32,
+    /// Key that signs new tokens. Tokens are signed with HS256 and `token_secret_key` when unset.
+    #[serde(default)]
+    pub active_key_id: Option<String>,
+    /// Keys accepted when verifying tokens: the active key and the ones it replaced.
+    #[serde(default)]
+    pub signing_keys: Vec<ConfigSigningKey>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigSigningAlgorithm {
+    RS256,
+    EdDSA,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigSigningKey {
+    /// Identifier sent in the `kid` header of the tokens it signs.
+    pub kid: String,
+    pub algorithm: ConfigSigningAlgorithm,
+    /// PEM file with the public key.
+    pub public_key_path: String,
+    /// PEM file with the PKCS#8 private key, only needed for the active key.
+    #[serde(default)]
+    pub private_key_path: Option<String>,
+}
+
+fn default_access_token_expiration_minutes() -> u32 {
+    15
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -65,3 +134,181 @@ pub struct ConfigRabbitmq {
     pub username: String,
     pub password: String,
 }
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigParcel {
+    /// Days a parcel may wait at the front desk before the resident is reminded.
+    pub reminder_days: u32,
+    /// Days a parcel may wait at the front desk before it is flagged for return.
+    pub return_days: u32,
+}
+
+impl Default for ConfigParcel {
+    fn default() -> Self {
+        ConfigParcel {
+            reminder_days: 3,
+            return_days: 15,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigAttachment {
+    pub max_size_bytes: usize,
+    pub allowed_content_types: Vec<String>,
+    /// Lifetime of presigned download URLs. Downloads are proxied through the API when zero.
+    pub presigned_url_expiration_secs: u64,
+}
+
+impl Default for ConfigAttachment {
+    fn default() -> Self {
+        ConfigAttachment {
+            max_size_bytes: 10 * 1024 * 1024,
+            allowed_content_types: vec![
+                "image/jpeg".to_string(),
+                "image/png".to_string(),
+                "image/webp".to_string(),
+                "application/pdf".to_string(),
+            ],
+            presigned_url_expiration_secs: 300,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigMaintenance {
+    /// How far ahead recurring maintenance plans are turned into scheduled maintenance.
+    pub plan_lookahead_days: u32,
+}
+
+impl Default for ConfigMaintenance {
+    fn default() -> Self {
+        ConfigMaintenance {
+            plan_lookahead_days: 30,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigInvite {
+    /// Days a resident invitation stays valid after it is sent or resent.
+    pub expiration_days: u32,
+    /// Hours the link to set the password of an imported resident's account stays valid.
+    #[serde(default = "default_account_link_expiration_hours")]
+    pub account_link_expiration_hours: u32,
+}
+
+impl Default for ConfigInvite {
+    fn default() -> Self {
+        ConfigInvite {
+            expiration_days: 7,
+            account_link_expiration_hours: default_account_link_expiration_hours(),
+        }
+    }
+}
+
+fn default_account_link_expiration_hours() -> u32 {
+    48
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigLockout {
+    /// Consecutive wrong passwords before an account is locked.
+    pub max_failed_attempts: u32,
+    /// Length of the first lockout, doubled on each lockout that follows.
+    pub lockout_minutes: u32,
+    /// Upper bound for the lockout length.
+    pub max_lockout_minutes: u32,
+    /// Failed sign-ins from a single IP, across all accounts, before it is throttled.
+    pub ip_max_failed_attempts: u32,
+    /// Window in which failed sign-ins from an IP are counted.
+    pub ip_window_minutes: u32,
+    /// Takes the client IP from `X-Forwarded-For`, only safe behind a trusted reverse proxy.
+    pub trust_forwarded_for: bool,
+}
+
+impl Default for ConfigLockout {
+    fn default() -> Self {
+        ConfigLockout {
+            max_failed_attempts: 5,
+            lockout_minutes: 15,
+            max_lockout_minutes: 24 * 60,
+            ip_max_failed_attempts: 20,
+            ip_window_minutes: 15,
+            trust_forwarded_for: false,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigPasswordPolicy {
+    pub min_length: usize,
+    pub max_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    /// Rejects passwords containing the user's name or the local part of their email.
+    pub reject_personal_info: bool,
+    /// Rejects passwords found in the bundled list of common passwords.
+    pub reject_common: bool,
+    /// Previous passwords, the current one included, that can't be reused. Zero disables the check.
+    pub history_count: usize,
+}
+
+impl Default for ConfigPasswordPolicy {
+    fn default() -> Self {
+        ConfigPasswordPolicy {
+            min_length: 8,
+            max_length: 128,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_digit: false,
+            require_symbol: false,
+            reject_personal_info: true,
+            reject_common: true,
+            history_count: 5,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigOidcProvider {
+    /// Identifier used in the login routes, e.g. `google`.
+    pub name: String,
+    /// Issuer URL, from which the provider metadata is discovered.
+    pub issuer: String,
+    pub client_id: String,
+    /// Not needed by public clients, which rely on PKCE alone.
+    #[serde(default)]
+    pub client_secret: Option<String>,
+    /// Frontend page the provider redirects back to with the authorization code.
+    pub redirect_uri: String,
+    #[serde(default = "default_oidc_scopes")]
+    pub scopes: Vec<String>,
+}
+
+fn default_oidc_scopes() -> Vec<String> {
+    vec![
+        "openid".to_string(),
+        "email".to_string(),
+        "profile".to_string(),
+    ]
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigMagicLink {
+    /// Lets users sign in with a link emailed to them instead of their password.
+    pub enabled: bool,
+    /// Minutes a sign-in link stays valid.
+    pub expiration_minutes: u32,
+}
+
+impl Default for ConfigMagicLink {
+    fn default() -> Self {
+        ConfigMagicLink {
+            enabled: false,
+            expiration_minutes: 15,
+        }
+    }
+}
@@ -3,4 +3,5 @@ pub mod rabbitmq;
 pub mod roles;
 pub mod scheduled_tasks;
 pub mod smtp;
+pub mod storage;
 pub mod user_types;
@@ -1,6 +1,8 @@
 use crate::establish_connection_pg;
 use crate::internal::rabbitmq::rabbitmq_client::RabbitMqClient;
+use crate::internal::smtp::smtp_client::SmtpEmailPayload;
 use crate::internal::smtp::smtp_service::listen_and_send_emails;
+use crate::internal::smtp::smtp_templates::{SmtpTemplate, SmtpTemplateData};
 use crate::models::auth_model::PasswordResetModel;
 use crate::services::{DatabaseTrait, MyCondominiumConfig};
 use diesel::prelude::*;
@@ -21,7 +23,17 @@ pub async fn scheduled_tasks_service(conf: Arc<MyCondominiumConfig>) {
     let smtp_config = conf.clone().smtp.clone();
     tokio::spawn(listen_and_send_emails(rabbitmq_conn_smtp, smtp_config));
     tokio::spawn(delete_expired_password_resets(conf.clone()));
-    tokio::spawn(update_reservation_status(conf));
+    tokio::spawn(delete_expired_auth_challenges(conf.clone()));
+    tokio::spawn(delete_expired_sessions(conf.clone()));
+    tokio::spawn(delete_old_sign_in_attempts(conf.clone()));
+    tokio::spawn(delete_expired_email_changes(conf.clone()));
+    tokio::spawn(expire_resident_invites(conf.clone()));
+    tokio::spawn(update_reservation_status(conf.clone()));
+    tokio::spawn(notify_pending_parcels(conf.clone()));
+    tokio::spawn(flag_incident_sla_breaches(conf.clone()));
+    tokio::spawn(materialise_maintenance_plans(conf.clone()));
+    tokio::spawn(remind_upcoming_maintenance(conf.clone()));
+    tokio::spawn(delete_orphaned_attachments(conf));
 }
 
 async fn delete_expired_password_resets(conf: Arc<MyCondominiumConfig>) {
@@ -31,10 +43,9 @@ async fn delete_expired_password_resets(conf: Arc<MyCondominiumConfig>) {
 
     loop {
         let now = chrono::Utc::now().naive_utc();
-        let cutoff_time = now - chrono::Duration::minutes(15);
 
         match diesel::delete(PasswordResetModel::table())
-            .filter(crate::schema::password_reset::created_at.lt(cutoff_time))
+            .filter(crate::schema::password_reset::expires_at.lt(now))
             .execute(conn)
         {
             Ok(count) => {
@@ -57,6 +68,133 @@ async fn delete_expired_password_resets(conf: Arc<MyCondominiumConfig>) {
     }
 }
 
+async fn delete_expired_auth_challenges(conf: Arc<MyCondominiumConfig>) {
+    use crate::models::auth_model::MagicLinkModel;
+    use crate::models::oidc_model::OidcStateModel;
+    use crate::models::two_factor_model::AuthChallengeModel;
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    log::info!("Starting expired sign-in challenge cleanup service");
+
+    loop {
+        match AuthChallengeModel::db_delete_expired(conn) {
+            Ok(count) => {
+                if count > 0 {
+                    log::info!("Deleted {} expired sign-in challenges", count);
+                } else {
+                    log::debug!("No expired sign-in challenges found to delete");
+                }
+            }
+            Err(e) => {
+                log::error!("Failed to delete expired sign-in challenges: {}", e);
+            }
+        }
+
+        match MagicLinkModel::db_delete_expired(conn) {
+            Ok(count) if count > 0 => log::info!("Deleted {} expired magic links", count),
+            Ok(_) => log::debug!("No expired magic links found to delete"),
+            Err(e) => log::error!("Failed to delete expired magic links: {}", e),
+        }
+
+        match OidcStateModel::db_delete_expired(conn) {
+            Ok(count) if count > 0 => log::info!("Deleted {} expired OIDC logins", count),
+            Ok(_) => log::debug!("No expired OIDC logins found to delete"),
+            Err(e) => log::error!("Failed to delete expired OIDC logins: {}", e),
+        }
+
+        log::debug!("Sign-in challenge cleanup complete, next run in 60 seconds");
+        sleep(Duration::from_secs(60)).await;
+    }
+}
+
+async fn delete_expired_sessions(conf: Arc<MyCondominiumConfig>) {
+    use crate::models::auth_token_model::{AuthTokenModel, RefreshTokenModel};
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    log::info!("Starting expired session cleanup service");
+
+    loop {
+        match AuthTokenModel::db_delete_idle(conn, conf.auth.token_expiration_days as i64) {
+            Ok(count) if count > 0 => log::info!("Deleted {} idle sessions", count),
+            Ok(_) => log::debug!("No idle sessions found to delete"),
+            Err(e) => log::error!("Failed to delete idle sessions: {}", e),
+        }
+
+        match RefreshTokenModel::db_delete_expired(conn) {
+            Ok(count) if count > 0 => log::info!("Deleted {} expired refresh tokens", count),
+            Ok(_) => log::debug!("No expired refresh tokens found to delete"),
+            Err(e) => log::error!("Failed to delete expired refresh tokens: {}", e),
+        }
+
+        log::debug!("Session cleanup complete, next run in 1 hour");
+        sleep(Duration::from_secs(3600)).await;
+    }
+}
+
+async fn delete_old_sign_in_attempts(conf: Arc<MyCondominiumConfig>) {
+    use crate::models::sign_in_attempt_model::SignInAttemptModel;
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    log::info!("Starting sign-in attempt cleanup service");
+
+    loop {
+        match SignInAttemptModel::db_delete_old(conn) {
+            Ok(count) if count > 0 => log::info!("Deleted {} old sign-in attempts", count),
+            Ok(_) => log::debug!("No old sign-in attempts found to delete"),
+            Err(e) => log::error!("Failed to delete old sign-in attempts: {}", e),
+        }
+
+        sleep(Duration::from_secs(3600)).await;
+    }
+}
+
+async fn delete_expired_email_changes(conf: Arc<MyCondominiumConfig>) {
+    use crate::models::auth_model::EmailChangeModel;
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    log::info!("Starting expired email change cleanup service");
+
+    loop {
+        match EmailChangeModel::db_delete_expired(conn) {
+            Ok(count) if count > 0 => log::info!("Deleted {} expired email changes", count),
+            Ok(_) => log::debug!("No expired email changes found to delete"),
+            Err(e) => log::error!("Failed to delete expired email changes: {}", e),
+        }
+
+        sleep(Duration::from_secs(3600)).await;
+    }
+}
+
+async fn expire_resident_invites(conf: Arc<MyCondominiumConfig>) {
+    use crate::models::resident_model::ResidentInviteModel;
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    log::info!("Starting resident invite expiry service");
+
+    loop {
+        match ResidentInviteModel::db_expire_all(conn) {
+            Ok(count) => {
+                if count > 0 {
+                    log::info!("Marked {} resident invites as expired", count);
+                } else {
+                    log::debug!("No expired resident invites found");
+                }
+            }
+            Err(e) => {
+                log::error!("Failed to expire resident invites: {}", e);
+            }
+        }
+
+        log::debug!("Resident invite expiry complete, next run in 60 seconds");
+        sleep(Duration::from_secs(60)).await;
+    }
+}
+
 async fn update_reservation_status(conf: Arc<MyCondominiumConfig>) {
     use crate::models::reservation_model::*;
     use crate::schema::reservations::dsl::*;
@@ -190,3 +328,325 @@ async fn update_reservation_status(conf: Arc<MyCondominiumConfig>) {
         sleep(Duration::from_secs(10)).await;
     }
 }
+
+async fn notify_pending_parcels(conf: Arc<MyCondominiumConfig>) {
+    use crate::models::parcel_model::ParcelModel;
+    use crate::models::resident_model::ResidentModel;
+    use crate::schema::parcels::dsl::*;
+    use chrono::Utc;
+
+    log::info!("Starting pending parcel notification service");
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    let rmq = match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+        Ok(rmq) => rmq,
+        Err(e) => {
+            log::error!("Failed to instantiate connection with RabbitMQ: {}", e);
+            return;
+        }
+    };
+
+    loop {
+        let now = Utc::now().naive_utc();
+        let reminder_cutoff = now - chrono::Duration::days(conf.parcel.reminder_days as i64);
+        let return_cutoff = now - chrono::Duration::days(conf.parcel.return_days as i64);
+
+        let to_return = parcels
+            .filter(received.eq(false))
+            .filter(flagged_for_return.eq(false))
+            .filter(arrival_date.le(return_cutoff))
+            .load::<ParcelModel>(conn);
+
+        match to_return {
+            Ok(to_update) => {
+                for mut parcel in to_update {
+                    parcel.flagged_for_return = true;
+                    parcel.flagged_for_return_at = Some(now);
+
+                    if let Err(e) = parcel.db_update(conn) {
+                        log::error!("Failed to flag parcel {} for return: {}", parcel.id, e);
+                        continue;
+                    }
+
+                    log::info!("Flagged parcel {} for return", parcel.id);
+
+                    match ResidentModel::db_read_by_id(conn, parcel.resident_id) {
+                        Ok(resident) => {
+                            let email = SmtpEmailPayload::from_template(
+                                resident.email.clone(),
+                                "Devolução de Encomenda - MyCondominium".to_string(),
+                                SmtpTemplate::ParcelReturn,
+                                parcel.email_parameters(&resident),
+                            );
+
+                            if let Err(e) = email.publish(&rmq).await {
+                                log::error!(
+                                    "Failed to queue return email for parcel {}: {}",
+                                    parcel.id,
+                                    e
+                                );
+                            }
+                        }
+                        Err(e) => {
+                            log::error!("Failed to read resident of parcel {}: {}", parcel.id, e);
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                log::error!("Error fetching parcels to flag for return: {}", e);
+            }
+        }
+
+        let to_remind = parcels
+            .filter(received.eq(false))
+            .filter(flagged_for_return.eq(false))
+            .filter(reminder_sent_at.is_null())
+            .filter(arrival_date.le(reminder_cutoff))
+            .load::<ParcelModel>(conn);
+
+        match to_remind {
+            Ok(to_update) => {
+                for mut parcel in to_update {
+                    let resident = match ResidentModel::db_read_by_id(conn, parcel.resident_id) {
+                        Ok(resident) => resident,
+                        Err(e) => {
+                            log::error!("Failed to read resident of parcel {}: {}", parcel.id, e);
+                            continue;
+                        }
+                    };
+
+                    let mut parameters = parcel.email_parameters(&resident);
+                    parameters.push(SmtpTemplateData {
+                        key: "{{RETURN_DAYS}}".to_string(),
+                        value: conf.parcel.return_days.to_string(),
+                    });
+
+                    let email = SmtpEmailPayload::from_template(
+                        resident.email,
+                        "Lembrete de Encomenda - MyCondominium".to_string(),
+                        SmtpTemplate::ParcelReminder,
+                        parameters,
+                    );
+
+                    if let Err(e) = email.publish(&rmq).await {
+                        log::error!(
+                            "Failed to queue reminder email for parcel {}: {}",
+                            parcel.id,
+                            e
+                        );
+                        continue;
+                    }
+
+                    parcel.reminder_sent_at = Some(now);
+
+                    match parcel.db_update(conn) {
+                        Ok(_) => log::info!("Sent reminder for parcel {}", parcel.id),
+                        Err(e) => {
+                            log::error!("Failed to update parcel {} reminder: {}", parcel.id, e);
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                log::error!("Error fetching parcels to remind: {}", e);
+            }
+        }
+
+        log::debug!("Pending parcel notification complete, next run in 60 seconds");
+        sleep(Duration::from_secs(60)).await;
+    }
+}
+
+async fn flag_incident_sla_breaches(conf: Arc<MyCondominiumConfig>) {
+    use crate::models::incident_model::IncidentModel;
+    use chrono::Utc;
+
+    log::info!("Starting incident SLA monitoring service");
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    loop {
+        let now = Utc::now().naive_utc();
+
+        match IncidentModel::db_read_all_pending_sla(conn) {
+            Ok(pending) => {
+                for (mut incident, category) in pending {
+                    if !incident.check_sla(&category, now) {
+                        continue;
+                    }
+
+                    match incident.db_update_sla_flags(conn) {
+                        Ok(_) => log::warn!(
+                            "Incident {} breached the SLA of category {} (response: {}, resolution: {})",
+                            incident.id,
+                            category.name,
+                            incident.response_sla_breached,
+                            incident.resolution_sla_breached
+                        ),
+                        Err(e) => {
+                            log::error!(
+                                "Failed to flag SLA breach of incident {}: {}",
+                                incident.id,
+                                e
+                            );
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                log::error!("Error fetching incidents pending SLA checks: {}", e);
+            }
+        }
+
+        log::debug!("Incident SLA check complete, next run in 60 seconds");
+        sleep(Duration::from_secs(60)).await;
+    }
+}
+
+async fn materialise_maintenance_plans(conf: Arc<MyCondominiumConfig>) {
+    use crate::models::maintenance_plan_model::MaintenancePlanModel;
+    use chrono::Utc;
+
+    log::info!("Starting maintenance plan scheduling service");
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    loop {
+        let now = Utc::now().naive_utc();
+        let until = now + chrono::Duration::days(conf.maintenance.plan_lookahead_days as i64);
+
+        match MaintenancePlanModel::db_read_all_active(conn) {
+            Ok(plans) => {
+                for mut plan in plans {
+                    match plan.db_materialise_until(conn, until) {
+                        Ok(created) => {
+                            if !created.is_empty() {
+                                log::info!(
+                                    "Scheduled {} occurrences of maintenance plan {}",
+                                    created.len(),
+                                    plan.id
+                                );
+                            }
+                        }
+                        Err(e) => {
+                            log::error!("Failed to schedule maintenance plan {}: {}", plan.id, e);
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                log::error!("Error fetching active maintenance plans: {}", e);
+            }
+        }
+
+        log::debug!("Maintenance plan scheduling complete, next run in 1 hour");
+        sleep(Duration::from_secs(3600)).await;
+    }
+}
+
+async fn remind_upcoming_maintenance(conf: Arc<MyCondominiumConfig>) {
+    use crate::models::maintenance_schedule_model::MaintenanceScheduleModel;
+    use crate::services::maintenance_schedule_service::notify_maintenance_residents;
+    use chrono::Utc;
+
+    log::info!("Starting maintenance reminder service");
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    let rmq = match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+        Ok(rmq) => rmq,
+        Err(e) => {
+            log::error!("Failed to instantiate connection with RabbitMQ: {}", e);
+            return;
+        }
+    };
+
+    loop {
+        let now = Utc::now().naive_utc();
+        let until = now + chrono::Duration::days(1);
+
+        match MaintenanceScheduleModel::db_read_all_pending_reminder(conn, now, until) {
+            Ok(to_remind) => {
+                for mut maintenance in to_remind {
+                    let sent = notify_maintenance_residents(
+                        conn,
+                        &rmq,
+                        &maintenance,
+                        SmtpTemplate::MaintenanceReminder,
+                        "Lembrete de Manutenção - MyCondominium",
+                    )
+                    .await;
+
+                    maintenance.reminder_sent_at = Some(now);
+
+                    match maintenance.db_update(conn) {
+                        Ok(_) => {
+                            log::info!("Sent {} reminders for maintenance {}", sent, maintenance.id)
+                        }
+                        Err(e) => log::error!(
+                            "Failed to update maintenance {} reminder: {}",
+                            maintenance.id,
+                            e
+                        ),
+                    }
+                }
+            }
+            Err(e) => {
+                log::error!("Error fetching maintenance to remind: {}", e);
+            }
+        }
+
+        log::debug!("Maintenance reminder check complete, next run in 60 seconds");
+        sleep(Duration::from_secs(60)).await;
+    }
+}
+
+async fn delete_orphaned_attachments(conf: Arc<MyCondominiumConfig>) {
+    use crate::internal::storage::storage_client::StorageClient;
+    use crate::models::attachment_model::AttachmentModel;
+
+    log::info!("Starting orphaned attachment cleanup service");
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    let storage = match StorageClient::new(&conf.minio) {
+        Ok(storage) => storage,
+        Err(e) => {
+            log::error!("Failed to connect to storage: {}", e);
+            return;
+        }
+    };
+
+    loop {
+        match AttachmentModel::db_read_all_orphaned(conn) {
+            Ok(orphaned) => {
+                for attachment in orphaned {
+                    // The file may be gone already if a previous run stopped halfway.
+                    match storage.delete(&attachment.storage_key).await {
+                        Ok(_) | Err(object_store::Error::NotFound { .. }) => (),
+                        Err(e) => {
+                            log::error!(
+                                "Failed to delete file of attachment {}: {}",
+                                attachment.id,
+                                e
+                            );
+                            continue;
+                        }
+                    }
+
+                    if let Err(e) = AttachmentModel::db_delete_by_id(conn, attachment.id) {
+                        log::error!("Failed to delete attachment {}: {}", attachment.id, e);
+                    }
+                }
+            }
+            Err(e) => {
+                log::error!("Error fetching orphaned attachments: {}", e);
+            }
+        }
+
+        log::debug!("Orphaned attachment cleanup complete, next run in 1 hour");
+        sleep(Duration::from_secs(3600)).await;
+    }
+}
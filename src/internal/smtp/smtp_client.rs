@@ -1,3 +1,5 @@
+use crate::internal::rabbitmq::rabbitmq_client::RabbitMqClient;
+use crate::internal::smtp::smtp_templates::{SmtpTemplate, SmtpTemplateData, smtp_get_template};
 use crate::services::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -6,3 +8,25 @@ pub struct SmtpEmailPayload {
     pub subject: String,
     pub body: String,
 }
+
+impl SmtpEmailPayload {
+    /// Builds a payload whose body is `template` rendered with `data`.
+    pub fn from_template(
+        to: String,
+        subject: String,
+        template: SmtpTemplate,
+        data: Vec<SmtpTemplateData>,
+    ) -> Self {
+        SmtpEmailPayload {
+            to,
+            subject,
+            body: smtp_get_template(template, data),
+        }
+    }
+
+    /// Publishes the payload to the queue consumed by `listen_and_send_emails`.
+    pub async fn publish(&self, rabbitmq: &RabbitMqClient) -> Result<(), lapin::Error> {
+        let payload = serde_json::to_vec(self).unwrap();
+        rabbitmq.publish(&payload).await
+    }
+}
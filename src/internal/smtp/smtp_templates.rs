@@ -1,8 +1,23 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SmtpTemplate {
     ResidentInvite,
     NewAccount,
     PasswordReset,
     PasswordResetWarning,
+    ParcelArrival,
+    ParcelReminder,
+    ParcelReturn,
+    IncidentStatusChanged,
+    MaintenanceScheduled,
+    MaintenanceImmediate,
+    MaintenanceReminder,
+    VisitorArrival,
+    ResidentAccountCreated,
+    AccountLocked,
+    EmailChangeConfirmation,
+    EmailChangedWarning,
+    MagicLink,
+    Announcement,
 }
 
 pub struct SmtpTemplateData {
@@ -10,6 +25,16 @@ pub struct SmtpTemplateData {
     pub value: String,
 }
 
+/// Escapes text for the HTML templates. Every value that comes from users goes through it, so
+/// names and descriptions can't inject markup into the emails.
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
 pub fn smtp_get_template(template_type: SmtpTemplate, data: Vec<SmtpTemplateData>) -> String {
     let mut template_str = match template_type {
         SmtpTemplate::ResidentInvite => {
@@ -24,6 +49,46 @@ pub fn smtp_get_template(template_type: SmtpTemplate, data: Vec<SmtpTemplateData
         SmtpTemplate::PasswordResetWarning => {
             include_str!("../../../res/mail/password_reset_warning.html").to_string()
         }
+        SmtpTemplate::ParcelArrival => {
+            include_str!("../../../res/mail/parcel_arrival.html").to_string()
+        }
+        SmtpTemplate::ParcelReminder => {
+            include_str!("../../../res/mail/parcel_reminder.html").to_string()
+        }
+        SmtpTemplate::ParcelReturn => {
+            include_str!("../../../res/mail/parcel_return.html").to_string()
+        }
+        SmtpTemplate::IncidentStatusChanged => {
+            include_str!("../../../res/mail/incident_status_changed.html").to_string()
+        }
+        SmtpTemplate::MaintenanceScheduled => {
+            include_str!("../../../res/mail/maintenance_scheduled.html").to_string()
+        }
+        SmtpTemplate::MaintenanceImmediate => {
+            include_str!("../../../res/mail/maintenance_immediate.html").to_string()
+        }
+        SmtpTemplate::MaintenanceReminder => {
+            include_str!("../../../res/mail/maintenance_reminder.html").to_string()
+        }
+        SmtpTemplate::VisitorArrival => {
+            include_str!("../../../res/mail/visitor_arrival.html").to_string()
+        }
+        SmtpTemplate::ResidentAccountCreated => {
+            include_str!("../../../res/mail/resident_account_created.html").to_string()
+        }
+        SmtpTemplate::AccountLocked => {
+            include_str!("../../../res/mail/account_locked.html").to_string()
+        }
+        SmtpTemplate::EmailChangeConfirmation => {
+            include_str!("../../../res/mail/email_change_confirmation.html").to_string()
+        }
+        SmtpTemplate::EmailChangedWarning => {
+            include_str!("../../../res/mail/email_changed_warning.html").to_string()
+        }
+        SmtpTemplate::MagicLink => include_str!("../../../res/mail/magic_link.html").to_string(),
+        SmtpTemplate::Announcement => {
+            include_str!("../../../res/mail/announcement.html").to_string()
+        }
     };
 
     for parameter in data {
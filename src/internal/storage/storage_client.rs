@@ -0,0 +1,83 @@
+use crate::services::ConfigMinio;
+use object_store::aws::{AmazonS3, AmazonS3Builder};
+use object_store::local::LocalFileSystem;
+use object_store::path::Path;
+use object_store::signer::Signer;
+use object_store::{ObjectStore, PutPayload};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub struct StorageClient {
+    store: Arc<dyn ObjectStore>,
+    signer: Option<Arc<AmazonS3>>,
+}
+
+impl StorageClient {
+    /// Connects to the configured MinIO bucket, or to `local_path` when one is configured.
+    pub fn new(config: &ConfigMinio) -> Result<Self, object_store::Error> {
+        match &config.local_path {
+            Some(local_path) => {
+                std::fs::create_dir_all(local_path).map_err(|e| object_store::Error::Generic {
+                    store: "LocalFileSystem",
+                    source: Box::new(e),
+                })?;
+
+                Ok(StorageClient {
+                    store: Arc::new(LocalFileSystem::new_with_prefix(local_path)?),
+                    signer: None,
+                })
+            }
+            None => {
+                let s3 = Arc::new(
+                    AmazonS3Builder::new()
+                        .with_endpoint(&config.url)
+                        .with_bucket_name(&config.bucket_name)
+                        .with_access_key_id(&config.access_key)
+                        .with_secret_access_key(&config.secret_key)
+                        .with_region(&config.region)
+                        .with_allow_http(config.allow_http)
+                        .with_virtual_hosted_style_request(false)
+                        .build()?,
+                );
+
+                Ok(StorageClient {
+                    store: s3.clone(),
+                    signer: Some(s3),
+                })
+            }
+        }
+    }
+
+    pub async fn put(&self, key: &str, data: Vec<u8>) -> Result<(), object_store::Error> {
+        self.store
+            .put(&Path::from(key), PutPayload::from(data))
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get(&self, key: &str) -> Result<Vec<u8>, object_store::Error> {
+        let result = self.store.get(&Path::from(key)).await?;
+        Ok(result.bytes().await?.to_vec())
+    }
+
+    pub async fn delete(&self, key: &str) -> Result<(), object_store::Error> {
+        self.store.delete(&Path::from(key)).await
+    }
+
+    /// Returns a presigned GET URL, or `None` when the backend cannot sign URLs.
+    pub async fn presigned_get_url(
+        &self,
+        key: &str,
+        expires_in: Duration,
+    ) -> Result<Option<String>, object_store::Error> {
+        match &self.signer {
+            Some(signer) => {
+                let url = signer
+                    .signed_url(reqwest::Method::GET, &Path::from(key), expires_in)
+                    .await?;
+                Ok(Some(url.to_string()))
+            }
+            None => Ok(None),
+        }
+    }
+}
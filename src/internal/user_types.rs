@@ -11,6 +11,8 @@ use diesel::{AsExpression, FromSqlRow, deserialize, serialize};
 pub enum UserTypes {
     Admin,
     Resident,
+    /// Non-interactive user of an integration, which only authenticates with API keys.
+    Service,
 }
 
 impl<DB> ToSql<diesel::sql_types::Text, DB> for UserTypes
@@ -22,6 +24,7 @@ where
         let s = match self {
             UserTypes::Admin => "admin",
             UserTypes::Resident => "resident",
+            UserTypes::Service => "service",
         };
         s.to_sql(out)
     }
@@ -36,6 +39,7 @@ where
         match <String as FromSql<diesel::sql_types::Text, DB>>::from_sql(bytes)?.as_str() {
             "admin" => Ok(UserTypes::Admin),
             "resident" => Ok(UserTypes::Resident),
+            "service" => Ok(UserTypes::Service),
             x => Err(format!("Unrecognized variant {}", x).into()),
         }
     }
@@ -11,6 +11,7 @@ use mycondominium_backend::internal::config::model::MyCondominiumConfig;
 use mycondominium_backend::internal::scheduled_tasks::scheduled_tasks_service::scheduled_tasks_service;
 use mycondominium_backend::routes::routes::*;
 use mycondominium_backend::services::ApiDoc;
+use mycondominium_backend::utilities::jwt_utils::JwtKeys;
 use mycondominium_backend::{MIGRATIONS, establish_connection_pg};
 use std::env;
 use std::path::PathBuf;
@@ -52,6 +53,15 @@ async fn main() {
                 }
             };
 
+            // Loaded once and shared, so requests don't read the key files again.
+            let jwt_keys = match JwtKeys::new(&conf.auth) {
+                Ok(keys) => web::Data::new(keys),
+                Err(e) => {
+                    log::error!("Failed to load token signing keys: {}", e);
+                    panic!("Failed to load token signing keys: {}", e);
+                }
+            };
+
             let conf: Arc<MyCondominiumConfig> = Arc::new(conf);
 
             let conn = &mut establish_connection_pg(&conf);
@@ -78,6 +88,7 @@ async fn main() {
                     .wrap(Logger::default())
                     .wrap(cors)
                     .app_data(web::Data::new(conf_clone.clone()))
+                    .app_data(jwt_keys.clone())
                     .service(resident_route())
                     .service(admin_route())
                     .service(auth_route())
@@ -89,6 +100,12 @@ async fn main() {
                     .service(reservation_route())
                     .service(invoice_route())
                     .service(incident_route())
+                    .service(attachment_route())
+                    .service(vendor_route())
+                    .service(visitor_route())
+                    .service(unit_route())
+                    .service(announcement_route())
+                    .service(election_route())
                     .service(
                         SwaggerUi::new("/docs-v1/{_:.*}")
                             .url("/api-docs/openapi.json", ApiDoc::openapi()),
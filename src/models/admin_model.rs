@@ -101,7 +101,7 @@ impl AdminModel {
         let mut query = user_roles::table
             .inner_join(users::table.on(users::id.eq(user_roles::user_id)))
             .inner_join(admins::table.on(admins::id.eq(users::entity_id)))
-            .filter(users::entity_type.eq("admin"))
+            .filter(users::entity_type.eq(UserTypes::Admin))
             .into_boxed();
 
         match user_role.role {
@@ -170,7 +170,7 @@ impl AdminModel {
 
         let user = UserModel::table()
             .filter(users::entity_id.eq(admin.id))
-            .filter(users::entity_type.eq("admin"))
+            .filter(users::entity_type.eq(UserTypes::Admin))
             .first::<UserModel>(conn)?;
 
         let role = UserRoleModel::table()
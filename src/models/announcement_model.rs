@@ -1,6 +1,21 @@
 use super::prelude::*;
+use super::*;
+use crate::internal::smtp::smtp_templates::{SmtpTemplateData, escape_html};
+use chrono::Datelike;
 
-#[derive(Debug, Queryable, Identifiable, Validate)]
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
 #[diesel(table_name = announcements)]
 pub struct AnnouncementModel {
     pub id: Uuid,
@@ -9,4 +24,222 @@ pub struct AnnouncementModel {
     pub community_id: Option<Uuid>,
     pub message: String,
     pub sent_at: NaiveDateTime,
+    pub audience: AnnouncementAudience,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct AnnouncementModelNew {
+    pub community_id: Uuid,
+    #[validate(length(min = 1, max = 150, message = "Title is too short or too long"))]
+    pub title: String,
+    #[validate(length(min = 1, message = "Message is too short"))]
+    pub message: String,
+    #[serde(default)]
+    pub audience: AnnouncementAudience,
+}
+
+/// Board announcements may be restricted to unit owners, leaving tenants and dependents out.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    AsExpression,
+    FromSqlRow,
+)]
+#[diesel(sql_type = diesel::sql_types::Text)]
+pub enum AnnouncementAudience {
+    #[default]
+    AllResidents,
+    Owners,
+}
+
+impl AnnouncementAudience {
+    /// Whether a resident is addressed, given whether they owned a unit when it was sent.
+    pub fn includes(self, is_owner: bool) -> bool {
+        match self {
+            AnnouncementAudience::AllResidents => true,
+            AnnouncementAudience::Owners => is_owner,
+        }
+    }
+}
+
+impl AnnouncementModel {
+    /// Admins see the announcements of their community, residents only those addressed to them.
+    pub fn db_read_by_id_matching_community(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        id: Uuid,
+    ) -> QueryResult<AnnouncementModel> {
+        let announcement = announcements::table
+            .filter(announcements::id.eq(id))
+            .first::<AnnouncementModel>(conn)?;
+
+        if !(user_role.role == UserRoles::Root
+            || announcement.community_id.is_none()
+            || user_role.community_id == announcement.community_id)
+        {
+            return Err(diesel::result::Error::NotFound);
+        }
+
+        if user_role.role == UserRoles::Resident
+            && !announcement.is_addressed_to(&Self::db_read_ownerships(conn, &user_role)?)
+        {
+            return Err(diesel::result::Error::NotFound);
+        }
+
+        Ok(announcement)
+    }
+
+    /// Announcements the user can read, newest first.
+    pub fn db_read_all_matching(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+    ) -> QueryResult<Vec<AnnouncementModel>> {
+        let mut query = announcements::table.into_boxed();
+
+        if user_role.role != UserRoles::Root {
+            query = query.filter(
+                announcements::community_id
+                    .is_null()
+                    .or(announcements::community_id.eq(user_role.community_id)),
+            );
+        }
+
+        let announcements = query
+            .order(announcements::sent_at.desc())
+            .load::<AnnouncementModel>(conn)?;
+
+        if user_role.role != UserRoles::Resident {
+            return Ok(announcements);
+        }
+
+        // Ownerships are read once and checked against the date each announcement was sent.
+        let ownerships = Self::db_read_ownerships(conn, &user_role)?;
+
+        Ok(announcements
+            .into_iter()
+            .filter(|announcement| announcement.is_addressed_to(&ownerships))
+            .collect())
+    }
+
+    /// Units of their community the resident behind `user_role` owns or owned.
+    fn db_read_ownerships(
+        conn: &mut PgConnection,
+        user_role: &UserRoleModel,
+    ) -> QueryResult<Vec<unit_model::UnitResidentModel>> {
+        let Some(community_id) = user_role.community_id else {
+            return Ok(Vec::new());
+        };
+
+        let resident_id = users::table
+            .find(user_role.user_id)
+            .filter(users::entity_type.eq(UserTypes::Resident))
+            .select(users::entity_id)
+            .first::<Uuid>(conn)?;
+
+        unit_model::UnitResidentModel::db_read_by_resident_and_relation(
+            conn,
+            community_id,
+            resident_id,
+            unit_model::UnitRelation::Owner,
+        )
+    }
+
+    /// Whether a resident with `ownerships` in the announcement's community is in its audience.
+    pub fn is_addressed_to(&self, ownerships: &[unit_model::UnitResidentModel]) -> bool {
+        match (self.community_id, self.audience) {
+            (_, AnnouncementAudience::AllResidents) => true,
+            (Some(_), audience) => audience.includes(
+                ownerships
+                    .iter()
+                    .any(|ownership| ownership.is_active_on(self.sent_at.date())),
+            ),
+            (None, _) => false,
+        }
+    }
+
+    /// Active residents of `community_id` the announcement is addressed to.
+    pub fn db_read_recipients(
+        &self,
+        conn: &mut PgConnection,
+        community_id: Uuid,
+    ) -> QueryResult<Vec<resident_model::ResidentModel>> {
+        let residents =
+            resident_model::ResidentModel::db_read_all_active_by_community(conn, community_id)?;
+
+        match self.audience {
+            AnnouncementAudience::AllResidents => Ok(residents),
+            AnnouncementAudience::Owners => {
+                let owner_ids = unit_model::UnitResidentModel::db_read_resident_ids_by_relation(
+                    conn,
+                    community_id,
+                    unit_model::UnitRelation::Owner,
+                    self.sent_at.date(),
+                )?;
+
+                Ok(residents
+                    .into_iter()
+                    .filter(|resident| owner_ids.contains(&resident.id))
+                    .collect())
+            }
+        }
+    }
+
+    pub fn email_parameters(
+        &self,
+        resident: &resident_model::ResidentModel,
+    ) -> Vec<SmtpTemplateData> {
+        vec![
+            SmtpTemplateData {
+                key: "{{USER_NAME}}".to_string(),
+                value: escape_html(&resident.first_name),
+            },
+            SmtpTemplateData {
+                key: "{{TITLE}}".to_string(),
+                value: escape_html(&self.title),
+            },
+            SmtpTemplateData {
+                key: "{{MESSAGE}}".to_string(),
+                value: escape_html(&self.message).replace('\n', "<br>"),
+            },
+            SmtpTemplateData {
+                key: "{{CURRENT_YEAR}}".to_string(),
+                value: chrono::Utc::now().year().to_string(),
+            },
+        ]
+    }
+}
+
+impl<DB> ToSql<diesel::sql_types::Text, DB> for AnnouncementAudience
+where
+    DB: Backend,
+    str: ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        let s = match self {
+            AnnouncementAudience::AllResidents => "AllResidents",
+            AnnouncementAudience::Owners => "Owners",
+        };
+        s.to_sql(out)
+    }
+}
+
+impl<DB> FromSql<diesel::sql_types::Text, DB> for AnnouncementAudience
+where
+    DB: Backend,
+    String: FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        match <String as FromSql<diesel::sql_types::Text, DB>>::from_sql(bytes)?.as_str() {
+            "AllResidents" => Ok(AnnouncementAudience::AllResidents),
+            "Owners" => Ok(AnnouncementAudience::Owners),
+            x => Err(format!("Unrecognized variant {}", x).into()),
+        }
+    }
 }
@@ -0,0 +1,212 @@
+use super::auth_token_model::LAST_USED_RESOLUTION_SECONDS;
+use super::prelude::*;
+use crate::utilities::auth_utils::{generate_token, hash_token};
+
+/// Marks a secret as an API key, so it is recognisable in logs and secret scanners.
+pub const API_KEY_PREFIX: &str = "mcd_";
+
+/// Characters of the key kept in clear, so the owner can tell their keys apart.
+const API_KEY_DISPLAY_LENGTH: usize = 12;
+
+/// Resources a key can be scoped to, named after the first path segment under `/api/`.
+///
+/// `auth` is left out on purpose: keys can't manage sessions, passwords or other keys.
+pub const API_KEY_RESOURCES: &[&str] = &[
+    "admin",
+    "announcement",
+    "attachment",
+    "common_area",
+    "community",
+    "election",
+    "incident",
+    "invoice",
+    "maintenance_schedule",
+    "parcel",
+    "reservation",
+    "resident",
+    "unit",
+    "vehicle",
+    "vendor",
+    "visitor",
+];
+
+/// Key that lets an integration act as its owner on the scoped resources, sent in the
+/// `X-Api-Key` header. Integrations get their own user through
+/// [`ServiceAccountModel`](super::service_account_model::ServiceAccountModel).
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::api_keys)]
+pub struct ApiKeyModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    /// Start of the key, shown in listings.
+    pub prefix: String,
+    pub key_hash: String,
+    /// Space-separated `<resource>:<read|write>` scopes.
+    pub scopes: String,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+    pub last_used_at: Option<NaiveDateTime>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ApiKeyNewModel {
+    #[validate(length(min = 1, max = 100, message = "Name is too short or too long"))]
+    pub name: String,
+    /// `<resource>:<read|write>`, where `read` only allows `GET` requests.
+    #[validate(length(min = 1, message = "At least one scope is required"))]
+    pub scopes: Vec<String>,
+    #[validate(range(min = 1, max = 365, message = "Keys must expire within a year"))]
+    pub expires_in_days: i64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ApiKeyResult {
+    pub id: Uuid,
+    pub name: String,
+    pub prefix: String,
+    pub scopes: Vec<String>,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+    pub last_used_at: Option<NaiveDateTime>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ApiKeyNewResult {
+    /// Only returned once, when the key is created.
+    pub key: String,
+    pub api_key: ApiKeyResult,
+}
+
+/// Checks the requested scopes and joins them in the form they are stored.
+pub fn normalize_scopes(scopes: &[String]) -> Result<String, std::io::Error> {
+    let mut normalized: Vec<String> = Vec::new();
+
+    for scope in scopes {
+        let scope = scope.trim().to_lowercase();
+        let valid = match scope.split_once(':') {
+            Some((resource, access)) => {
+                API_KEY_RESOURCES.contains(&resource) && matches!(access, "read" | "write")
+            }
+            None => false,
+        };
+
+        if !valid {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Invalid scope: {}", scope),
+            ));
+        }
+
+        if !normalized.contains(&scope) {
+            normalized.push(scope);
+        }
+    }
+
+    Ok(normalized.join(" "))
+}
+
+impl ApiKeyModel {
+    /// Creates a key for the user, returning it along with the plaintext secret.
+    pub fn new(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        name: String,
+        scopes: String,
+        expires_in_days: i64,
+    ) -> (Self, String) {
+        let now = chrono::Utc::now().naive_utc();
+        let key = format!("{}{}", API_KEY_PREFIX, generate_token());
+
+        let api_key = ApiKeyModel {
+            id: ApiKeyModel::new_id(conn),
+            user_id,
+            name,
+            prefix: key.chars().take(API_KEY_DISPLAY_LENGTH).collect(),
+            key_hash: hash_token(&key),
+            scopes,
+            created_at: now,
+            expires_at: now + chrono::Duration::days(expires_in_days),
+            last_used_at: None,
+        };
+
+        (api_key, key)
+    }
+
+    pub fn to_result(&self) -> ApiKeyResult {
+        ApiKeyResult {
+            id: self.id,
+            name: self.name.clone(),
+            prefix: self.prefix.clone(),
+            scopes: self.scopes.split_whitespace().map(String::from).collect(),
+            created_at: self.created_at,
+            expires_at: self.expires_at,
+            last_used_at: self.last_used_at,
+        }
+    }
+
+    /// Whether the scopes of the key cover a request. `read` scopes only allow `GET` and `HEAD`.
+    pub fn allows(&self, method: &str, path: &str) -> bool {
+        let resource = match path
+            .strip_prefix("/api/")
+            .and_then(|rest| rest.split('/').next())
+        {
+            Some(resource) if API_KEY_RESOURCES.contains(&resource) => resource,
+            _ => return false,
+        };
+        let read_only = matches!(method, "GET" | "HEAD");
+
+        self.scopes
+            .split_whitespace()
+            .filter_map(|scope| scope.split_once(':'))
+            .any(|(scoped, access)| {
+                scoped == resource && (access == "write" || (access == "read" && read_only))
+            })
+    }
+
+    pub fn db_read_valid_by_key(conn: &mut PgConnection, key: &str) -> diesel::QueryResult<Self> {
+        api_keys::table
+            .filter(api_keys::key_hash.eq(hash_token(key)))
+            .filter(api_keys::expires_at.gt(chrono::Utc::now().naive_utc()))
+            .first::<Self>(conn)
+    }
+
+    pub fn db_read_by_user(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+    ) -> diesel::QueryResult<Vec<Self>> {
+        api_keys::table
+            .filter(api_keys::user_id.eq(user_id))
+            .order(api_keys::created_at.desc())
+            .load::<Self>(conn)
+    }
+
+    /// Records that the key was just used, at most once per `LAST_USED_RESOLUTION_SECONDS`.
+    pub fn db_touch(&mut self, conn: &mut PgConnection) -> diesel::QueryResult<()> {
+        let now = chrono::Utc::now().naive_utc();
+
+        if let Some(last_used_at) = self.last_used_at
+            && now - last_used_at < chrono::Duration::seconds(LAST_USED_RESOLUTION_SECONDS)
+        {
+            return Ok(());
+        }
+
+        self.last_used_at = Some(now);
+        diesel::update(api_keys::table.find(self.id))
+            .set(api_keys::last_used_at.eq(now))
+            .execute(conn)
+            .map(|_| ())
+    }
+}
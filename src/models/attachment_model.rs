@@ -0,0 +1,190 @@
+use super::prelude::*;
+use super::*;
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = attachments)]
+pub struct AttachmentModel {
+    pub id: Uuid,
+    pub entity_type: AttachmentEntityType,
+    pub entity_id: Uuid,
+    #[validate(length(min = 1, max = 255, message = "File name is too short or too long"))]
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    #[serde(skip_serializing)]
+    pub storage_key: String,
+    pub uploaded_by: Option<Uuid>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct AttachmentUploadForm {
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = diesel::sql_types::Text)]
+pub enum AttachmentEntityType {
+    Incident,
+    MaintenanceSchedule,
+    Announcement,
+    Invoice,
+    Vendor,
+}
+
+impl AttachmentEntityType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AttachmentEntityType::Incident => "Incident",
+            AttachmentEntityType::MaintenanceSchedule => "MaintenanceSchedule",
+            AttachmentEntityType::Announcement => "Announcement",
+            AttachmentEntityType::Invoice => "Invoice",
+            AttachmentEntityType::Vendor => "Vendor",
+        }
+    }
+}
+
+impl AttachmentModel {
+    pub fn storage_key(entity_type: AttachmentEntityType, entity_id: Uuid, id: Uuid) -> String {
+        format!("{}/{}/{}", entity_type.as_str(), entity_id, id)
+    }
+
+    /// Applies the access rules of the record an attachment belongs to. Residents may only
+    /// add or remove attachments on incidents, everything else is managed by admins.
+    pub fn db_check_entity_access(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        entity_type: AttachmentEntityType,
+        entity_id: Uuid,
+        write: bool,
+    ) -> diesel::QueryResult<()> {
+        if write
+            && user_role.role == UserRoles::Resident
+            && entity_type != AttachmentEntityType::Incident
+        {
+            return Err(diesel::result::Error::NotFound);
+        }
+
+        match entity_type {
+            AttachmentEntityType::Incident => {
+                incident_model::IncidentModel::db_read_by_id_matching_resident(
+                    user_role, conn, entity_id,
+                )?;
+            }
+            AttachmentEntityType::MaintenanceSchedule => {
+                maintenance_schedule_model::MaintenanceScheduleModel::db_read_by_id_matching_community(
+                    user_role, conn, entity_id,
+                )?;
+            }
+            AttachmentEntityType::Announcement => {
+                announcement_model::AnnouncementModel::db_read_by_id_matching_community(
+                    user_role, conn, entity_id,
+                )?;
+            }
+            AttachmentEntityType::Invoice => {
+                invoice_model::InvoiceModel::db_read_by_id_matching_resident(
+                    user_role, conn, entity_id,
+                )?;
+            }
+            AttachmentEntityType::Vendor => {
+                vendor_model::VendorModel::db_read_by_id_matching_community(
+                    user_role, conn, entity_id,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Attachments whose record was deleted, which the scheduler removes with their files.
+    pub fn db_read_all_orphaned(
+        conn: &mut PgConnection,
+    ) -> diesel::QueryResult<Vec<AttachmentModel>> {
+        use diesel::dsl::{exists, not};
+
+        attachments::table
+            .filter(
+                attachments::entity_type
+                    .eq(AttachmentEntityType::Incident)
+                    .and(not(exists(
+                        incidents::table.filter(incidents::id.eq(attachments::entity_id)),
+                    )))
+                    .or(attachments::entity_type
+                        .eq(AttachmentEntityType::MaintenanceSchedule)
+                        .and(not(exists(maintenance_schedules::table.filter(
+                            maintenance_schedules::id.eq(attachments::entity_id),
+                        )))))
+                    .or(attachments::entity_type
+                        .eq(AttachmentEntityType::Announcement)
+                        .and(not(exists(
+                            announcements::table
+                                .filter(announcements::id.eq(attachments::entity_id)),
+                        ))))
+                    .or(attachments::entity_type
+                        .eq(AttachmentEntityType::Invoice)
+                        .and(not(exists(
+                            invoices::table.filter(invoices::id.eq(attachments::entity_id)),
+                        ))))
+                    .or(attachments::entity_type
+                        .eq(AttachmentEntityType::Vendor)
+                        .and(not(exists(
+                            vendors::table.filter(vendors::id.eq(attachments::entity_id)),
+                        )))),
+            )
+            .load::<AttachmentModel>(conn)
+    }
+
+    pub fn db_read_all_by_entity(
+        conn: &mut PgConnection,
+        entity_type: AttachmentEntityType,
+        entity_id: Uuid,
+    ) -> diesel::QueryResult<Vec<AttachmentModel>> {
+        attachments::table
+            .filter(attachments::entity_type.eq(entity_type))
+            .filter(attachments::entity_id.eq(entity_id))
+            .order(attachments::created_at.asc())
+            .load::<AttachmentModel>(conn)
+    }
+}
+
+impl<DB> ToSql<diesel::sql_types::Text, DB> for AttachmentEntityType
+where
+    DB: Backend,
+    str: ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.as_str().to_sql(out)
+    }
+}
+
+impl<DB> FromSql<diesel::sql_types::Text, DB> for AttachmentEntityType
+where
+    DB: Backend,
+    String: FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        match <String as FromSql<diesel::sql_types::Text, DB>>::from_sql(bytes)?.as_str() {
+            "Incident" => Ok(AttachmentEntityType::Incident),
+            "MaintenanceSchedule" => Ok(AttachmentEntityType::MaintenanceSchedule),
+            "Announcement" => Ok(AttachmentEntityType::Announcement),
+            "Invoice" => Ok(AttachmentEntityType::Invoice),
+            "Vendor" => Ok(AttachmentEntityType::Vendor),
+            x => Err(format!("Unrecognized variant {}", x).into()),
+        }
+    }
+}
@@ -1,5 +1,12 @@
 use super::prelude::*;
 use super::*;
+use crate::utilities::auth_utils::hash_token;
+
+/// Minutes a password reset requested by the user stays valid.
+pub const PASSWORD_RESET_EXPIRATION_MINUTES: i64 = 15;
+
+/// Hours a link confirming a new email address stays valid.
+pub const EMAIL_CHANGE_EXPIRATION_HOURS: i64 = 24;
 
 #[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
 pub struct AuthModel {
@@ -14,6 +21,21 @@ pub struct TokenClaims {
     pub exp: usize,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct AuthTokenPairResult {
+    /// Sent in the `X-Auth-Token` header.
+    pub access_token: String,
+    pub access_token_expires_at: NaiveDateTime,
+    /// Single use, exchanged for a new pair at `/refresh`.
+    pub refresh_token: String,
+    pub refresh_token_expires_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct RefreshTokenRequestModel {
+    pub refresh_token: String,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
 pub struct AuthAdminNewSelfServiceModel {
     pub community: community_model::CommunityModelNew,
@@ -59,4 +81,197 @@ pub struct PasswordResetModel {
     pub user_id: Uuid,
     pub token: String,
     pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct MagicLinkRequestModel {
+    pub email: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct MagicLinkSignInModel {
+    pub token: String,
+}
+
+/// Single-use link that signs the user in without their password.
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::magic_links)]
+pub struct MagicLinkModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+}
+
+impl PasswordResetModel {
+    pub fn new(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        email: String,
+        lifetime: chrono::Duration,
+    ) -> Self {
+        let id = PasswordResetModel::new_id(conn);
+        let now = chrono::Utc::now().naive_utc();
+
+        PasswordResetModel {
+            id,
+            email,
+            user_id,
+            token: format!("{}{}", id, Uuid::new_v4()),
+            created_at: now,
+            expires_at: now + lifetime,
+        }
+    }
+
+    /// Link to the page where the password is set with the token.
+    pub fn link(&self, base_url: &str) -> String {
+        format!(
+            "{}/redirect/onboarding/password_reset/{}",
+            base_url, self.token
+        )
+    }
+}
+
+impl MagicLinkModel {
+    pub fn new(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        token: &str,
+        expiration_minutes: i64,
+    ) -> Self {
+        let now = chrono::Utc::now().naive_utc();
+
+        MagicLinkModel {
+            id: MagicLinkModel::new_id(conn),
+            user_id,
+            token_hash: hash_token(token),
+            created_at: now,
+            expires_at: now + chrono::Duration::minutes(expiration_minutes),
+        }
+    }
+
+    /// Inserts the link, invalidating the ones sent to the user before.
+    pub fn db_replace(&self, conn: &mut PgConnection) -> diesel::QueryResult<()> {
+        conn.transaction(|conn| {
+            diesel::delete(magic_links::table.filter(magic_links::user_id.eq(self.user_id)))
+                .execute(conn)?;
+            self.db_insert(conn).map(|_| ())
+        })
+    }
+
+    /// Removes and returns the link with the token, if it hasn't expired.
+    pub fn db_take_valid(conn: &mut PgConnection, token: &str) -> diesel::QueryResult<Self> {
+        diesel::delete(
+            magic_links::table
+                .filter(magic_links::token_hash.eq(hash_token(token)))
+                .filter(magic_links::expires_at.gt(chrono::Utc::now().naive_utc())),
+        )
+        .get_result::<Self>(conn)
+    }
+
+    pub fn db_delete_expired(conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::delete(
+            magic_links::table.filter(magic_links::expires_at.le(chrono::Utc::now().naive_utc())),
+        )
+        .execute(conn)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ChangePasswordModel {
+    pub current_password: String,
+    pub new_password: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ChangeEmailRequestModel {
+    #[validate(email(message = "Invalid email"))]
+    pub new_email: String,
+    pub password: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ChangeEmailConfirmModel {
+    pub token: String,
+}
+
+/// Email address waiting to be confirmed through the link sent to it.
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::email_changes)]
+pub struct EmailChangeModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub new_email: String,
+    pub token_hash: String,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+}
+
+impl EmailChangeModel {
+    pub fn new(conn: &mut PgConnection, user_id: Uuid, new_email: String, token: &str) -> Self {
+        let now = chrono::Utc::now().naive_utc();
+
+        EmailChangeModel {
+            id: EmailChangeModel::new_id(conn),
+            user_id,
+            new_email,
+            token_hash: hash_token(token),
+            created_at: now,
+            expires_at: now + chrono::Duration::hours(EMAIL_CHANGE_EXPIRATION_HOURS),
+        }
+    }
+
+    /// Inserts the change, dropping any other change the user hasn't confirmed yet.
+    pub fn db_replace(&self, conn: &mut PgConnection) -> diesel::QueryResult<()> {
+        conn.transaction(|conn| {
+            diesel::delete(email_changes::table.filter(email_changes::user_id.eq(self.user_id)))
+                .execute(conn)?;
+            self.db_insert(conn).map(|_| ())
+        })
+    }
+
+    /// Reads a change by its token, only if it hasn't expired.
+    pub fn db_read_valid_by_token(
+        conn: &mut PgConnection,
+        token: &str,
+    ) -> diesel::QueryResult<Self> {
+        email_changes::table
+            .filter(email_changes::token_hash.eq(hash_token(token)))
+            .filter(email_changes::expires_at.gt(chrono::Utc::now().naive_utc()))
+            .first::<Self>(conn)
+    }
+
+    pub fn db_delete_expired(conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::delete(
+            email_changes::table
+                .filter(email_changes::expires_at.le(chrono::Utc::now().naive_utc())),
+        )
+        .execute(conn)
+    }
 }
@@ -1,4 +1,5 @@
 use super::prelude::*;
+use crate::utilities::auth_utils::hash_token;
 use user_agent_parser::UserAgentParser;
 
 #[derive(
@@ -27,6 +28,181 @@ pub struct AuthTokenModel {
     pub cpu_arch: Option<String>,
 }
 
+/// Single-use token that renews the access token of a session. Every refresh replaces it with a
+/// new one, and presenting a used token again revokes the whole session.
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::refresh_tokens)]
+pub struct RefreshTokenModel {
+    pub id: Uuid,
+    pub auth_token_id: Uuid,
+    pub token_hash: String,
+    pub used_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct AuthSessionResult {
+    pub id: Uuid,
+    pub time_added: NaiveDateTime,
+    pub time_last_used: NaiveDateTime,
+    pub device: Option<String>,
+    pub browser: Option<String>,
+    pub version: Option<String>,
+    pub cpu_arch: Option<String>,
+    /// The session making the request.
+    pub current: bool,
+}
+
+/// How long `time_last_used` may lag behind, so not every request writes to the database.
+pub const LAST_USED_RESOLUTION_SECONDS: i64 = 60;
+
+impl AuthTokenModel {
+    pub fn to_session_result(&self, current_id: Uuid) -> AuthSessionResult {
+        AuthSessionResult {
+            id: self.id,
+            time_added: self.time_added,
+            time_last_used: self.time_last_used,
+            device: self.device.clone(),
+            browser: self.browser.clone(),
+            version: self.version.clone(),
+            cpu_arch: self.cpu_arch.clone(),
+            current: self.id == current_id,
+        }
+    }
+
+    pub fn db_read_active_by_user(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+    ) -> diesel::QueryResult<Vec<Self>> {
+        auth_tokens::table
+            .filter(auth_tokens::user_id.eq(user_id))
+            .filter(auth_tokens::active.eq(true))
+            .order(auth_tokens::time_last_used.desc())
+            .load::<Self>(conn)
+    }
+
+    /// Deletes every session of the user, except `keep` when given.
+    pub fn db_delete_by_user(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        keep: Option<Uuid>,
+    ) -> diesel::QueryResult<usize> {
+        let mut query = diesel::delete(auth_tokens::table)
+            .filter(auth_tokens::user_id.eq(user_id))
+            .into_boxed();
+
+        if let Some(keep) = keep {
+            query = query.filter(auth_tokens::id.ne(keep));
+        }
+
+        query.execute(conn)
+    }
+
+    /// Deletes every session of the user behind a resident.
+    pub fn db_delete_by_resident(
+        conn: &mut PgConnection,
+        resident_id: Uuid,
+    ) -> diesel::QueryResult<usize> {
+        diesel::delete(
+            auth_tokens::table.filter(
+                auth_tokens::user_id.eq_any(
+                    users::table
+                        .filter(users::entity_id.eq(resident_id))
+                        .filter(users::entity_type.eq(UserTypes::Resident))
+                        .select(users::id),
+                ),
+            ),
+        )
+        .execute(conn)
+    }
+
+    /// Deletes the sessions that have not been used for longer than a refresh token lives.
+    pub fn db_delete_idle(conn: &mut PgConnection, idle_days: i64) -> diesel::QueryResult<usize> {
+        let cutoff = chrono::Utc::now().naive_utc() - chrono::Duration::days(idle_days);
+
+        diesel::delete(auth_tokens::table.filter(auth_tokens::time_last_used.lt(cutoff)))
+            .execute(conn)
+    }
+
+    /// Records that the session was just used, at most once per `LAST_USED_RESOLUTION_SECONDS`.
+    pub fn db_touch(&mut self, conn: &mut PgConnection) -> diesel::QueryResult<()> {
+        let now = chrono::Utc::now().naive_utc();
+
+        if now - self.time_last_used < chrono::Duration::seconds(LAST_USED_RESOLUTION_SECONDS) {
+            return Ok(());
+        }
+
+        self.time_last_used = now;
+        diesel::update(auth_tokens::table.find(self.id))
+            .set(auth_tokens::time_last_used.eq(now))
+            .execute(conn)
+            .map(|_| ())
+    }
+}
+
+impl RefreshTokenModel {
+    pub fn new(
+        conn: &mut PgConnection,
+        auth_token_id: Uuid,
+        token: &str,
+        lifetime_days: i64,
+    ) -> Self {
+        let now = chrono::Utc::now().naive_utc();
+
+        RefreshTokenModel {
+            id: RefreshTokenModel::new_id(conn),
+            auth_token_id,
+            token_hash: hash_token(token),
+            used_at: None,
+            created_at: now,
+            expires_at: now + chrono::Duration::days(lifetime_days),
+        }
+    }
+
+    pub fn is_expired(&self, now: NaiveDateTime) -> bool {
+        self.expires_at <= now
+    }
+
+    pub fn db_read_by_token(conn: &mut PgConnection, token: &str) -> diesel::QueryResult<Self> {
+        refresh_tokens::table
+            .filter(refresh_tokens::token_hash.eq(hash_token(token)))
+            .first::<Self>(conn)
+    }
+
+    /// Spends the token, returning false if it had already been used.
+    pub fn db_mark_used(&self, conn: &mut PgConnection) -> diesel::QueryResult<bool> {
+        diesel::update(
+            refresh_tokens::table
+                .filter(refresh_tokens::id.eq(self.id))
+                .filter(refresh_tokens::used_at.is_null()),
+        )
+        .set(refresh_tokens::used_at.eq(chrono::Utc::now().naive_utc()))
+        .execute(conn)
+        .map(|count| count > 0)
+    }
+
+    pub fn db_delete_expired(conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::delete(
+            refresh_tokens::table
+                .filter(refresh_tokens::expires_at.le(chrono::Utc::now().naive_utc())),
+        )
+        .execute(conn)
+    }
+}
+
 pub trait FromUaParser: Sized {
     type ParserType<'a>;
     fn from_ua_parser(s: Self::ParserType<'_>) -> Self;
@@ -23,6 +23,7 @@ pub struct CommunityModel {
     pub address: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub require_admin_2fa: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
@@ -32,4 +33,7 @@ pub struct CommunityModelNew {
     #[validate(length(max = 25, message = "Short name is too long"))]
     pub short_name: Option<String>,
     pub address: String,
+    /// Admins of the community must sign in with a second factor.
+    #[serde(default)]
+    pub require_admin_2fa: bool,
 }
@@ -1,6 +1,19 @@
 use super::prelude::*;
+use super::*;
 
-#[derive(Debug, Queryable, Identifiable, Validate)]
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
 #[diesel(table_name = elections)]
 pub struct ElectionModel {
     pub id: Uuid,
@@ -12,3 +25,70 @@ pub struct ElectionModel {
     pub end_date: NaiveDateTime,
     pub created_at: NaiveDateTime,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ElectionModelNew {
+    pub community_id: Uuid,
+    #[validate(length(min = 1, max = 150, message = "Title is too short or too long"))]
+    pub title: String,
+    pub description: Option<String>,
+    pub start_date: NaiveDateTime,
+    pub end_date: NaiveDateTime,
+}
+
+impl ElectionModel {
+    /// Whether votes are accepted at `now`.
+    pub fn is_open(&self, now: NaiveDateTime) -> bool {
+        self.start_date <= now && now < self.end_date
+    }
+
+    /// Root sees every election, everyone else only those of their community.
+    pub fn db_read_by_id_matching_community(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        id: Uuid,
+    ) -> QueryResult<ElectionModel> {
+        let election = ElectionModel::db_read_by_id(conn, id)?;
+
+        if user_role.role == UserRoles::Root || user_role.community_id == election.community_id {
+            Ok(election)
+        } else {
+            Err(diesel::result::Error::NotFound)
+        }
+    }
+
+    pub fn db_read_all_matching(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+    ) -> QueryResult<Vec<ElectionModel>> {
+        let mut query = elections::table.into_boxed();
+
+        if user_role.role != UserRoles::Root {
+            query = query.filter(elections::community_id.eq(user_role.community_id));
+        }
+
+        query
+            .order(elections::start_date.desc())
+            .load::<ElectionModel>(conn)
+    }
+
+    /// Whether `resident_id` casts the vote of `unit_id` in this election, as the unit's owner
+    /// or the tenant holding the owner's proxy when the election opens.
+    pub fn db_is_voter(
+        &self,
+        conn: &mut PgConnection,
+        unit_id: Uuid,
+        resident_id: Uuid,
+    ) -> QueryResult<bool> {
+        let unit = unit_model::UnitModel::db_read_by_id(conn, unit_id)?;
+        if self.community_id != Some(unit.community_id) {
+            return Ok(false);
+        }
+
+        let links = unit_model::UnitResidentModel::db_read_all_by_unit(conn, unit_id)?;
+        Ok(
+            unit_model::UnitResidentModel::voter_for(&links, self.start_date.date())
+                == Some(resident_id),
+        )
+    }
+}
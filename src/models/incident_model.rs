@@ -1,5 +1,7 @@
 use super::prelude::*;
 use super::*;
+use crate::internal::smtp::smtp_templates::{SmtpTemplateData, escape_html};
+use chrono::Datelike;
 
 #[derive(
     Queryable,
@@ -27,6 +29,12 @@ pub struct IncidentModel {
     pub report_date: NaiveDateTime,
     pub resolution_date: Option<NaiveDateTime>,
     pub notes: Option<String>,
+    pub assignee_id: Option<Uuid>,
+    pub category_id: Option<Uuid>,
+    pub priority: IncidentPriority,
+    pub response_date: Option<NaiveDateTime>,
+    pub response_sla_breached: bool,
+    pub resolution_sla_breached: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
@@ -40,6 +48,139 @@ pub struct IncidentModelNew {
     pub status: IncidentStatus,
     pub resolution_date: Option<NaiveDateTime>,
     pub notes: Option<String>,
+    pub assignee_id: Option<Uuid>,
+    pub category_id: Option<Uuid>,
+    #[serde(default)]
+    pub priority: IncidentPriority,
+}
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = incident_categories)]
+pub struct IncidentCategoryModel {
+    pub id: Uuid,
+    pub community_id: Uuid,
+    #[validate(length(min = 2, max = 50, message = "Name is too short or too long"))]
+    pub name: String,
+    #[validate(range(min = 1, message = "Response SLA must be at least one hour"))]
+    pub response_sla_hours: i32,
+    #[validate(range(min = 1, message = "Resolution SLA must be at least one hour"))]
+    pub resolution_sla_hours: i32,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct IncidentCategoryModelNew {
+    pub community_id: Uuid,
+    #[validate(length(min = 2, max = 50, message = "Name is too short or too long"))]
+    pub name: String,
+    #[validate(range(min = 1, message = "Response SLA must be at least one hour"))]
+    pub response_sla_hours: i32,
+    #[validate(range(min = 1, message = "Resolution SLA must be at least one hour"))]
+    pub resolution_sla_hours: i32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct IncidentMaintenanceModelNew {
+    pub scheduled_date: NaiveDateTime,
+    pub status: maintenance_schedule_model::MaintenanceScheduleStatus,
+    pub details: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct IncidentFilterParams {
+    pub category_id: Option<Uuid>,
+    pub priority: Option<IncidentPriority>,
+    pub sla_breached: Option<bool>,
+}
+
+#[derive(Deserialize)]
+pub struct IncidentReportParams {
+    pub community_id: Option<Uuid>,
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct IncidentResolutionReport {
+    pub resolved_count: i64,
+    pub average_resolution_hours: Option<f64>,
+    pub response_sla_breaches: i64,
+    pub resolution_sla_breaches: i64,
+    pub categories: Vec<IncidentCategoryResolutionReport>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct IncidentCategoryResolutionReport {
+    pub category_id: Option<Uuid>,
+    pub category_name: Option<String>,
+    pub resolved_count: i64,
+    pub average_resolution_hours: Option<f64>,
+    pub response_sla_breaches: i64,
+    pub resolution_sla_breaches: i64,
+}
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = incident_comments)]
+pub struct IncidentCommentModel {
+    pub id: Uuid,
+    pub incident_id: Uuid,
+    pub user_id: Uuid,
+    #[validate(length(min = 1, message = "Message is too short"))]
+    pub message: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct IncidentCommentModelNew {
+    #[validate(length(min = 1, message = "Message is too short"))]
+    pub message: String,
+}
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = incident_status_history)]
+pub struct IncidentStatusHistoryModel {
+    pub id: Uuid,
+    pub incident_id: Uuid,
+    pub old_status: Option<IncidentStatus>,
+    pub new_status: IncidentStatus,
+    pub changed_by: Option<Uuid>,
+    pub changed_at: NaiveDateTime,
 }
 
 #[derive(
@@ -53,7 +194,331 @@ pub enum IncidentStatus {
     Closed,
 }
 
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    AsExpression,
+    FromSqlRow,
+)]
+#[diesel(sql_type = diesel::sql_types::Text)]
+pub enum IncidentPriority {
+    Low,
+    #[default]
+    Medium,
+    High,
+    Urgent,
+}
+
+impl IncidentStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            IncidentStatus::Reported => "Reportado",
+            IncidentStatus::Ongoing => "Em andamento",
+            IncidentStatus::Resolved => "Resolvido",
+            IncidentStatus::Closed => "Fechado",
+        }
+    }
+}
+
+impl IncidentPriority {
+    pub fn label(&self) -> &'static str {
+        match self {
+            IncidentPriority::Low => "Baixa",
+            IncidentPriority::Medium => "Média",
+            IncidentPriority::High => "Alta",
+            IncidentPriority::Urgent => "Urgente",
+        }
+    }
+}
+
 impl IncidentModel {
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self.status,
+            IncidentStatus::Reported | IncidentStatus::Ongoing
+        )
+    }
+
+    /// Records the first response and the resolution time once the status moves past them. A
+    /// reopened incident is no longer resolved.
+    pub fn track_progress(&mut self, now: NaiveDateTime) {
+        if self.response_date.is_none() && self.status != IncidentStatus::Reported {
+            self.response_date = Some(now);
+        }
+
+        if self.is_open() {
+            self.resolution_date = None;
+        } else if self.resolution_date.is_none() {
+            self.resolution_date = Some(now);
+        }
+    }
+
+    /// Flags response and resolution SLA breaches against `category`. Returns whether a flag changed.
+    pub fn check_sla(&mut self, category: &IncidentCategoryModel, now: NaiveDateTime) -> bool {
+        let mut changed = false;
+
+        let response_deadline =
+            self.report_date + chrono::Duration::hours(category.response_sla_hours as i64);
+        if !self.response_sla_breached && self.response_date.unwrap_or(now) > response_deadline {
+            self.response_sla_breached = true;
+            changed = true;
+        }
+
+        let resolution_deadline =
+            self.report_date + chrono::Duration::hours(category.resolution_sla_hours as i64);
+        if !self.resolution_sla_breached
+            && self.resolution_date.unwrap_or(now) > resolution_deadline
+        {
+            self.resolution_sla_breached = true;
+            changed = true;
+        }
+
+        changed
+    }
+
+    /// Moves the incident to `status`, updating progress, SLA flags and the status history.
+    /// Returns the previous status.
+    pub fn db_transition(
+        &mut self,
+        conn: &mut PgConnection,
+        status: IncidentStatus,
+        changed_by: Uuid,
+    ) -> diesel::QueryResult<IncidentStatus> {
+        let old_status = self.status;
+        let now = chrono::Utc::now().naive_utc();
+
+        self.status = status;
+        self.track_progress(now);
+
+        if let Some(category_id) = self.category_id {
+            let category = IncidentCategoryModel::db_read_by_id(conn, category_id)?;
+            self.check_sla(&category, now);
+        }
+
+        self.db_update(conn)?;
+        if self.resolution_date.is_none() {
+            self.db_clear_resolution(conn)?;
+        }
+        self.db_record_status_change(conn, Some(old_status), changed_by)?;
+
+        Ok(old_status)
+    }
+
+    /// Checks that `category_id` is one of the categories of `community_id`.
+    pub fn db_check_category(
+        conn: &mut PgConnection,
+        community_id: Uuid,
+        category_id: Uuid,
+    ) -> diesel::QueryResult<bool> {
+        let category = IncidentCategoryModel::db_read_by_id(conn, category_id)?;
+        Ok(category.community_id == community_id)
+    }
+
+    /// Open incidents with a category whose SLAs have not both been flagged yet.
+    pub fn db_read_all_pending_sla(
+        conn: &mut PgConnection,
+    ) -> diesel::QueryResult<Vec<(IncidentModel, IncidentCategoryModel)>> {
+        incidents::table
+            .inner_join(incident_categories::table)
+            .filter(
+                incidents::status.eq_any(vec![IncidentStatus::Reported, IncidentStatus::Ongoing]),
+            )
+            .filter(
+                incidents::response_sla_breached
+                    .eq(false)
+                    .or(incidents::resolution_sla_breached.eq(false)),
+            )
+            .select((
+                IncidentModel::as_select(),
+                IncidentCategoryModel::as_select(),
+            ))
+            .load::<(IncidentModel, IncidentCategoryModel)>(conn)
+    }
+
+    /// Average time-to-resolution of resolved incidents, overall and per category.
+    pub fn db_resolution_report(
+        conn: &mut PgConnection,
+        community_id: Option<Uuid>,
+        from: Option<NaiveDateTime>,
+        to: Option<NaiveDateTime>,
+    ) -> diesel::QueryResult<IncidentResolutionReport> {
+        let mut query = incidents::table
+            .left_join(incident_categories::table)
+            .filter(incidents::resolution_date.is_not_null())
+            .into_boxed();
+
+        if let Some(community_id) = community_id {
+            query = query.filter(incidents::community_id.eq(community_id));
+        }
+        if let Some(from) = from {
+            query = query.filter(incidents::report_date.ge(from));
+        }
+        if let Some(to) = to {
+            query = query.filter(incidents::report_date.le(to));
+        }
+
+        let rows = query
+            .select((
+                IncidentModel::as_select(),
+                Option::<IncidentCategoryModel>::as_select(),
+            ))
+            .load::<(IncidentModel, Option<IncidentCategoryModel>)>(conn)?;
+
+        let mut categories: Vec<IncidentCategoryResolutionReport> = Vec::new();
+        let mut total_hours = 0f64;
+
+        for (incident, category) in &rows {
+            let hours = resolution_hours(incident);
+            total_hours += hours;
+
+            let category_id = category.as_ref().map(|c| c.id);
+            let entry = match categories.iter_mut().find(|c| c.category_id == category_id) {
+                Some(entry) => entry,
+                None => {
+                    categories.push(IncidentCategoryResolutionReport {
+                        category_id,
+                        category_name: category.as_ref().map(|c| c.name.clone()),
+                        resolved_count: 0,
+                        average_resolution_hours: Some(0.0),
+                        response_sla_breaches: 0,
+                        resolution_sla_breaches: 0,
+                    });
+                    categories.last_mut().unwrap()
+                }
+            };
+
+            entry.resolved_count += 1;
+            entry.average_resolution_hours = entry.average_resolution_hours.map(|h| h + hours);
+            entry.response_sla_breaches += incident.response_sla_breached as i64;
+            entry.resolution_sla_breaches += incident.resolution_sla_breached as i64;
+        }
+
+        for entry in categories.iter_mut() {
+            entry.average_resolution_hours = entry
+                .average_resolution_hours
+                .map(|h| h / entry.resolved_count as f64);
+        }
+
+        Ok(IncidentResolutionReport {
+            resolved_count: rows.len() as i64,
+            average_resolution_hours: if rows.is_empty() {
+                None
+            } else {
+                Some(total_hours / rows.len() as f64)
+            },
+            response_sla_breaches: rows.iter().filter(|(i, _)| i.response_sla_breached).count()
+                as i64,
+            resolution_sla_breaches: rows
+                .iter()
+                .filter(|(i, _)| i.resolution_sla_breached)
+                .count() as i64,
+            categories,
+        })
+    }
+    /// Checks that `assignee_id` belongs to an admin able to manage incidents of `community_id`.
+    pub fn db_check_assignee(
+        conn: &mut PgConnection,
+        community_id: Uuid,
+        assignee_id: Uuid,
+    ) -> diesel::QueryResult<bool> {
+        let role = UserRoleModel::table()
+            .filter(user_roles::user_id.eq(assignee_id))
+            .first::<UserRoleModel>(conn)?;
+
+        Ok(match role.role {
+            UserRoles::Root => true,
+            UserRoles::Admin => role.community_id == Some(community_id),
+            UserRoles::Resident => false,
+        })
+    }
+
+    /// Saves only the SLA flags, so edits made since the incident was read are kept.
+    pub fn db_update_sla_flags(&self, conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::update(incidents::table.find(self.id))
+            .set((
+                incidents::response_sla_breached.eq(self.response_sla_breached),
+                incidents::resolution_sla_breached.eq(self.resolution_sla_breached),
+            ))
+            .execute(conn)
+    }
+
+    /// Removes the assignee, which `db_update` leaves untouched when `assignee_id` is `None`.
+    pub fn db_unassign(&self, conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::update(incidents::table.find(self.id))
+            .set(incidents::assignee_id.eq(None::<Uuid>))
+            .execute(conn)
+    }
+
+    /// Removes the category, which `db_update` leaves untouched when `category_id` is `None`.
+    pub fn db_uncategorise(&self, conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::update(incidents::table.find(self.id))
+            .set(incidents::category_id.eq(None::<Uuid>))
+            .execute(conn)
+    }
+
+    /// Clears the resolution date of a reopened incident, which `db_update` leaves untouched when
+    /// it's `None`.
+    pub fn db_clear_resolution(&self, conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::update(incidents::table.find(self.id))
+            .set(incidents::resolution_date.eq(None::<NaiveDateTime>))
+            .execute(conn)
+    }
+
+    /// Appends an entry to the incident's status history. Entries are never updated.
+    pub fn db_record_status_change(
+        &self,
+        conn: &mut PgConnection,
+        old_status: Option<IncidentStatus>,
+        changed_by: Uuid,
+    ) -> diesel::QueryResult<usize> {
+        IncidentStatusHistoryModel {
+            id: IncidentStatusHistoryModel::new_id(conn),
+            incident_id: self.id,
+            old_status,
+            new_status: self.status,
+            changed_by: Some(changed_by),
+            changed_at: chrono::Utc::now().naive_utc(),
+        }
+        .db_insert(conn)
+    }
+
+    /// Template parameters for the status change email sent to the reporting resident.
+    pub fn email_parameters(
+        &self,
+        resident: &resident_model::ResidentModel,
+        old_status: IncidentStatus,
+    ) -> Vec<SmtpTemplateData> {
+        vec![
+            SmtpTemplateData {
+                key: "{{USER_NAME}}".to_string(),
+                value: escape_html(&resident.first_name),
+            },
+            SmtpTemplateData {
+                key: "{{INCIDENT_NAME}}".to_string(),
+                value: escape_html(&self.name),
+            },
+            SmtpTemplateData {
+                key: "{{OLD_STATUS}}".to_string(),
+                value: old_status.label().to_string(),
+            },
+            SmtpTemplateData {
+                key: "{{NEW_STATUS}}".to_string(),
+                value: self.status.label().to_string(),
+            },
+            SmtpTemplateData {
+                key: "{{CURRENT_YEAR}}".to_string(),
+                value: chrono::Utc::now().year().to_string(),
+            },
+        ]
+    }
+
     pub fn db_read_by_id_matching_resident(
         user_role: user_role_model::UserRoleModel,
         conn: &mut PgConnection,
@@ -86,6 +551,7 @@ impl IncidentModel {
     pub fn db_read_all_matching_by_range(
         user_role: UserRoleModel,
         conn: &mut PgConnection,
+        filters: &IncidentFilterParams,
         per_page: i64,
         offset: i64,
     ) -> diesel::QueryResult<Vec<IncidentModel>> {
@@ -105,6 +571,20 @@ impl IncidentModel {
             UserRoles::Resident => query = query.filter(users::id.eq(user_role.user_id)),
         }
 
+        if let Some(category_id) = filters.category_id {
+            query = query.filter(incidents::category_id.eq(category_id));
+        }
+        if let Some(priority) = filters.priority {
+            query = query.filter(incidents::priority.eq(priority));
+        }
+        if let Some(sla_breached) = filters.sla_breached {
+            query = query.filter(
+                incidents::response_sla_breached
+                    .or(incidents::resolution_sla_breached)
+                    .eq(sla_breached),
+            );
+        }
+
         query
             .select(incidents::all_columns)
             .limit(per_page)
@@ -115,6 +595,7 @@ impl IncidentModel {
     pub fn db_count_all_matching(
         user_role: UserRoleModel,
         conn: &mut PgConnection,
+        filters: &IncidentFilterParams,
     ) -> diesel::QueryResult<i64> {
         let mut query = UserRoleModel::table()
             .inner_join(users::table.on(user_roles::user_id.eq(users::id)))
@@ -132,6 +613,20 @@ impl IncidentModel {
             UserRoles::Resident => query = query.filter(users::id.eq(user_role.user_id)),
         }
 
+        if let Some(category_id) = filters.category_id {
+            query = query.filter(incidents::category_id.eq(category_id));
+        }
+        if let Some(priority) = filters.priority {
+            query = query.filter(incidents::priority.eq(priority));
+        }
+        if let Some(sla_breached) = filters.sla_breached {
+            query = query.filter(
+                incidents::response_sla_breached
+                    .or(incidents::resolution_sla_breached)
+                    .eq(sla_breached),
+            );
+        }
+
         query
             .select(incidents::all_columns)
             .count()
@@ -139,6 +634,84 @@ impl IncidentModel {
     }
 }
 
+fn resolution_hours(incident: &IncidentModel) -> f64 {
+    match incident.resolution_date {
+        Some(resolution_date) => {
+            (resolution_date - incident.report_date)
+                .num_seconds()
+                .max(0) as f64
+                / 3600.0
+        }
+        None => 0.0,
+    }
+}
+
+impl IncidentCategoryModel {
+    pub fn db_read_by_id_matching(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        id: Uuid,
+    ) -> diesel::QueryResult<IncidentCategoryModel> {
+        let category = IncidentCategoryModel::db_read_by_id(conn, id)?;
+
+        match user_role.role {
+            UserRoles::Root => {}
+            UserRoles::Admin | UserRoles::Resident => {
+                if user_role.community_id != Some(category.community_id) {
+                    return Err(diesel::result::Error::NotFound);
+                }
+            }
+        }
+
+        Ok(category)
+    }
+
+    pub fn db_read_all_matching(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+    ) -> diesel::QueryResult<Vec<IncidentCategoryModel>> {
+        let mut query = IncidentCategoryModel::table().into_boxed();
+
+        match user_role.role {
+            UserRoles::Root => {}
+            UserRoles::Admin | UserRoles::Resident => {
+                let community_id = user_role
+                    .community_id
+                    .ok_or(diesel::result::Error::NotFound)?;
+                query = query.filter(incident_categories::community_id.eq(community_id));
+            }
+        }
+
+        query
+            .order(incident_categories::name.asc())
+            .load::<IncidentCategoryModel>(conn)
+    }
+}
+
+impl IncidentCommentModel {
+    pub fn db_read_all_by_incident(
+        conn: &mut PgConnection,
+        incident_id: Uuid,
+    ) -> diesel::QueryResult<Vec<IncidentCommentModel>> {
+        incident_comments::table
+            .filter(incident_comments::incident_id.eq(incident_id))
+            .order(incident_comments::created_at.asc())
+            .load::<IncidentCommentModel>(conn)
+    }
+}
+
+impl IncidentStatusHistoryModel {
+    pub fn db_read_all_by_incident(
+        conn: &mut PgConnection,
+        incident_id: Uuid,
+    ) -> diesel::QueryResult<Vec<IncidentStatusHistoryModel>> {
+        incident_status_history::table
+            .filter(incident_status_history::incident_id.eq(incident_id))
+            .order(incident_status_history::changed_at.asc())
+            .load::<IncidentStatusHistoryModel>(conn)
+    }
+}
+
 impl<DB> ToSql<diesel::sql_types::Text, DB> for IncidentStatus
 where
     DB: Backend,
@@ -170,3 +743,35 @@ where
         }
     }
 }
+
+impl<DB> ToSql<diesel::sql_types::Text, DB> for IncidentPriority
+where
+    DB: Backend,
+    str: ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        let s = match self {
+            IncidentPriority::Low => "Low",
+            IncidentPriority::Medium => "Medium",
+            IncidentPriority::High => "High",
+            IncidentPriority::Urgent => "Urgent",
+        };
+        s.to_sql(out)
+    }
+}
+
+impl<DB> FromSql<diesel::sql_types::Text, DB> for IncidentPriority
+where
+    DB: Backend,
+    String: FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        match <String as FromSql<diesel::sql_types::Text, DB>>::from_sql(bytes)?.as_str() {
+            "Low" => Ok(IncidentPriority::Low),
+            "Medium" => Ok(IncidentPriority::Medium),
+            "High" => Ok(IncidentPriority::High),
+            "Urgent" => Ok(IncidentPriority::Urgent),
+            x => Err(format!("Unrecognized variant {}", x).into()),
+        }
+    }
+}
@@ -27,11 +27,17 @@ pub struct InvoiceModel {
     pub paid_date: Option<NaiveDate>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub unit_id: Option<Uuid>,
+    pub charge_type: InvoiceChargeType,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
 pub struct InvoiceModelNew {
-    pub resident_id: Uuid,
+    /// Required unless `unit_id` is given, in which case the unit's responsible resident is billed.
+    pub resident_id: Option<Uuid>,
+    pub unit_id: Option<Uuid>,
+    #[serde(default)]
+    pub charge_type: InvoiceChargeType,
     pub community_id: Uuid,
     pub issue_date: NaiveDate,
     pub due_date: NaiveDate,
@@ -51,7 +57,75 @@ pub enum InvoiceStatus {
     Overdue,
 }
 
+/// Ordinary charges are the monthly fee, extraordinary ones are apportioned works and the like.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    AsExpression,
+    FromSqlRow,
+    Default,
+)]
+#[diesel(sql_type = diesel::sql_types::Text)]
+pub enum InvoiceChargeType {
+    #[default]
+    Ordinary,
+    Extraordinary,
+}
+
+impl InvoiceModelNew {
+    /// Resident to bill: the unit's responsible resident for the charge type on the issue date
+    /// when a unit is given, the requested resident otherwise.
+    pub fn db_resolve_resident(
+        &self,
+        conn: &mut PgConnection,
+    ) -> diesel::QueryResult<Option<Uuid>> {
+        match self.unit_id {
+            Some(unit_id) => {
+                let unit = unit_model::UnitModel::db_read_by_id(conn, unit_id)?;
+
+                if unit.community_id != self.community_id {
+                    return Ok(None);
+                }
+
+                unit_model::UnitModel::db_responsible_resident(
+                    conn,
+                    unit_id,
+                    self.charge_type,
+                    self.issue_date,
+                )
+            }
+            None => Ok(self.resident_id),
+        }
+    }
+}
+
 impl InvoiceModel {
+    /// Removes the payment date of an invoice that is no longer paid, which `db_update` leaves
+    /// untouched when `paid_date` is `None`.
+    pub fn db_clear_paid_date(&self, conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::update(invoices::table.find(self.id))
+            .set(invoices::paid_date.eq(None::<NaiveDate>))
+            .execute(conn)
+    }
+
+    /// Invoices of `resident_id` that are not paid yet.
+    pub fn db_read_open_by_resident(
+        conn: &mut PgConnection,
+        resident_id: Uuid,
+    ) -> diesel::QueryResult<Vec<InvoiceModel>> {
+        invoices::table
+            .filter(invoices::resident_id.eq(resident_id))
+            .filter(invoices::status.ne(InvoiceStatus::Paid))
+            .order(invoices::due_date.asc())
+            .load::<InvoiceModel>(conn)
+    }
+
     pub fn db_read_by_id_matching_resident(
         user_role: user_role_model::UserRoleModel,
         conn: &mut PgConnection,
@@ -166,3 +240,31 @@ where
         }
     }
 }
+
+impl<DB> ToSql<diesel::sql_types::Text, DB> for InvoiceChargeType
+where
+    DB: Backend,
+    str: ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        let s = match self {
+            InvoiceChargeType::Ordinary => "Ordinary",
+            InvoiceChargeType::Extraordinary => "Extraordinary",
+        };
+        s.to_sql(out)
+    }
+}
+
+impl<DB> FromSql<diesel::sql_types::Text, DB> for InvoiceChargeType
+where
+    DB: Backend,
+    String: FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        match <String as FromSql<diesel::sql_types::Text, DB>>::from_sql(bytes)?.as_str() {
+            "Ordinary" => Ok(InvoiceChargeType::Ordinary),
+            "Extraordinary" => Ok(InvoiceChargeType::Extraordinary),
+            x => Err(format!("Unrecognized variant {}", x).into()),
+        }
+    }
+}
@@ -61,3 +61,13 @@ where
         .map(Some)
         .map_err(serde::de::Error::custom)
 }
+
+/// Tells a missing field from an explicit `null`: missing stays `None`, `null` becomes
+/// `Some(None)`. Use it with `#[serde(default)]`.
+pub fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
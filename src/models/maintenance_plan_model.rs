@@ -0,0 +1,297 @@
+use super::prelude::*;
+use super::*;
+use crate::services::UserRoles;
+use chrono::Months;
+use maintenance_schedule_model::{MaintenanceScheduleModel, MaintenanceScheduleStatus};
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = maintenance_plans)]
+pub struct MaintenancePlanModel {
+    pub id: Uuid,
+    pub community_id: Uuid,
+    #[validate(length(min = 5, message = "Description is too short"))]
+    pub description: String,
+    pub details: Option<String>,
+    pub frequency: MaintenancePlanFrequency,
+    #[validate(range(min = 1, message = "Interval must be at least one"))]
+    pub frequency_interval: i32,
+    pub start_date: NaiveDateTime,
+    pub end_date: Option<NaiveDateTime>,
+    pub active: bool,
+    /// Number of occurrences already turned into maintenance schedules.
+    pub occurrence_count: i32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub vendor_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct MaintenancePlanModelNew {
+    pub community_id: Uuid,
+    #[validate(length(min = 5, message = "Description is too short"))]
+    pub description: String,
+    pub details: Option<String>,
+    pub frequency: MaintenancePlanFrequency,
+    #[validate(range(min = 1, message = "Interval must be at least one"))]
+    pub frequency_interval: i32,
+    pub start_date: NaiveDateTime,
+    pub end_date: Option<NaiveDateTime>,
+    pub active: bool,
+    pub vendor_id: Option<Uuid>,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = diesel::sql_types::Text)]
+pub enum MaintenancePlanFrequency {
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl MaintenancePlanModel {
+    /// Date of the `n`-th occurrence, counted from `start_date` so month ends do not drift.
+    pub fn occurrence(&self, n: i32) -> Option<NaiveDateTime> {
+        if n < 0 {
+            return None;
+        }
+
+        let steps = n.checked_mul(self.frequency_interval)? as u32;
+
+        match self.frequency {
+            MaintenancePlanFrequency::Weekly => self
+                .start_date
+                .checked_add_signed(chrono::Duration::weeks(steps as i64)),
+            MaintenancePlanFrequency::Monthly => {
+                self.start_date.checked_add_months(Months::new(steps))
+            }
+            MaintenancePlanFrequency::Yearly => self
+                .start_date
+                .checked_add_months(Months::new(steps.checked_mul(12)?)),
+        }
+    }
+
+    /// Date of the next occurrence that has not been scheduled yet, if the plan is still running.
+    pub fn next_occurrence(&self) -> Option<NaiveDateTime> {
+        if !self.active {
+            return None;
+        }
+
+        let next = self.occurrence(self.occurrence_count)?;
+
+        match self.end_date {
+            Some(end_date) if next > end_date => None,
+            _ => Some(next),
+        }
+    }
+
+    /// Skips occurrences up to `after` so a rescheduled plan does not backfill the past.
+    pub fn skip_until(&mut self, after: NaiveDateTime) {
+        self.occurrence_count = 0;
+
+        while let Some(next) = self.occurrence(self.occurrence_count) {
+            if next > after {
+                break;
+            }
+            self.occurrence_count += 1;
+        }
+    }
+
+    /// Schedules the occurrence at `scheduled_date` and counts it. Only called with the plan
+    /// locked.
+    fn db_schedule_next(
+        &mut self,
+        conn: &mut PgConnection,
+        scheduled_date: NaiveDateTime,
+    ) -> diesel::QueryResult<MaintenanceScheduleModel> {
+        let now = chrono::Utc::now().naive_utc();
+
+        let schedule = MaintenanceScheduleModel {
+            id: MaintenanceScheduleModel::new_id(conn),
+            community_id: Some(self.community_id),
+            description: self.description.clone(),
+            scheduled_date,
+            status: MaintenanceScheduleStatus::Scheduled,
+            details: self.details.clone(),
+            created_at: now,
+            updated_at: now,
+            incident_id: None,
+            plan_id: Some(self.id),
+            vendor_id: self.vendor_id,
+            cost: None,
+            invoice_number: None,
+            completed_at: None,
+            reminder_sent_at: None,
+        };
+
+        schedule.db_insert(conn)?;
+
+        self.occurrence_count += 1;
+        self.updated_at = now;
+        diesel::update(maintenance_plans::table.find(self.id))
+            .set((
+                maintenance_plans::occurrence_count.eq(self.occurrence_count),
+                maintenance_plans::updated_at.eq(self.updated_at),
+            ))
+            .execute(conn)?;
+
+        Ok(schedule)
+    }
+
+    /// Reloads the plan with its row locked until the transaction ends, so the scheduler and
+    /// completed maintenances don't schedule the same occurrence.
+    fn db_lock(&mut self, conn: &mut PgConnection) -> diesel::QueryResult<()> {
+        *self = maintenance_plans::table
+            .find(self.id)
+            .for_update()
+            .first::<MaintenancePlanModel>(conn)?;
+
+        Ok(())
+    }
+
+    /// Creates maintenance schedules for every occurrence up to `until`.
+    pub fn db_materialise_until(
+        &mut self,
+        conn: &mut PgConnection,
+        until: NaiveDateTime,
+    ) -> diesel::QueryResult<Vec<MaintenanceScheduleModel>> {
+        conn.transaction(|conn| {
+            self.db_lock(conn)?;
+
+            let mut created = Vec::new();
+            if !self.active {
+                return Ok(created);
+            }
+
+            while let Some(next) = self.next_occurrence() {
+                if next > until {
+                    break;
+                }
+                created.push(self.db_schedule_next(conn, next)?);
+            }
+
+            Ok(created)
+        })
+    }
+
+    /// Creates the next occurrence when the plan has no pending maintenance left,
+    /// regardless of how far ahead it falls.
+    pub fn db_materialise_next(
+        &mut self,
+        conn: &mut PgConnection,
+    ) -> diesel::QueryResult<Option<MaintenanceScheduleModel>> {
+        conn.transaction(|conn| {
+            self.db_lock(conn)?;
+
+            if !self.active {
+                return Ok(None);
+            }
+
+            let pending = maintenance_schedules::table
+                .filter(maintenance_schedules::plan_id.eq(self.id))
+                .filter(maintenance_schedules::status.ne(MaintenanceScheduleStatus::Completed))
+                .count()
+                .get_result::<i64>(conn)?;
+
+            if pending > 0 {
+                return Ok(None);
+            }
+
+            match self.next_occurrence() {
+                Some(next) => Ok(Some(self.db_schedule_next(conn, next)?)),
+                None => Ok(None),
+            }
+        })
+    }
+
+    pub fn db_read_all_active(
+        conn: &mut PgConnection,
+    ) -> diesel::QueryResult<Vec<MaintenancePlanModel>> {
+        maintenance_plans::table
+            .filter(maintenance_plans::active.eq(true))
+            .load::<MaintenancePlanModel>(conn)
+    }
+
+    pub fn db_read_by_id_matching_community(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        id: Uuid,
+    ) -> diesel::QueryResult<MaintenancePlanModel> {
+        let plan = MaintenancePlanModel::db_read_by_id(conn, id)?;
+
+        match user_role.role {
+            UserRoles::Root => {}
+            UserRoles::Admin | UserRoles::Resident => {
+                if user_role.community_id != Some(plan.community_id) {
+                    return Err(diesel::result::Error::NotFound);
+                }
+            }
+        }
+
+        Ok(plan)
+    }
+
+    pub fn db_read_all_matching_community(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+    ) -> diesel::QueryResult<Vec<MaintenancePlanModel>> {
+        let mut query = MaintenancePlanModel::table().into_boxed();
+
+        match user_role.role {
+            UserRoles::Root => {}
+            UserRoles::Admin | UserRoles::Resident => {
+                let community_id = user_role
+                    .community_id
+                    .ok_or(diesel::result::Error::NotFound)?;
+                query = query.filter(maintenance_plans::community_id.eq(community_id));
+            }
+        }
+
+        query
+            .order(maintenance_plans::start_date.asc())
+            .load::<MaintenancePlanModel>(conn)
+    }
+}
+
+impl<DB> ToSql<diesel::sql_types::Text, DB> for MaintenancePlanFrequency
+where
+    DB: Backend,
+    str: ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        let s = match self {
+            MaintenancePlanFrequency::Weekly => "Weekly",
+            MaintenancePlanFrequency::Monthly => "Monthly",
+            MaintenancePlanFrequency::Yearly => "Yearly",
+        };
+        s.to_sql(out)
+    }
+}
+
+impl<DB> FromSql<diesel::sql_types::Text, DB> for MaintenancePlanFrequency
+where
+    DB: Backend,
+    String: FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        match <String as FromSql<diesel::sql_types::Text, DB>>::from_sql(bytes)?.as_str() {
+            "Weekly" => Ok(MaintenancePlanFrequency::Weekly),
+            "Monthly" => Ok(MaintenancePlanFrequency::Monthly),
+            "Yearly" => Ok(MaintenancePlanFrequency::Yearly),
+            x => Err(format!("Unrecognized variant {}", x).into()),
+        }
+    }
+}
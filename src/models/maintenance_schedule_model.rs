@@ -1,6 +1,8 @@
 use super::prelude::*;
 use super::*;
+use crate::internal::smtp::smtp_templates::{SmtpTemplateData, escape_html};
 use crate::services::UserRoles;
+use chrono::Datelike;
 use validator::ValidateLength;
 
 #[derive(
@@ -26,6 +28,14 @@ pub struct MaintenanceScheduleModel {
     pub details: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub incident_id: Option<Uuid>,
+    pub plan_id: Option<Uuid>,
+    pub vendor_id: Option<Uuid>,
+    #[schema(value_type = Option<f64>)]
+    pub cost: Option<BigDecimal>,
+    pub invoice_number: Option<String>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub reminder_sent_at: Option<NaiveDateTime>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
@@ -35,6 +45,34 @@ pub struct MaintenanceScheduleModelNew {
     pub scheduled_date: NaiveDateTime,
     pub status: MaintenanceScheduleStatus,
     pub details: Option<String>,
+    pub vendor_id: Option<Uuid>,
+    #[schema(value_type = Option<f64>)]
+    pub cost: Option<BigDecimal>,
+    pub invoice_number: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct MaintenanceExpenseReportParams {
+    pub community_id: Option<Uuid>,
+    pub from: Option<NaiveDateTime>,
+    pub to: Option<NaiveDateTime>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct MaintenanceExpenseReport {
+    pub completed_count: i64,
+    #[schema(value_type = f64)]
+    pub total_cost: BigDecimal,
+    pub vendors: Vec<MaintenanceVendorExpense>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct MaintenanceVendorExpense {
+    pub vendor_id: Option<Uuid>,
+    pub company_name: Option<String>,
+    pub completed_count: i64,
+    #[schema(value_type = f64)]
+    pub total_cost: BigDecimal,
 }
 
 #[derive(
@@ -49,6 +87,137 @@ pub enum MaintenanceScheduleStatus {
 }
 
 impl MaintenanceScheduleModel {
+    /// Template parameters for the maintenance emails sent to residents of the community.
+    pub fn email_parameters(
+        &self,
+        resident: &resident_model::ResidentModel,
+    ) -> Vec<SmtpTemplateData> {
+        vec![
+            SmtpTemplateData {
+                key: "{{USER_NAME}}".to_string(),
+                value: escape_html(&resident.first_name),
+            },
+            SmtpTemplateData {
+                key: "{{MAINTENANCE_DESCRIPTION}}".to_string(),
+                value: escape_html(&self.description),
+            },
+            SmtpTemplateData {
+                key: "{{MAINTENANCE_DATE}}".to_string(),
+                value: self.scheduled_date.format("%d/%m/%Y %H:%M").to_string(),
+            },
+            SmtpTemplateData {
+                key: "{{MAINTENANCE_DETAILS}}".to_string(),
+                value: escape_html(self.details.as_deref().unwrap_or_default()),
+            },
+            SmtpTemplateData {
+                key: "{{CURRENT_YEAR}}".to_string(),
+                value: chrono::Utc::now().year().to_string(),
+            },
+        ]
+    }
+
+    /// Scheduled maintenance starting before `until` whose reminder has not been sent yet.
+    pub fn db_read_all_pending_reminder(
+        conn: &mut PgConnection,
+        now: NaiveDateTime,
+        until: NaiveDateTime,
+    ) -> diesel::QueryResult<Vec<MaintenanceScheduleModel>> {
+        maintenance_schedules::table
+            .filter(maintenance_schedules::status.eq(MaintenanceScheduleStatus::Scheduled))
+            .filter(maintenance_schedules::reminder_sent_at.is_null())
+            .filter(maintenance_schedules::community_id.is_not_null())
+            .filter(maintenance_schedules::scheduled_date.gt(now))
+            .filter(maintenance_schedules::scheduled_date.le(until))
+            .load::<MaintenanceScheduleModel>(conn)
+    }
+
+    /// Clears the reminder so a rescheduled maintenance is announced again the day before.
+    pub fn db_reset_reminder(&self, conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::update(maintenance_schedules::table.find(self.id))
+            .set(maintenance_schedules::reminder_sent_at.eq(None::<NaiveDateTime>))
+            .execute(conn)
+    }
+
+    /// Checks that `vendor_id` is registered in `community_id`.
+    pub fn db_check_vendor(
+        conn: &mut PgConnection,
+        community_id: Option<Uuid>,
+        vendor_id: Uuid,
+    ) -> diesel::QueryResult<bool> {
+        let vendor = vendor_model::VendorModel::db_read_by_id(conn, vendor_id)?;
+        Ok(community_id == Some(vendor.community_id))
+    }
+
+    /// Cost of completed maintenance, overall and per vendor, by completion date.
+    pub fn db_expense_report(
+        conn: &mut PgConnection,
+        community_id: Option<Uuid>,
+        from: Option<NaiveDateTime>,
+        to: Option<NaiveDateTime>,
+    ) -> diesel::QueryResult<MaintenanceExpenseReport> {
+        let mut query = maintenance_schedules::table
+            .left_join(
+                vendors::table.on(maintenance_schedules::vendor_id.eq(vendors::id.nullable())),
+            )
+            .filter(maintenance_schedules::status.eq(MaintenanceScheduleStatus::Completed))
+            .into_boxed();
+
+        if let Some(community_id) = community_id {
+            query = query.filter(maintenance_schedules::community_id.eq(community_id));
+        }
+        if let Some(from) = from {
+            query = query.filter(maintenance_schedules::completed_at.ge(from));
+        }
+        if let Some(to) = to {
+            query = query.filter(maintenance_schedules::completed_at.le(to));
+        }
+
+        let rows = query
+            .select((
+                MaintenanceScheduleModel::as_select(),
+                Option::<vendor_model::VendorModel>::as_select(),
+            ))
+            .load::<(MaintenanceScheduleModel, Option<vendor_model::VendorModel>)>(conn)?;
+
+        let mut vendors: Vec<MaintenanceVendorExpense> = Vec::new();
+        let mut total_cost = BigDecimal::from(0);
+
+        for (maintenance, vendor) in &rows {
+            let cost = maintenance.cost.clone().unwrap_or_default();
+            total_cost += &cost;
+
+            let vendor_id = vendor.as_ref().map(|v| v.id);
+            match vendors.iter_mut().find(|v| v.vendor_id == vendor_id) {
+                Some(entry) => {
+                    entry.completed_count += 1;
+                    entry.total_cost += cost;
+                }
+                None => vendors.push(MaintenanceVendorExpense {
+                    vendor_id,
+                    company_name: vendor.as_ref().map(|v| v.company_name.clone()),
+                    completed_count: 1,
+                    total_cost: cost,
+                }),
+            }
+        }
+
+        Ok(MaintenanceExpenseReport {
+            completed_count: rows.len() as i64,
+            total_cost,
+            vendors,
+        })
+    }
+
+    pub fn db_read_by_incident(
+        conn: &mut PgConnection,
+        incident_id: Uuid,
+    ) -> diesel::QueryResult<Option<MaintenanceScheduleModel>> {
+        maintenance_schedules::table
+            .filter(maintenance_schedules::incident_id.eq(incident_id))
+            .first::<MaintenanceScheduleModel>(conn)
+            .optional()
+    }
+
     pub fn db_read_by_id_matching_community(
         user_role: user_role_model::UserRoleModel,
         conn: &mut PgConnection,
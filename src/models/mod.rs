@@ -1,5 +1,7 @@
 pub mod admin_model;
 pub mod announcement_model;
+pub mod api_key_model;
+pub mod attachment_model;
 pub mod auth_model;
 pub mod auth_token_model;
 pub mod common_area_model;
@@ -8,12 +10,21 @@ pub mod election_model;
 pub mod incident_model;
 pub mod invoice_model;
 mod lib;
+pub mod maintenance_plan_model;
 pub mod maintenance_schedule_model;
+pub mod oidc_model;
 pub mod parcel_model;
+pub mod parking_spot_model;
 pub mod prelude;
 pub mod reservation_model;
 pub mod resident_model;
+pub mod service_account_model;
+pub mod sign_in_attempt_model;
+pub mod two_factor_model;
+pub mod unit_model;
 pub mod user_model;
 pub mod user_role_model;
 pub mod vehicle_model;
+pub mod vendor_model;
+pub mod visitor_model;
 pub mod vote_model;
@@ -0,0 +1,158 @@
+use super::prelude::*;
+use crate::utilities::auth_utils::hash_token;
+
+/// Minutes the user has to sign in at the provider and come back.
+pub const OIDC_STATE_EXPIRATION_MINUTES: i64 = 10;
+
+/// Login started at a provider, looked up by its `state` when the user comes back.
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::oidc_states)]
+pub struct OidcStateModel {
+    pub id: Uuid,
+    pub provider: String,
+    pub state_hash: String,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+}
+
+/// Identity at an external provider linked to a user.
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::user_identities)]
+pub struct UserIdentityModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub provider: String,
+    /// `sub` claim, which identifies the user at the provider.
+    pub subject: String,
+    pub email: String,
+    pub created_at: NaiveDateTime,
+    pub last_used_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct OidcProviderResult {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct OidcAuthorizationResult {
+    /// Provider page the user is sent to.
+    pub authorization_url: String,
+    pub expires_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct OidcCallbackModel {
+    pub code: String,
+    pub state: String,
+}
+
+impl OidcStateModel {
+    pub fn new(
+        conn: &mut PgConnection,
+        provider: String,
+        state: &str,
+        nonce: String,
+        code_verifier: String,
+    ) -> Self {
+        let now = chrono::Utc::now().naive_utc();
+
+        OidcStateModel {
+            id: OidcStateModel::new_id(conn),
+            provider,
+            state_hash: hash_token(state),
+            nonce,
+            code_verifier,
+            created_at: now,
+            expires_at: now + chrono::Duration::minutes(OIDC_STATE_EXPIRATION_MINUTES),
+        }
+    }
+
+    /// Removes and returns the login of the provider started with `state`, if it hasn't expired.
+    pub fn db_take_valid(
+        conn: &mut PgConnection,
+        provider: &str,
+        state: &str,
+    ) -> diesel::QueryResult<Self> {
+        diesel::delete(
+            oidc_states::table
+                .filter(oidc_states::provider.eq(provider))
+                .filter(oidc_states::state_hash.eq(hash_token(state)))
+                .filter(oidc_states::expires_at.gt(chrono::Utc::now().naive_utc())),
+        )
+        .get_result::<Self>(conn)
+    }
+
+    pub fn db_delete_expired(conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::delete(
+            oidc_states::table.filter(oidc_states::expires_at.le(chrono::Utc::now().naive_utc())),
+        )
+        .execute(conn)
+    }
+}
+
+impl UserIdentityModel {
+    pub fn new(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        provider: String,
+        subject: String,
+        email: String,
+    ) -> Self {
+        let now = chrono::Utc::now().naive_utc();
+
+        UserIdentityModel {
+            id: UserIdentityModel::new_id(conn),
+            user_id,
+            provider,
+            subject,
+            email,
+            created_at: now,
+            last_used_at: now,
+        }
+    }
+
+    pub fn db_read_by_subject(
+        conn: &mut PgConnection,
+        provider: &str,
+        subject: &str,
+    ) -> diesel::QueryResult<Option<Self>> {
+        user_identities::table
+            .filter(user_identities::provider.eq(provider))
+            .filter(user_identities::subject.eq(subject))
+            .first::<Self>(conn)
+            .optional()
+    }
+
+    pub fn db_touch(&self, conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::update(user_identities::table.find(self.id))
+            .set(user_identities::last_used_at.eq(chrono::Utc::now().naive_utc()))
+            .execute(conn)
+    }
+}
@@ -1,5 +1,7 @@
 use super::prelude::*;
 use super::*;
+use crate::internal::smtp::smtp_templates::{SmtpTemplateData, escape_html};
+use chrono::Datelike;
 
 #[derive(
     Queryable,
@@ -23,6 +25,9 @@ pub struct ParcelModel {
     pub arrival_date: NaiveDateTime,
     pub received: bool,
     pub received_at: Option<NaiveDateTime>,
+    pub reminder_sent_at: Option<NaiveDateTime>,
+    pub flagged_for_return: bool,
+    pub flagged_for_return_at: Option<NaiveDateTime>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
@@ -45,7 +50,47 @@ pub enum ParcelType {
     Groceries,
 }
 
+impl ParcelType {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ParcelType::Letter => "Carta",
+            ParcelType::Package => "Pacote",
+            ParcelType::Groceries => "Compras",
+        }
+    }
+}
+
 impl ParcelModel {
+    /// Template parameters shared by the parcel arrival, reminder and return emails.
+    pub fn email_parameters(
+        &self,
+        resident: &resident_model::ResidentModel,
+    ) -> Vec<SmtpTemplateData> {
+        let description = match &self.description {
+            Some(desc) => format!("{} ({})", self.parcel_type.label(), desc),
+            None => self.parcel_type.label().to_string(),
+        };
+
+        vec![
+            SmtpTemplateData {
+                key: "{{USER_NAME}}".to_string(),
+                value: escape_html(&resident.first_name),
+            },
+            SmtpTemplateData {
+                key: "{{PARCEL_DESCRIPTION}}".to_string(),
+                value: escape_html(&description),
+            },
+            SmtpTemplateData {
+                key: "{{ARRIVAL_DATE}}".to_string(),
+                value: self.arrival_date.format("%d/%m/%Y %H:%M").to_string(),
+            },
+            SmtpTemplateData {
+                key: "{{CURRENT_YEAR}}".to_string(),
+                value: chrono::Utc::now().year().to_string(),
+            },
+        ]
+    }
+
     pub fn db_read_by_id_matching_resident(
         user_role: user_role_model::UserRoleModel,
         conn: &mut PgConnection,
@@ -0,0 +1,78 @@
+use super::prelude::*;
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = parking_spots)]
+pub struct ParkingSpotModel {
+    pub id: Uuid,
+    pub community_id: Uuid,
+    #[validate(length(min = 1, max = 20, message = "Code is too short or too long"))]
+    pub code: String,
+    pub notes: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub unit_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ParkingSpotModelNew {
+    pub community_id: Uuid,
+    #[validate(length(min = 1, max = 20, message = "Code is too short or too long"))]
+    pub code: String,
+    pub unit_id: Option<Uuid>,
+    pub notes: Option<String>,
+}
+
+impl ParkingSpotModel {
+    pub fn db_read_by_id_matching_community(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        id: Uuid,
+    ) -> diesel::QueryResult<ParkingSpotModel> {
+        let spot = ParkingSpotModel::db_read_by_id(conn, id)?;
+
+        match user_role.role {
+            UserRoles::Root => {}
+            UserRoles::Admin | UserRoles::Resident => {
+                if user_role.community_id != Some(spot.community_id) {
+                    return Err(diesel::result::Error::NotFound);
+                }
+            }
+        }
+
+        Ok(spot)
+    }
+
+    pub fn db_read_all_matching_community(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+    ) -> diesel::QueryResult<Vec<ParkingSpotModel>> {
+        let mut query = ParkingSpotModel::table().into_boxed();
+
+        match user_role.role {
+            UserRoles::Root => {}
+            UserRoles::Admin | UserRoles::Resident => {
+                query = query.filter(
+                    parking_spots::community_id
+                        .nullable()
+                        .eq(user_role.community_id),
+                );
+            }
+        }
+
+        query
+            .order(parking_spots::code.asc())
+            .load::<ParkingSpotModel>(conn)
+    }
+}
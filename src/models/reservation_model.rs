@@ -45,6 +45,7 @@ pub enum ReservationStatus {
     Reserved,
     Ongoing,
     Finished,
+    Cancelled,
 }
 
 impl<DB> ToSql<diesel::sql_types::Text, DB> for ReservationStatus
@@ -57,6 +58,7 @@ where
             ReservationStatus::Reserved => "Reserved",
             ReservationStatus::Ongoing => "Ongoing",
             ReservationStatus::Finished => "Finished",
+            ReservationStatus::Cancelled => "Cancelled",
         };
         s.to_sql(out)
     }
@@ -72,6 +74,7 @@ where
             "Reserved" => Ok(ReservationStatus::Reserved),
             "Ongoing" => Ok(ReservationStatus::Ongoing),
             "Finished" => Ok(ReservationStatus::Finished),
+            "Cancelled" => Ok(ReservationStatus::Cancelled),
             x => Err(format!("Unrecognized variant {}", x).into()),
         }
     }
@@ -84,6 +87,7 @@ impl ReservationModel {
 
         let overlapping_reservations = reservations
             .filter(common_area_id.eq(self.common_area_id))
+            .filter(status.ne(ReservationStatus::Cancelled))
             .filter(id.ne(self.id))
             .filter(
                 start_time
@@ -204,6 +208,7 @@ impl ReservationModelNew {
 
         let overlapping_reservations = reservations
             .filter(common_area_id.eq(self.common_area_id))
+            .filter(status.ne(ReservationStatus::Cancelled))
             .filter(
                 start_time
                     .le(self.end_time)
@@ -1,4 +1,8 @@
 use super::prelude::*;
+use crate::internal::smtp::smtp_templates::{SmtpTemplateData, escape_html};
+use crate::models::reservation_model::ReservationStatus;
+use chrono::Datelike;
+use validator::Validate as _;
 
 #[derive(
     Queryable,
@@ -18,8 +22,6 @@ pub struct ResidentModel {
     pub id: Uuid,
     pub first_name: String,
     pub last_name: String,
-    #[validate(length(max = 20, message = "Unit number is too long"))]
-    pub unit_number: Option<String>,
     pub address: Option<String>,
     pub phone: Option<String>,
     pub email: String,
@@ -27,16 +29,19 @@ pub struct ResidentModel {
     pub is_active: bool,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub moved_in_at: Option<NaiveDate>,
+    pub moved_out_at: Option<NaiveDate>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
 pub struct ResidentModelNew {
+    #[validate(length(min = 1, message = "First name is required"))]
     pub first_name: String,
+    #[validate(length(min = 1, message = "Last name is required"))]
     pub last_name: String,
-    #[validate(length(max = 20, message = "Unit number is too long"))]
-    pub unit_number: Option<String>,
     pub address: Option<String>,
     pub phone: Option<String>,
+    #[validate(email(message = "Invalid email"))]
     pub email: String,
     pub date_of_birth: Option<NaiveDate>,
     #[validate(length(min = 8, message = "Password is too short"))]
@@ -50,8 +55,6 @@ pub struct ResidentModelNewInvite {
     pub key: String,
     pub first_name: String,
     pub last_name: String,
-    #[validate(length(max = 20, message = "Unit number is too long"))]
-    pub unit_number: Option<String>,
     pub address: Option<String>,
     pub phone: Option<String>,
     pub date_of_birth: Option<NaiveDate>,
@@ -63,16 +66,25 @@ pub struct ResidentModelNewInvite {
 pub struct ResidentModelEdit {
     pub first_name: String,
     pub last_name: String,
-    #[validate(length(max = 20, message = "Unit number is too long"))]
-    pub unit_number: Option<String>,
     pub address: Option<String>,
     pub phone: Option<String>,
     pub email: String,
     pub date_of_birth: Option<NaiveDate>,
     pub community_id: Option<Uuid>,
-    pub is_active: bool,
 }
 
+/// Statuses of the invitations that can be used until they expire.
+pub const PENDING_INVITE_STATUSES: [ResidentInviteStatus; 2] =
+    [ResidentInviteStatus::Sent, ResidentInviteStatus::Opened];
+
+pub type ResidentInviteFilter = Box<
+    dyn BoxableExpression<
+            resident_invites::table,
+            diesel::pg::Pg,
+            SqlType = diesel::sql_types::Bool,
+        >,
+>;
+
 #[derive(
     Queryable,
     Selectable,
@@ -93,6 +105,10 @@ pub struct ResidentInviteModel {
     pub community_id: Uuid,
     pub key: String,
     pub created_at: NaiveDateTime,
+    pub status: ResidentInviteStatus,
+    pub expires_at: NaiveDateTime,
+    pub opened_at: Option<NaiveDateTime>,
+    pub accepted_at: Option<NaiveDateTime>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
@@ -101,6 +117,126 @@ pub struct ResidentInviteModelNew {
     pub community_id: Uuid,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ResidentInviteBulkModel {
+    pub community_id: Uuid,
+    #[validate(length(min = 1, max = 500, message = "Between 1 and 500 emails are required"))]
+    pub emails: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct ResidentInviteBulkResult {
+    pub email: String,
+    pub invite_id: Option<Uuid>,
+    pub error: Option<String>,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = diesel::sql_types::Text)]
+pub enum ResidentInviteStatus {
+    Sent,
+    /// The invitation link was viewed but no account was created yet.
+    Opened,
+    Accepted,
+    Expired,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ResidentMoveOutModel {
+    /// Defaults to today.
+    pub moved_out_at: Option<NaiveDate>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ResidentMoveInModel {
+    /// Defaults to today.
+    pub moved_in_at: Option<NaiveDate>,
+}
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::resident_checklist_items)]
+pub struct ResidentChecklistItemModel {
+    pub id: Uuid,
+    pub resident_id: Uuid,
+    #[validate(length(min = 1, max = 150, message = "Item is too short or too long"))]
+    pub item: String,
+    pub completed_at: Option<NaiveDateTime>,
+    pub completed_by: Option<Uuid>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ResidentChecklistItemModelNew {
+    #[validate(length(min = 1, max = 150, message = "Item is too short or too long"))]
+    pub item: String,
+}
+
+/// Items every new resident starts with, admins may add more per resident.
+pub const MOVE_IN_CHECKLIST: [&str; 5] = [
+    "Keys and access tags handed over",
+    "Unit inspection report signed",
+    "Vehicles registered",
+    "Condominium rules acknowledged",
+    "Emergency contacts provided",
+];
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq, ToSchema)]
+pub enum ResidentImportMode {
+    /// Creates the accounts right away and emails each resident a link to set a password.
+    Create,
+    /// Sends a `ResidentInviteModel` invitation to each row instead.
+    Invite,
+}
+
+#[derive(Deserialize)]
+pub struct ResidentImportParams {
+    pub community_id: Uuid,
+    pub mode: ResidentImportMode,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// A data row of an import spreadsheet, `row` being its line number in the file.
+#[derive(Clone, Debug)]
+pub struct ResidentImportRow {
+    pub row: usize,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    pub phone: Option<String>,
+    pub address: Option<String>,
+    pub date_of_birth: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct ResidentImportRowError {
+    pub row: usize,
+    pub email: String,
+    pub errors: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct ResidentImportReport {
+    pub dry_run: bool,
+    pub total_rows: usize,
+    pub imported: usize,
+    pub errors: Vec<ResidentImportRowError>,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
 pub struct ResidentModelResult {
     pub resident: ResidentModel,
@@ -108,7 +244,301 @@ pub struct ResidentModelResult {
     pub role: UserRoleModel,
 }
 
+impl ResidentImportRow {
+    /// Maps spreadsheet rows to import rows using the header line, which must name at least
+    /// the `first_name`, `last_name` and `email` columns. Blank lines are skipped.
+    pub fn from_table(table: Vec<Vec<String>>) -> Result<Vec<ResidentImportRow>, String> {
+        let mut lines = table.into_iter();
+
+        let header: Vec<String> = match lines.next() {
+            Some(header) => header
+                .iter()
+                .map(|cell| cell.trim().to_lowercase().replace(' ', "_"))
+                .collect(),
+            None => return Err("File is empty".to_string()),
+        };
+
+        let column = |name: &str| header.iter().position(|cell| cell == name);
+
+        let (first_name, last_name, email) =
+            match (column("first_name"), column("last_name"), column("email")) {
+                (Some(first_name), Some(last_name), Some(email)) => (first_name, last_name, email),
+                _ => {
+                    return Err(
+                        "Header must contain the first_name, last_name and email columns"
+                            .to_string(),
+                    );
+                }
+            };
+        let (phone, address, date_of_birth) =
+            (column("phone"), column("address"), column("date_of_birth"));
+
+        Ok(lines
+            .enumerate()
+            .filter(|(_, line)| line.iter().any(|cell| !cell.trim().is_empty()))
+            .map(|(i, line)| {
+                let cell = |index: usize| line.get(index).map(|c| c.trim()).unwrap_or_default();
+                let optional = |index: Option<usize>| {
+                    index
+                        .map(cell)
+                        .filter(|value| !value.is_empty())
+                        .map(|value| value.to_string())
+                };
+
+                ResidentImportRow {
+                    row: i + 2,
+                    first_name: cell(first_name).to_string(),
+                    last_name: cell(last_name).to_string(),
+                    email: cell(email).to_lowercase(),
+                    phone: optional(phone),
+                    address: optional(address),
+                    date_of_birth: optional(date_of_birth),
+                }
+            })
+            .collect())
+    }
+
+    /// Builds the resident the row describes, applying the `ResidentModelNew` validation rules.
+    pub fn to_resident_new(
+        &self,
+        community_id: Uuid,
+        password: String,
+    ) -> Result<ResidentModelNew, Vec<String>> {
+        let mut errors: Vec<String> = Vec::new();
+
+        let date_of_birth = match &self.date_of_birth {
+            Some(value) => match crate::utilities::import_utils::parse_date(value) {
+                Some(date) => Some(date),
+                None => {
+                    errors.push(format!("Invalid date of birth: {}", value));
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let resident = ResidentModelNew {
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+            address: self.address.clone(),
+            phone: self.phone.clone(),
+            email: self.email.clone(),
+            date_of_birth,
+            password,
+            community_id: Some(community_id),
+            is_active: true,
+        };
+
+        if let Err(validation_errors) = resident.validate() {
+            for (_, field_errors) in validation_errors.field_errors() {
+                errors.extend(field_errors.iter().map(|e| match &e.message {
+                    Some(message) => message.to_string(),
+                    None => e.code.to_string(),
+                }));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(resident)
+        } else {
+            errors.sort();
+            Err(errors)
+        }
+    }
+}
+
+impl ResidentChecklistItemModel {
+    pub fn db_seed_move_in(conn: &mut PgConnection, resident_id: Uuid) -> QueryResult<usize> {
+        let now = chrono::Utc::now().naive_utc();
+        let items: Vec<ResidentChecklistItemModel> = MOVE_IN_CHECKLIST
+            .iter()
+            .map(|item| ResidentChecklistItemModel {
+                id: Uuid::new_v4(),
+                resident_id,
+                item: item.to_string(),
+                completed_at: None,
+                completed_by: None,
+                created_at: now,
+            })
+            .collect();
+
+        diesel::insert_into(resident_checklist_items::table)
+            .values(&items)
+            .execute(conn)
+    }
+
+    pub fn db_read_all_by_resident(
+        conn: &mut PgConnection,
+        resident_id: Uuid,
+    ) -> QueryResult<Vec<ResidentChecklistItemModel>> {
+        resident_checklist_items::table
+            .filter(resident_checklist_items::resident_id.eq(resident_id))
+            .order(resident_checklist_items::created_at.asc())
+            .load::<ResidentChecklistItemModel>(conn)
+    }
+}
+
 impl ResidentModel {
+    /// Creates the resident together with its user and role in one go, seeding the move-in
+    /// checklist. Returns the user created for the resident.
+    pub fn db_create_account(
+        conn: &mut PgConnection,
+        new: ResidentModelNew,
+        hashed_password: String,
+    ) -> QueryResult<UserModel> {
+        conn.transaction(|conn| {
+            let now = chrono::Utc::now().naive_utc();
+
+            let resident = ResidentModel {
+                id: ResidentModel::new_id_user(conn),
+                first_name: new.first_name,
+                last_name: new.last_name,
+                address: new.address,
+                phone: new.phone,
+                email: new.email,
+                date_of_birth: new.date_of_birth,
+                is_active: new.is_active,
+                created_at: now,
+                updated_at: now,
+                moved_in_at: Some(now.date()),
+                moved_out_at: None,
+            };
+            resident.db_insert(conn)?;
+
+            let user = UserModel {
+                id: UserModel::new_id(conn),
+                entity_id: resident.id,
+                entity_type: UserTypes::Resident,
+                admin_id: None,
+                resident_id: Some(resident.id),
+                password: hashed_password,
+                created_at: now,
+                updated_at: now,
+                service_account_id: None,
+            };
+            user.db_insert(conn)?;
+
+            UserRoleModel {
+                id: UserRoleModel::new_id(conn),
+                user_id: user.id,
+                role: UserRoles::Resident,
+                community_id: new.community_id,
+                created_at: now,
+                updated_at: now,
+            }
+            .db_insert(conn)?;
+
+            ResidentChecklistItemModel::db_seed_move_in(conn, resident.id)?;
+
+            Ok(user)
+        })
+    }
+
+    /// Signs a resident off: marks them inactive as of `date`, revokes their sessions, cancels
+    /// their upcoming reservations and ends their unit links. Past records are left untouched.
+    /// Checks a move-out on `date`. Moving out deactivates the resident at once, so the date
+    /// can't be after `today`, nor before the move-in.
+    pub fn check_move_out_date(&self, date: NaiveDate, today: NaiveDate) -> Result<(), String> {
+        if date > today {
+            return Err("Move-out date is in the future".to_string());
+        }
+
+        if self
+            .moved_in_at
+            .is_some_and(|moved_in_at| date < moved_in_at)
+        {
+            return Err("Move-out date is before the move-in date".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Checks a move-in on `date` of a resident coming back. Moving in reactivates the resident
+    /// at once, so the date can't be after `today`, nor before the last move-out.
+    pub fn check_move_in_date(&self, date: NaiveDate, today: NaiveDate) -> Result<(), String> {
+        if date > today {
+            return Err("Move-in date is in the future".to_string());
+        }
+
+        if self
+            .moved_out_at
+            .is_some_and(|moved_out_at| date < moved_out_at)
+        {
+            return Err("Move-in date is before the move-out date".to_string());
+        }
+
+        Ok(())
+    }
+
+    pub fn db_move_out(conn: &mut PgConnection, id: Uuid, date: NaiveDate) -> QueryResult<()> {
+        conn.transaction(|conn| {
+            diesel::update(residents::table.filter(residents::id.eq(id)))
+                .set((
+                    residents::is_active.eq(false),
+                    residents::moved_out_at.eq(Some(date)),
+                    residents::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .execute(conn)?;
+
+            diesel::update(
+                auth_tokens::table.filter(
+                    auth_tokens::user_id.eq_any(
+                        users::table
+                            .filter(users::entity_id.eq(id))
+                            .filter(users::entity_type.eq(UserTypes::Resident))
+                            .select(users::id),
+                    ),
+                ),
+            )
+            .set(auth_tokens::active.eq(false))
+            .execute(conn)?;
+
+            diesel::update(
+                reservations::table
+                    .filter(reservations::resident_id.eq(id))
+                    .filter(reservations::status.eq(ReservationStatus::Reserved)),
+            )
+            .set((
+                reservations::status.eq(ReservationStatus::Cancelled),
+                reservations::updated_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(conn)?;
+
+            diesel::update(
+                unit_residents::table
+                    .filter(unit_residents::resident_id.eq(id))
+                    .filter(unit_residents::start_date.le(date))
+                    .filter(
+                        unit_residents::end_date
+                            .is_null()
+                            .or(unit_residents::end_date.gt(date)),
+                    ),
+            )
+            .set(unit_residents::end_date.eq(Some(date)))
+            .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    /// Reactivates a resident who moved out, with a fresh move-in checklist.
+    pub fn db_move_in(conn: &mut PgConnection, id: Uuid, date: NaiveDate) -> QueryResult<()> {
+        conn.transaction(|conn| {
+            diesel::update(residents::table.filter(residents::id.eq(id)))
+                .set((
+                    residents::is_active.eq(true),
+                    residents::moved_in_at.eq(Some(date)),
+                    residents::moved_out_at.eq(None::<NaiveDate>),
+                    residents::updated_at.eq(chrono::Utc::now().naive_utc()),
+                ))
+                .execute(conn)?;
+
+            ResidentChecklistItemModel::db_seed_move_in(conn, id)?;
+
+            Ok(())
+        })
+    }
+
     pub fn new_id_user(conn: &mut PgConnection) -> uuid::Uuid {
         let uuid_new = uuid::Uuid::new_v4();
         let mut exists = true;
@@ -155,7 +585,7 @@ impl ResidentModel {
 
         let user = crate::models::user_model::UserModel::table()
             .filter(users::entity_id.eq(resident.id))
-            .filter(users::entity_type.eq("resident"))
+            .filter(users::entity_type.eq(UserTypes::Resident))
             .first::<crate::models::user_model::UserModel>(conn)?;
 
         let role = crate::models::user_role_model::UserRoleModel::table()
@@ -188,7 +618,7 @@ impl ResidentModel {
 
         let user = crate::models::user_model::UserModel::table()
             .filter(users::entity_id.eq(resident.id))
-            .filter(users::entity_type.eq("resident"))
+            .filter(users::entity_type.eq(UserTypes::Resident))
             .first::<crate::models::user_model::UserModel>(conn)?;
 
         let role = crate::models::user_role_model::UserRoleModel::table()
@@ -291,9 +721,168 @@ impl ResidentModel {
 
         Ok(results)
     }
+
+    pub fn db_read_all_active_by_community(
+        conn: &mut PgConnection,
+        community_id: Uuid,
+    ) -> diesel::QueryResult<Vec<ResidentModel>> {
+        user_roles::table
+            .inner_join(users::table.on(user_roles::user_id.eq(users::id)))
+            .inner_join(residents::table.on(users::entity_id.eq(residents::id)))
+            .filter(users::entity_type.eq(UserTypes::Resident))
+            .filter(user_roles::role.eq(UserRoles::Resident))
+            .filter(user_roles::community_id.eq(community_id))
+            .filter(residents::is_active.eq(true))
+            .select(ResidentModel::as_select())
+            .load::<ResidentModel>(conn)
+    }
 }
 
 impl ResidentInviteModel {
+    pub fn new(
+        conn: &mut PgConnection,
+        email: String,
+        community_id: Uuid,
+        expiration_days: u32,
+    ) -> ResidentInviteModel {
+        let id = ResidentInviteModel::new_id(conn);
+        let now = chrono::Utc::now().naive_utc();
+
+        ResidentInviteModel {
+            id,
+            email,
+            community_id,
+            key: format!("{}-{}", id, Uuid::new_v4()),
+            created_at: now,
+            status: ResidentInviteStatus::Sent,
+            expires_at: now + chrono::Duration::days(expiration_days as i64),
+            opened_at: None,
+            accepted_at: None,
+        }
+    }
+
+    /// Whether the invitation can still be used to sign up at `now`, the same check as
+    /// [`ResidentInviteModel::pending`] makes in queries.
+    pub fn is_pending(&self, now: NaiveDateTime) -> bool {
+        PENDING_INVITE_STATUSES.contains(&self.status) && self.expires_at > now
+    }
+
+    /// Filter for the invitations that can still be used to sign up at `now`.
+    pub fn pending(now: NaiveDateTime) -> ResidentInviteFilter {
+        Box::new(
+            resident_invites::status
+                .eq_any(PENDING_INVITE_STATUSES)
+                .and(resident_invites::expires_at.gt(now)),
+        )
+    }
+
+    /// Filter for the invitations still marked as pending although they expired at `now`.
+    pub fn overdue(now: NaiveDateTime) -> ResidentInviteFilter {
+        Box::new(
+            resident_invites::status
+                .eq_any(PENDING_INVITE_STATUSES)
+                .and(resident_invites::expires_at.le(now)),
+        )
+    }
+
+    /// Template parameters for the invitation email.
+    pub fn email_parameters(
+        &self,
+        community_name: String,
+        base_url: &str,
+    ) -> Vec<SmtpTemplateData> {
+        vec![
+            SmtpTemplateData {
+                key: "{{COMMUNITY_NAME}}".to_string(),
+                value: escape_html(&community_name),
+            },
+            SmtpTemplateData {
+                key: "{{INVITE_LINK}}".to_string(),
+                value: format!("{}/redirect/onboarding/register/{}", base_url, self.key),
+            },
+            SmtpTemplateData {
+                key: "{{CURRENT_YEAR}}".to_string(),
+                value: chrono::Utc::now().year().to_string(),
+            },
+        ]
+    }
+
+    /// Pending invitation holding `key`, expired and accepted ones are not returned.
+    pub fn db_read_pending_by_key(
+        conn: &mut PgConnection,
+        key: &str,
+    ) -> diesel::QueryResult<ResidentInviteModel> {
+        resident_invites::table
+            .filter(resident_invites::key.eq(key))
+            .filter(ResidentInviteModel::pending(chrono::Utc::now().naive_utc()))
+            .first::<ResidentInviteModel>(conn)
+    }
+
+    pub fn db_is_email_invited(conn: &mut PgConnection, email: &str) -> diesel::QueryResult<bool> {
+        resident_invites::table
+            .filter(resident_invites::email.eq(email))
+            .filter(ResidentInviteModel::pending(chrono::Utc::now().naive_utc()))
+            .count()
+            .get_result::<i64>(conn)
+            .map(|count| count != 0)
+    }
+
+    /// Gives the invitation a new key and validity period, invalidating the previous link.
+    pub fn db_rotate_key(
+        &mut self,
+        conn: &mut PgConnection,
+        expiration_days: u32,
+    ) -> diesel::QueryResult<()> {
+        let now = chrono::Utc::now().naive_utc();
+
+        self.key = format!("{}-{}", self.id, Uuid::new_v4());
+        self.status = ResidentInviteStatus::Sent;
+        self.expires_at = now + chrono::Duration::days(expiration_days as i64);
+        self.opened_at = None;
+
+        diesel::update(resident_invites::table.filter(resident_invites::id.eq(self.id)))
+            .set((
+                resident_invites::key.eq(&self.key),
+                resident_invites::status.eq(self.status),
+                resident_invites::expires_at.eq(self.expires_at),
+                resident_invites::opened_at.eq(None::<NaiveDateTime>),
+            ))
+            .execute(conn)
+            .map(|_| ())
+    }
+
+    pub fn db_mark_opened(&self, conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::update(
+            resident_invites::table
+                .filter(resident_invites::id.eq(self.id))
+                .filter(resident_invites::status.eq(ResidentInviteStatus::Sent)),
+        )
+        .set((
+            resident_invites::status.eq(ResidentInviteStatus::Opened),
+            resident_invites::opened_at.eq(Some(chrono::Utc::now().naive_utc())),
+        ))
+        .execute(conn)
+    }
+
+    pub fn db_mark_accepted(&self, conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::update(resident_invites::table.filter(resident_invites::id.eq(self.id)))
+            .set((
+                resident_invites::status.eq(ResidentInviteStatus::Accepted),
+                resident_invites::accepted_at.eq(Some(chrono::Utc::now().naive_utc())),
+            ))
+            .execute(conn)
+    }
+
+    /// Flags every pending invitation past its expiry date as expired.
+    pub fn db_expire_all(conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::update(
+            resident_invites::table
+                .filter(ResidentInviteModel::overdue(chrono::Utc::now().naive_utc())),
+        )
+        .set(resident_invites::status.eq(ResidentInviteStatus::Expired))
+        .execute(conn)
+    }
+
     pub fn db_count_all_matching_community(
         user_role: UserRoleModel,
         conn: &mut PgConnection,
@@ -387,3 +976,35 @@ impl ResidentInviteModel {
         Ok(resident_invite)
     }
 }
+
+impl<DB> ToSql<diesel::sql_types::Text, DB> for ResidentInviteStatus
+where
+    DB: Backend,
+    str: ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        let s = match self {
+            ResidentInviteStatus::Sent => "Sent",
+            ResidentInviteStatus::Opened => "Opened",
+            ResidentInviteStatus::Accepted => "Accepted",
+            ResidentInviteStatus::Expired => "Expired",
+        };
+        s.to_sql(out)
+    }
+}
+
+impl<DB> FromSql<diesel::sql_types::Text, DB> for ResidentInviteStatus
+where
+    DB: Backend,
+    String: FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        match <String as FromSql<diesel::sql_types::Text, DB>>::from_sql(bytes)?.as_str() {
+            "Sent" => Ok(ResidentInviteStatus::Sent),
+            "Opened" => Ok(ResidentInviteStatus::Opened),
+            "Accepted" => Ok(ResidentInviteStatus::Accepted),
+            "Expired" => Ok(ResidentInviteStatus::Expired),
+            x => Err(format!("Unrecognized variant {}", x).into()),
+        }
+    }
+}
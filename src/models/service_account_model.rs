@@ -0,0 +1,150 @@
+use super::api_key_model::{ApiKeyModel, ApiKeyNewModel, ApiKeyNewResult, ApiKeyResult};
+use super::prelude::*;
+use validator::Validate as _;
+
+/// Integration of a community, such as a gate controller, acting as an admin of the community
+/// within the scopes of its API keys.
+///
+/// Its user has no email or known password, so it can't sign in and only authenticates with keys.
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::service_accounts)]
+pub struct ServiceAccountModel {
+    pub id: Uuid,
+    pub community_id: Uuid,
+    #[validate(length(max = 100, message = "Name is too long"))]
+    pub name: String,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ServiceAccountNewModel {
+    pub community_id: Uuid,
+    #[validate(length(min = 1, max = 100, message = "Name is too short or too long"))]
+    pub name: String,
+    /// First key of the account.
+    #[validate(nested)]
+    pub api_key: ApiKeyNewModel,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ServiceAccountResult {
+    pub id: Uuid,
+    pub community_id: Uuid,
+    pub name: String,
+    pub created_at: NaiveDateTime,
+    pub api_keys: Vec<ApiKeyResult>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct ServiceAccountNewResult {
+    pub id: Uuid,
+    pub api_key: ApiKeyNewResult,
+}
+
+impl ServiceAccountModel {
+    /// Inserts the account with its user, an admin of the community, returning the user.
+    pub fn db_create(
+        &self,
+        conn: &mut PgConnection,
+        hashed_password: String,
+    ) -> QueryResult<UserModel> {
+        conn.transaction(|conn| {
+            let now = chrono::Utc::now().naive_utc();
+            self.db_insert(conn)?;
+
+            let user = UserModel {
+                id: UserModel::new_id(conn),
+                entity_id: self.id,
+                entity_type: UserTypes::Service,
+                admin_id: None,
+                resident_id: None,
+                password: hashed_password,
+                created_at: now,
+                updated_at: now,
+                service_account_id: Some(self.id),
+            };
+            user.db_insert(conn)?;
+
+            UserRoleModel {
+                id: UserRoleModel::new_id(conn),
+                user_id: user.id,
+                role: UserRoles::Admin,
+                community_id: Some(self.community_id),
+                created_at: now,
+                updated_at: now,
+            }
+            .db_insert(conn)?;
+
+            Ok(user)
+        })
+    }
+
+    /// Root sees every account, admins only those of their community.
+    pub fn db_read_by_id_matching_community(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        id: Uuid,
+    ) -> QueryResult<ServiceAccountModel> {
+        let account = ServiceAccountModel::db_read_by_id(conn, id)?;
+
+        if user_role.role == UserRoles::Root || user_role.community_id == Some(account.community_id)
+        {
+            Ok(account)
+        } else {
+            Err(diesel::result::Error::NotFound)
+        }
+    }
+
+    pub fn db_read_all_matching(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+    ) -> QueryResult<Vec<ServiceAccountModel>> {
+        let mut query = service_accounts::table.into_boxed();
+
+        if user_role.role != UserRoles::Root {
+            query = query.filter(
+                service_accounts::community_id
+                    .nullable()
+                    .eq(user_role.community_id),
+            );
+        }
+
+        query
+            .order(service_accounts::created_at.desc())
+            .load::<ServiceAccountModel>(conn)
+    }
+
+    pub fn db_read_user(&self, conn: &mut PgConnection) -> QueryResult<UserModel> {
+        users::table
+            .filter(users::service_account_id.eq(self.id))
+            .first::<UserModel>(conn)
+    }
+
+    /// The account with the keys of its user.
+    pub fn db_to_result(&self, conn: &mut PgConnection) -> QueryResult<ServiceAccountResult> {
+        let user = self.db_read_user(conn)?;
+
+        Ok(ServiceAccountResult {
+            id: self.id,
+            community_id: self.community_id,
+            name: self.name.clone(),
+            created_at: self.created_at,
+            api_keys: ApiKeyModel::db_read_by_user(conn, user.id)?
+                .iter()
+                .map(|api_key| api_key.to_result())
+                .collect(),
+        })
+    }
+}
@@ -0,0 +1,212 @@
+use super::prelude::*;
+use crate::internal::config::model::ConfigLockout;
+
+/// Days sign-in attempts are kept before they are cleaned up.
+pub const SIGN_IN_ATTEMPT_RETENTION_DAYS: i64 = 30;
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::sign_in_attempts)]
+pub struct SignInAttemptModel {
+    pub id: Uuid,
+    pub email: String,
+    pub ip_address: String,
+    /// Empty when the email doesn't belong to any user.
+    pub user_id: Option<Uuid>,
+    pub successful: bool,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::account_lockouts)]
+pub struct AccountLockoutModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// Wrong passwords since the last successful sign-in or lockout.
+    pub failed_attempts: i32,
+    /// Lockouts since the last successful sign-in, which make each lockout longer.
+    pub lockouts: i32,
+    pub locked_until: Option<NaiveDateTime>,
+    pub updated_at: NaiveDateTime,
+}
+
+impl SignInAttemptModel {
+    pub fn new(
+        conn: &mut PgConnection,
+        email: String,
+        ip_address: String,
+        user_id: Option<Uuid>,
+        successful: bool,
+    ) -> Self {
+        SignInAttemptModel {
+            id: SignInAttemptModel::new_id(conn),
+            email,
+            ip_address,
+            user_id,
+            successful,
+            created_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+
+    /// Records an attempt from `ip_address` as failed before the password is checked, unless the
+    /// IP already has `max_failed` failures since `since`, in which case nothing is recorded.
+    ///
+    /// Attempts from the same IP are serialised with an advisory lock, so concurrent requests
+    /// can't all pass the check before any of them is recorded.
+    pub fn db_reserve(
+        &self,
+        conn: &mut PgConnection,
+        since: NaiveDateTime,
+        max_failed: i64,
+    ) -> diesel::QueryResult<bool> {
+        conn.transaction(|conn| {
+            diesel::sql_query("SELECT pg_advisory_xact_lock(hashtext($1))")
+                .bind::<diesel::sql_types::Text, _>(&self.ip_address)
+                .execute(conn)?;
+
+            let failed: i64 = sign_in_attempts::table
+                .filter(sign_in_attempts::ip_address.eq(&self.ip_address))
+                .filter(sign_in_attempts::successful.eq(false))
+                .filter(sign_in_attempts::created_at.gt(since))
+                .count()
+                .get_result(conn)?;
+
+            if failed >= max_failed {
+                return Ok(false);
+            }
+
+            self.db_insert(conn)?;
+            Ok(true)
+        })
+    }
+
+    pub fn db_delete_old(conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        let cutoff =
+            chrono::Utc::now().naive_utc() - chrono::Duration::days(SIGN_IN_ATTEMPT_RETENTION_DAYS);
+
+        diesel::delete(sign_in_attempts::table.filter(sign_in_attempts::created_at.lt(cutoff)))
+            .execute(conn)
+    }
+}
+
+impl AccountLockoutModel {
+    pub fn new(conn: &mut PgConnection, user_id: Uuid) -> Self {
+        AccountLockoutModel {
+            id: AccountLockoutModel::new_id(conn),
+            user_id,
+            failed_attempts: 0,
+            lockouts: 0,
+            locked_until: None,
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+
+    pub fn is_locked(&self, now: NaiveDateTime) -> bool {
+        self.locked_until.is_some_and(|until| until > now)
+    }
+
+    /// Counts a wrong password, returning whether it locked the account.
+    ///
+    /// Every lockout lasts twice as long as the previous one, up to `max_lockout_minutes`.
+    pub fn register_failure(&mut self, now: NaiveDateTime, conf: &ConfigLockout) -> bool {
+        self.failed_attempts += 1;
+        self.updated_at = now;
+
+        if (self.failed_attempts as u32) < conf.max_failed_attempts.max(1) {
+            return false;
+        }
+
+        let minutes = (conf.lockout_minutes as i64)
+            .saturating_mul(1 << self.lockouts.clamp(0, 16))
+            .min(conf.max_lockout_minutes as i64);
+
+        self.failed_attempts = 0;
+        self.lockouts += 1;
+        self.locked_until = Some(now + chrono::Duration::minutes(minutes));
+        true
+    }
+
+    pub fn db_read_by_user(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+    ) -> diesel::QueryResult<Option<Self>> {
+        account_lockouts::table
+            .filter(account_lockouts::user_id.eq(user_id))
+            .first::<Self>(conn)
+            .optional()
+    }
+
+    /// Counts a wrong password for the user, returning the lockout and whether it locked the
+    /// account. The row is locked while it's updated, so concurrent failures are all counted.
+    pub fn db_register_failure(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        now: NaiveDateTime,
+        conf: &ConfigLockout,
+    ) -> diesel::QueryResult<(Self, bool)> {
+        conn.transaction(|conn| {
+            let new_lockout = AccountLockoutModel::new(conn, user_id);
+            diesel::insert_into(account_lockouts::table)
+                .values(&new_lockout)
+                .on_conflict(account_lockouts::user_id)
+                .do_nothing()
+                .execute(conn)?;
+
+            let mut lockout = account_lockouts::table
+                .filter(account_lockouts::user_id.eq(user_id))
+                .for_update()
+                .first::<Self>(conn)?;
+
+            let locked = lockout.register_failure(now, conf);
+            lockout.db_update(conn)?;
+
+            Ok((lockout, locked))
+        })
+    }
+
+    /// Clears the failed attempts and lockouts of the user.
+    pub fn db_delete_by_user(conn: &mut PgConnection, user_id: Uuid) -> diesel::QueryResult<usize> {
+        diesel::delete(account_lockouts::table.filter(account_lockouts::user_id.eq(user_id)))
+            .execute(conn)
+    }
+
+    pub fn db_delete_by_resident(
+        conn: &mut PgConnection,
+        resident_id: Uuid,
+    ) -> diesel::QueryResult<usize> {
+        diesel::delete(
+            account_lockouts::table.filter(
+                account_lockouts::user_id.eq_any(
+                    users::table
+                        .filter(users::entity_id.eq(resident_id))
+                        .filter(users::entity_type.eq(UserTypes::Resident))
+                        .select(users::id),
+                ),
+            ),
+        )
+        .execute(conn)
+    }
+}
@@ -0,0 +1,309 @@
+use super::prelude::*;
+use crate::models::auth_model;
+use crate::utilities::auth_utils::hash_token;
+use crate::utilities::totp_utils::normalize_recovery_code;
+
+/// Minutes a sign-in challenge stays valid once the password has been checked.
+pub const CHALLENGE_EXPIRATION_MINUTES: i64 = 5;
+/// Wrong codes accepted on a sign-in challenge before it stops working.
+pub const CHALLENGE_MAX_ATTEMPTS: i32 = 5;
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::user_totp)]
+pub struct UserTotpModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub secret: String,
+    pub enabled_at: Option<NaiveDateTime>,
+    pub last_used_step: Option<i64>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::user_recovery_codes)]
+pub struct UserRecoveryCodeModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub code_hash: String,
+    pub used_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::auth_challenges)]
+pub struct AuthChallengeModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub enrolment_required: bool,
+    pub attempts: i32,
+    pub created_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct TwoFactorEnrolmentResult {
+    /// Base32 secret, for authenticator apps that can't scan the QR code.
+    pub secret: String,
+    /// `otpauth://` URI to be rendered as a QR code.
+    pub provisioning_uri: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct TwoFactorCodeModel {
+    /// TOTP code or recovery code.
+    #[validate(length(min = 6, max = 16, message = "Invalid code"))]
+    pub code: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct TwoFactorChallengeModel {
+    pub challenge_token: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct TwoFactorSignInModel {
+    pub challenge_token: String,
+    /// TOTP code or recovery code.
+    #[validate(length(min = 6, max = 16, message = "Invalid code"))]
+    pub code: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct TwoFactorChallengeResult {
+    pub challenge_token: String,
+    /// The user has no second factor yet and must enrol one before signing in.
+    pub enrolment_required: bool,
+    pub expires_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct TwoFactorSignInResult {
+    pub tokens: auth_model::AuthTokenPairResult,
+    /// Only present when the sign-in completed an enrolment.
+    pub recovery_codes: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct TwoFactorRecoveryCodesResult {
+    pub recovery_codes: Vec<String>,
+}
+
+impl UserTotpModel {
+    pub fn new(conn: &mut PgConnection, user_id: Uuid, secret: String) -> Self {
+        UserTotpModel {
+            id: UserTotpModel::new_id(conn),
+            user_id,
+            secret,
+            enabled_at: None,
+            last_used_step: None,
+            created_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled_at.is_some()
+    }
+
+    pub fn db_read_by_user(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+    ) -> diesel::QueryResult<Option<Self>> {
+        user_totp::table
+            .filter(user_totp::user_id.eq(user_id))
+            .first::<Self>(conn)
+            .optional()
+    }
+
+    /// Replaces any enrolment the user hasn't confirmed yet with a new secret.
+    pub fn db_start_enrolment(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        secret: String,
+    ) -> diesel::QueryResult<Self> {
+        let totp = UserTotpModel::new(conn, user_id, secret);
+
+        conn.transaction(|conn| {
+            diesel::delete(user_totp::table.filter(user_totp::user_id.eq(user_id)))
+                .execute(conn)?;
+            totp.db_insert(conn)
+        })?;
+
+        Ok(totp)
+    }
+
+    /// Records the time step of an accepted code, enabling the factor on its first use.
+    pub fn db_use_step(&mut self, conn: &mut PgConnection, step: i64) -> diesel::QueryResult<()> {
+        self.last_used_step = Some(step);
+        self.enabled_at = self
+            .enabled_at
+            .or_else(|| Some(chrono::Utc::now().naive_utc()));
+        self.db_update(conn).map(|_| ())
+    }
+
+    /// Removes the user's second factor along with its recovery codes.
+    pub fn db_delete_by_user(conn: &mut PgConnection, user_id: Uuid) -> diesel::QueryResult<()> {
+        conn.transaction(|conn| {
+            diesel::delete(
+                user_recovery_codes::table.filter(user_recovery_codes::user_id.eq(user_id)),
+            )
+            .execute(conn)?;
+            diesel::delete(user_totp::table.filter(user_totp::user_id.eq(user_id)))
+                .execute(conn)?;
+            Ok(())
+        })
+    }
+
+    /// Whether the user administers a community that requires a second factor from its admins.
+    pub fn db_is_required(conn: &mut PgConnection, user_id: Uuid) -> diesel::QueryResult<bool> {
+        diesel::select(diesel::dsl::exists(
+            user_roles::table
+                .inner_join(communities::table)
+                .filter(user_roles::user_id.eq(user_id))
+                .filter(user_roles::role.eq(UserRoles::Admin))
+                .filter(communities::require_admin_2fa.eq(true)),
+        ))
+        .get_result(conn)
+    }
+}
+
+impl UserRecoveryCodeModel {
+    /// Replaces every recovery code of the user with the given ones.
+    pub fn db_replace_all(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        codes: &[String],
+    ) -> diesel::QueryResult<()> {
+        let now = chrono::Utc::now().naive_utc();
+        let new_codes: Vec<UserRecoveryCodeModel> = codes
+            .iter()
+            .map(|code| UserRecoveryCodeModel {
+                id: Uuid::new_v4(),
+                user_id,
+                code_hash: hash_token(&normalize_recovery_code(code)),
+                used_at: None,
+                created_at: now,
+            })
+            .collect();
+
+        conn.transaction(|conn| {
+            diesel::delete(
+                user_recovery_codes::table.filter(user_recovery_codes::user_id.eq(user_id)),
+            )
+            .execute(conn)?;
+            diesel::insert_into(user_recovery_codes::table)
+                .values(&new_codes)
+                .execute(conn)?;
+            Ok(())
+        })
+    }
+
+    /// Spends a recovery code, returning whether it was valid and still unused.
+    pub fn db_use(conn: &mut PgConnection, user_id: Uuid, code: &str) -> diesel::QueryResult<bool> {
+        diesel::update(
+            user_recovery_codes::table
+                .filter(user_recovery_codes::user_id.eq(user_id))
+                .filter(
+                    user_recovery_codes::code_hash.eq(hash_token(&normalize_recovery_code(code))),
+                )
+                .filter(user_recovery_codes::used_at.is_null()),
+        )
+        .set(user_recovery_codes::used_at.eq(chrono::Utc::now().naive_utc()))
+        .execute(conn)
+        .map(|count| count > 0)
+    }
+}
+
+impl AuthChallengeModel {
+    pub fn new(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        token: &str,
+        enrolment_required: bool,
+    ) -> Self {
+        let now = chrono::Utc::now().naive_utc();
+
+        AuthChallengeModel {
+            id: AuthChallengeModel::new_id(conn),
+            user_id,
+            token_hash: hash_token(token),
+            enrolment_required,
+            attempts: 0,
+            created_at: now,
+            expires_at: now + chrono::Duration::minutes(CHALLENGE_EXPIRATION_MINUTES),
+        }
+    }
+
+    pub fn is_valid(&self, now: NaiveDateTime) -> bool {
+        self.expires_at > now && self.attempts < CHALLENGE_MAX_ATTEMPTS
+    }
+
+    /// Reads a challenge by its token, only if it can still be answered.
+    pub fn db_read_valid_by_token(
+        conn: &mut PgConnection,
+        token: &str,
+    ) -> diesel::QueryResult<Self> {
+        let challenge = auth_challenges::table
+            .filter(auth_challenges::token_hash.eq(hash_token(token)))
+            .first::<Self>(conn)?;
+
+        if challenge.is_valid(chrono::Utc::now().naive_utc()) {
+            Ok(challenge)
+        } else {
+            Err(diesel::result::Error::NotFound)
+        }
+    }
+
+    pub fn db_register_failure(&self, conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::update(auth_challenges::table.find(self.id))
+            .set(auth_challenges::attempts.eq(auth_challenges::attempts + 1))
+            .execute(conn)
+    }
+
+    pub fn db_delete_expired(conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::delete(
+            auth_challenges::table.filter(
+                auth_challenges::expires_at
+                    .le(chrono::Utc::now().naive_utc())
+                    .or(auth_challenges::attempts.ge(CHALLENGE_MAX_ATTEMPTS)),
+            ),
+        )
+        .execute(conn)
+    }
+}
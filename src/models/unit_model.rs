@@ -0,0 +1,361 @@
+use super::invoice_model::InvoiceChargeType;
+use super::prelude::*;
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = units)]
+pub struct UnitModel {
+    pub id: Uuid,
+    pub community_id: Uuid,
+    /// Block or tower, for communities with more than one building.
+    #[validate(length(max = 20, message = "Block is too long"))]
+    pub block: Option<String>,
+    #[validate(length(min = 1, max = 20, message = "Number is too short or too long"))]
+    pub number: String,
+    /// Share of the community's common expenses apportioned to the unit.
+    #[schema(value_type = f64)]
+    pub ideal_fraction: BigDecimal,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct UnitModelNew {
+    pub community_id: Uuid,
+    #[validate(length(max = 20, message = "Block is too long"))]
+    pub block: Option<String>,
+    #[validate(length(min = 1, max = 20, message = "Number is too short or too long"))]
+    pub number: String,
+    #[schema(value_type = f64)]
+    pub ideal_fraction: BigDecimal,
+}
+
+#[derive(Deserialize)]
+pub struct UnitFilterParams {
+    pub block: Option<String>,
+}
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = unit_residents)]
+#[diesel(treat_none_as_null = true)]
+pub struct UnitResidentModel {
+    pub id: Uuid,
+    pub unit_id: Uuid,
+    pub resident_id: Uuid,
+    pub relation: UnitRelation,
+    pub created_at: NaiveDateTime,
+    pub start_date: NaiveDate,
+    /// Last day of the ownership or tenancy, open-ended when `None`.
+    pub end_date: Option<NaiveDate>,
+    /// Set on a tenant who votes on behalf of the owner.
+    pub voting_proxy: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct UnitResidentModelNew {
+    pub resident_id: Uuid,
+    pub relation: UnitRelation,
+    pub start_date: Option<NaiveDate>,
+    pub end_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub voting_proxy: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct UnitResidentModelEdit {
+    /// Left unchanged when missing, `null` reopens the period.
+    #[serde(default, deserialize_with = "crate::models::lib::deserialize_nullable")]
+    #[schema(value_type = Option<NaiveDate>)]
+    pub end_date: Option<Option<NaiveDate>>,
+    pub voting_proxy: Option<bool>,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = diesel::sql_types::Text)]
+pub enum UnitRelation {
+    Owner,
+    Tenant,
+    Dependent,
+}
+
+impl UnitModel {
+    /// Whether `ideal_fraction` is a valid share, greater than zero and at most the whole.
+    pub fn has_valid_fraction(ideal_fraction: &BigDecimal) -> bool {
+        *ideal_fraction > BigDecimal::from(0) && *ideal_fraction <= BigDecimal::from(1)
+    }
+
+    /// Admins only see the units of their community, residents only the units they live in.
+    pub fn db_read_by_id_matching_community(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        id: Uuid,
+    ) -> diesel::QueryResult<UnitModel> {
+        Self::filtered_query(user_role, &UnitFilterParams { block: None })
+            .filter(units::id.eq(id))
+            .first::<UnitModel>(conn)
+    }
+
+    /// Units `resident_id` is linked to, in the order the links were made.
+    pub fn db_read_all_by_resident(
+        conn: &mut PgConnection,
+        resident_id: Uuid,
+    ) -> diesel::QueryResult<Vec<UnitModel>> {
+        units::table
+            .inner_join(unit_residents::table.on(unit_residents::unit_id.eq(units::id)))
+            .filter(unit_residents::resident_id.eq(resident_id))
+            .order(unit_residents::created_at.asc())
+            .select(UnitModel::as_select())
+            .load(conn)
+    }
+
+    fn filtered_query<'a>(
+        user_role: UserRoleModel,
+        filters: &'a UnitFilterParams,
+    ) -> units::BoxedQuery<'a, diesel::pg::Pg> {
+        let mut query = UnitModel::table().into_boxed();
+
+        match user_role.role {
+            UserRoles::Root => {}
+            UserRoles::Admin => {
+                query = query.filter(units::community_id.nullable().eq(user_role.community_id));
+            }
+            UserRoles::Resident => {
+                query = query.filter(
+                    units::id.eq_any(
+                        unit_residents::table
+                            .inner_join(
+                                users::table.on(users::entity_id.eq(unit_residents::resident_id)),
+                            )
+                            .filter(users::id.eq(user_role.user_id))
+                            .select(unit_residents::unit_id),
+                    ),
+                );
+            }
+        }
+
+        if let Some(block) = &filters.block {
+            query = query.filter(units::block.eq(block));
+        }
+
+        query
+    }
+
+    pub fn db_count_all_matching(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        filters: &UnitFilterParams,
+    ) -> diesel::QueryResult<i64> {
+        Self::filtered_query(user_role, filters)
+            .count()
+            .get_result::<i64>(conn)
+    }
+
+    pub fn db_read_all_matching_by_range(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        filters: &UnitFilterParams,
+        per_page: i64,
+        offset: i64,
+    ) -> diesel::QueryResult<Vec<UnitModel>> {
+        Self::filtered_query(user_role, filters)
+            .order((units::block.asc(), units::number.asc()))
+            .limit(per_page)
+            .offset(offset)
+            .load::<UnitModel>(conn)
+    }
+}
+
+impl UnitResidentModel {
+    pub fn is_active_on(&self, date: NaiveDate) -> bool {
+        self.start_date <= date && self.end_date.is_none_or(|end_date| end_date >= date)
+    }
+
+    /// Only tenants can hold the owner's vote, and a period cannot end before it starts.
+    pub fn is_valid(&self) -> bool {
+        (!self.voting_proxy || self.relation == UnitRelation::Tenant)
+            && self
+                .end_date
+                .is_none_or(|end_date| end_date >= self.start_date)
+    }
+
+    /// Picks who answers for a charge among a unit's links: the owner pays extraordinary
+    /// charges, a tenant living in the unit pays the ordinary fee in place of the owner.
+    pub fn responsible_for(
+        links: &[UnitResidentModel],
+        charge_type: InvoiceChargeType,
+        date: NaiveDate,
+    ) -> Option<Uuid> {
+        let active = |relation: UnitRelation| {
+            links
+                .iter()
+                .filter(move |link| link.relation == relation && link.is_active_on(date))
+                .min_by_key(|link| link.start_date)
+        };
+
+        let link = match charge_type {
+            InvoiceChargeType::Ordinary => {
+                active(UnitRelation::Tenant).or_else(|| active(UnitRelation::Owner))
+            }
+            InvoiceChargeType::Extraordinary => active(UnitRelation::Owner),
+        };
+
+        link.map(|link| link.resident_id)
+    }
+
+    /// Picks who casts a unit's vote: a tenant holding the owner's proxy, otherwise the owner.
+    pub fn voter_for(links: &[UnitResidentModel], date: NaiveDate) -> Option<Uuid> {
+        links
+            .iter()
+            .filter(|link| link.is_active_on(date))
+            .filter(|link| link.relation == UnitRelation::Tenant && link.voting_proxy)
+            .chain(
+                links
+                    .iter()
+                    .filter(|link| link.is_active_on(date))
+                    .filter(|link| link.relation == UnitRelation::Owner),
+            )
+            .map(|link| link.resident_id)
+            .next()
+    }
+
+    pub fn db_read_all_by_unit(
+        conn: &mut PgConnection,
+        unit_id: Uuid,
+    ) -> diesel::QueryResult<Vec<UnitResidentModel>> {
+        unit_residents::table
+            .filter(unit_residents::unit_id.eq(unit_id))
+            .order(unit_residents::created_at.asc())
+            .load::<UnitResidentModel>(conn)
+    }
+
+    /// Whether `resident_id` currently lives in or owns `unit_id`.
+    pub fn db_is_linked(
+        conn: &mut PgConnection,
+        unit_id: Uuid,
+        resident_id: Uuid,
+    ) -> diesel::QueryResult<bool> {
+        let today = chrono::Utc::now().date_naive();
+
+        unit_residents::table
+            .filter(unit_residents::unit_id.eq(unit_id))
+            .filter(unit_residents::resident_id.eq(resident_id))
+            .filter(unit_residents::start_date.le(today))
+            .filter(
+                unit_residents::end_date
+                    .is_null()
+                    .or(unit_residents::end_date.ge(today)),
+            )
+            .count()
+            .get_result::<i64>(conn)
+            .map(|count| count != 0)
+    }
+
+    /// Residents of `community_id` that hold at least one link matching `relation` on `date`.
+    /// Links of `resident_id` to the units of `community_id` with `relation`, past ones included.
+    pub fn db_read_by_resident_and_relation(
+        conn: &mut PgConnection,
+        community_id: Uuid,
+        resident_id: Uuid,
+        relation: UnitRelation,
+    ) -> diesel::QueryResult<Vec<UnitResidentModel>> {
+        unit_residents::table
+            .inner_join(units::table.on(units::id.eq(unit_residents::unit_id)))
+            .filter(units::community_id.eq(community_id))
+            .filter(unit_residents::resident_id.eq(resident_id))
+            .filter(unit_residents::relation.eq(relation))
+            .select(UnitResidentModel::as_select())
+            .load::<UnitResidentModel>(conn)
+    }
+
+    pub fn db_read_resident_ids_by_relation(
+        conn: &mut PgConnection,
+        community_id: Uuid,
+        relation: UnitRelation,
+        date: NaiveDate,
+    ) -> diesel::QueryResult<Vec<Uuid>> {
+        unit_residents::table
+            .inner_join(units::table.on(units::id.eq(unit_residents::unit_id)))
+            .filter(units::community_id.eq(community_id))
+            .filter(unit_residents::relation.eq(relation))
+            .filter(unit_residents::start_date.le(date))
+            .filter(
+                unit_residents::end_date
+                    .is_null()
+                    .or(unit_residents::end_date.ge(date)),
+            )
+            .select(unit_residents::resident_id)
+            .distinct()
+            .load::<Uuid>(conn)
+    }
+}
+
+impl UnitModel {
+    pub fn db_responsible_resident(
+        conn: &mut PgConnection,
+        unit_id: Uuid,
+        charge_type: InvoiceChargeType,
+        date: NaiveDate,
+    ) -> diesel::QueryResult<Option<Uuid>> {
+        let links = UnitResidentModel::db_read_all_by_unit(conn, unit_id)?;
+        Ok(UnitResidentModel::responsible_for(
+            &links,
+            charge_type,
+            date,
+        ))
+    }
+}
+
+impl<DB> ToSql<diesel::sql_types::Text, DB> for UnitRelation
+where
+    DB: Backend,
+    str: ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        let s = match self {
+            UnitRelation::Owner => "Owner",
+            UnitRelation::Tenant => "Tenant",
+            UnitRelation::Dependent => "Dependent",
+        };
+        s.to_sql(out)
+    }
+}
+
+impl<DB> FromSql<diesel::sql_types::Text, DB> for UnitRelation
+where
+    DB: Backend,
+    String: FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        match <String as FromSql<diesel::sql_types::Text, DB>>::from_sql(bytes)?.as_str() {
+            "Owner" => Ok(UnitRelation::Owner),
+            "Tenant" => Ok(UnitRelation::Tenant),
+            "Dependent" => Ok(UnitRelation::Dependent),
+            x => Err(format!("Unrecognized variant {}", x).into()),
+        }
+    }
+}
@@ -24,6 +24,44 @@ pub struct UserModel {
     pub password: String,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub service_account_id: Option<Uuid>,
+}
+
+/// Names and email of a user, read from the resident or admin behind it.
+#[derive(Clone, Debug)]
+pub struct UserPersonalInfo {
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+}
+
+impl UserPersonalInfo {
+    /// Words the password policy keeps out of passwords.
+    pub fn words(&self) -> [&str; 3] {
+        [&self.first_name, &self.last_name, &self.email]
+    }
+}
+
+/// Hash of a password the user had before, kept so it can't be reused.
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = crate::schema::password_history)]
+pub struct PasswordHistoryModel {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub password: String,
+    pub created_at: NaiveDateTime,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
@@ -124,9 +162,138 @@ impl UserModel {
                         Err(e) => Err(e),
                     }
                 }
+                UserTypes::Service => Err(diesel::result::Error::NotFound),
             }
         } else {
             Err(diesel::result::Error::NotFound)
         }
     }
+
+    /// Whether the user may authenticate. Admins always can, residents only until they move out.
+    pub fn db_is_active(conn: &mut PgConnection, id: Uuid) -> QueryResult<bool> {
+        let inactive = users::table
+            .inner_join(residents::table.on(residents::id.eq(users::entity_id)))
+            .filter(users::id.eq(id))
+            .filter(users::entity_type.eq(UserTypes::Resident))
+            .filter(residents::is_active.eq(false))
+            .count()
+            .get_result::<i64>(conn)?;
+
+        Ok(inactive == 0)
+    }
+
+    /// Reads the email of the resident or admin behind the user.
+    pub fn db_read_email(&self, conn: &mut PgConnection) -> QueryResult<String> {
+        match self.entity_type {
+            UserTypes::Admin => {
+                admin_model::AdminModel::db_read_by_id(conn, self.entity_id).map(|adm| adm.email)
+            }
+            UserTypes::Resident => {
+                resident_model::ResidentModel::db_read_by_id(conn, self.entity_id)
+                    .map(|res| res.email)
+            }
+            UserTypes::Service => Err(diesel::result::Error::NotFound),
+        }
+    }
+
+    /// Names and email of the resident or admin behind the user.
+    pub fn db_read_personal_info(&self, conn: &mut PgConnection) -> QueryResult<UserPersonalInfo> {
+        match self.entity_type {
+            UserTypes::Admin => {
+                admin_model::AdminModel::db_read_by_id(conn, self.entity_id).map(|adm| {
+                    UserPersonalInfo {
+                        first_name: adm.first_name,
+                        last_name: adm.last_name,
+                        email: adm.email,
+                    }
+                })
+            }
+            UserTypes::Resident => {
+                resident_model::ResidentModel::db_read_by_id(conn, self.entity_id).map(|res| {
+                    UserPersonalInfo {
+                        first_name: res.first_name,
+                        last_name: res.last_name,
+                        email: res.email,
+                    }
+                })
+            }
+            UserTypes::Service => Err(diesel::result::Error::NotFound),
+        }
+    }
+
+    /// Hashes of the current password and the ones before it, up to `count` in total.
+    pub fn db_read_password_history(
+        &self,
+        conn: &mut PgConnection,
+        count: usize,
+    ) -> QueryResult<Vec<String>> {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut hashes = vec![self.password.clone()];
+        hashes.extend(
+            password_history::table
+                .filter(password_history::user_id.eq(self.id))
+                .order(password_history::created_at.desc())
+                .limit(count as i64 - 1)
+                .select(password_history::password)
+                .load::<String>(conn)?,
+        );
+
+        Ok(hashes)
+    }
+
+    /// Replaces the password, moving the previous one into the history kept for `history_count`.
+    pub fn db_update_password(
+        &mut self,
+        conn: &mut PgConnection,
+        hashed_password: String,
+        history_count: usize,
+    ) -> QueryResult<()> {
+        let now = chrono::Utc::now().naive_utc();
+        let previous = PasswordHistoryModel {
+            id: Uuid::new_v4(),
+            user_id: self.id,
+            password: std::mem::replace(&mut self.password, hashed_password),
+            created_at: now,
+        };
+        self.updated_at = now;
+
+        conn.transaction(|conn| {
+            if history_count > 1 {
+                previous.db_insert(conn)?;
+            }
+
+            let kept: Vec<Uuid> = password_history::table
+                .filter(password_history::user_id.eq(self.id))
+                .order(password_history::created_at.desc())
+                .limit(history_count.saturating_sub(1) as i64)
+                .select(password_history::id)
+                .load(conn)?;
+            diesel::delete(
+                password_history::table
+                    .filter(password_history::user_id.eq(self.id))
+                    .filter(password_history::id.ne_all(kept)),
+            )
+            .execute(conn)?;
+
+            self.db_update(conn).map(|_| ())
+        })
+    }
+
+    /// Sets the email of the admin or resident behind the user.
+    pub fn db_update_email(&self, conn: &mut PgConnection, email: &str) -> QueryResult<usize> {
+        let now = chrono::Utc::now().naive_utc();
+
+        match self.entity_type {
+            UserTypes::Admin => diesel::update(admins::table.find(self.entity_id))
+                .set((admins::email.eq(email), admins::updated_at.eq(now)))
+                .execute(conn),
+            UserTypes::Resident => diesel::update(residents::table.find(self.entity_id))
+                .set((residents::email.eq(email), residents::updated_at.eq(now)))
+                .execute(conn),
+            UserTypes::Service => Err(diesel::result::Error::NotFound),
+        }
+    }
 }
@@ -1,4 +1,8 @@
+use super::parking_spot_model::ParkingSpotModel;
 use super::prelude::*;
+use super::resident_model::ResidentModel;
+use super::unit_model::{UnitModel, UnitResidentModel};
+use crate::utilities::plate_utils::{equivalent_plates, normalize_plate};
 
 #[derive(
     Queryable,
@@ -25,6 +29,8 @@ pub struct VehicleModel {
     pub color: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub parking_spot_id: Option<Uuid>,
+    pub authorized: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
@@ -36,6 +42,22 @@ pub struct VehicleModelNew {
     pub model: Option<String>,
     #[validate(length(max = 50, message = "Color is too long"))]
     pub color: Option<String>,
+    pub parking_spot_id: Option<Uuid>,
+    /// Whether the gate should let the vehicle in, defaults to `true` for new vehicles.
+    pub authorized: Option<bool>,
+}
+
+/// Answer given to the guard booth for a license plate.
+#[derive(Serialize, Deserialize, Clone, Debug, ToSchema)]
+pub struct VehicleAccessResult {
+    pub license_plate: String,
+    pub authorized: bool,
+    pub vehicle: Option<VehicleModel>,
+    pub parking_spot: Option<ParkingSpotModel>,
+    pub unit: Option<UnitModel>,
+    pub resident_name: Option<String>,
+    pub resident_phone: Option<String>,
+    pub resident_email: Option<String>,
 }
 
 impl VehicleModel {
@@ -100,4 +122,98 @@ impl VehicleModel {
 
         query.select(VehicleModel::as_select()).first(conn)
     }
+
+    pub fn db_release_parking_spot(&self, conn: &mut PgConnection) -> diesel::QueryResult<usize> {
+        diesel::update(vehicles::table.find(self.id))
+            .set(vehicles::parking_spot_id.eq(None::<Uuid>))
+            .execute(conn)
+    }
+
+    /// A vehicle may only use a spot of its resident's community, and when the spot is assigned
+    /// to a unit the resident must live in it.
+    pub fn db_check_parking_spot(
+        conn: &mut PgConnection,
+        resident_id: Uuid,
+        parking_spot_id: Uuid,
+    ) -> diesel::QueryResult<bool> {
+        let spot = ParkingSpotModel::db_read_by_id(conn, parking_spot_id)?;
+
+        let community_id = residents::table
+            .inner_join(users::table.on(users::entity_id.eq(residents::id)))
+            .inner_join(user_roles::table.on(user_roles::user_id.eq(users::id)))
+            .filter(residents::id.eq(resident_id))
+            .select(user_roles::community_id)
+            .first::<Option<Uuid>>(conn)?;
+
+        if community_id != Some(spot.community_id) {
+            return Ok(false);
+        }
+
+        match spot.unit_id {
+            Some(unit_id) => UnitResidentModel::db_is_linked(conn, unit_id, resident_id),
+            None => Ok(true),
+        }
+    }
+
+    /// Looks a plate up for the gate, matching it in either the old or the Mercosul format.
+    /// Admins only see vehicles of their own community, anything else is reported as unknown.
+    pub fn db_access_lookup(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        license_plate: String,
+    ) -> diesel::QueryResult<VehicleAccessResult> {
+        let mut query = vehicles::table
+            .inner_join(residents::table.on(vehicles::resident_id.eq(residents::id)))
+            .inner_join(users::table.on(users::entity_id.eq(residents::id)))
+            .inner_join(user_roles::table.on(user_roles::user_id.eq(users::id)))
+            .filter(vehicles::license_plate.eq_any(equivalent_plates(&license_plate)))
+            .into_boxed();
+
+        if user_role.role != UserRoles::Root {
+            query = query.filter(user_roles::community_id.eq(user_role.community_id));
+        }
+
+        let license_plate = normalize_plate(&license_plate).unwrap_or(license_plate);
+
+        let Some((vehicle, resident)) = query
+            .select((VehicleModel::as_select(), ResidentModel::as_select()))
+            .first::<(VehicleModel, ResidentModel)>(conn)
+            .optional()?
+        else {
+            return Ok(VehicleAccessResult {
+                license_plate,
+                authorized: false,
+                vehicle: None,
+                parking_spot: None,
+                unit: None,
+                resident_name: None,
+                resident_phone: None,
+                resident_email: None,
+            });
+        };
+
+        let parking_spot = match vehicle.parking_spot_id {
+            Some(parking_spot_id) => ParkingSpotModel::db_read_by_id(conn, parking_spot_id).ok(),
+            None => None,
+        };
+
+        // The spot's unit takes precedence, otherwise the first unit the resident lives in.
+        let unit = match parking_spot.as_ref().and_then(|spot| spot.unit_id) {
+            Some(unit_id) => UnitModel::db_read_by_id(conn, unit_id).ok(),
+            None => UnitModel::db_read_all_by_resident(conn, resident.id)?
+                .into_iter()
+                .next(),
+        };
+
+        Ok(VehicleAccessResult {
+            license_plate,
+            authorized: vehicle.authorized && resident.is_active,
+            unit,
+            resident_name: Some(format!("{} {}", resident.first_name, resident.last_name)),
+            resident_phone: resident.phone,
+            resident_email: Some(resident.email),
+            vehicle: Some(vehicle),
+            parking_spot,
+        })
+    }
 }
@@ -0,0 +1,137 @@
+use super::prelude::*;
+use crate::services::UserRoles;
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = vendors)]
+pub struct VendorModel {
+    pub id: Uuid,
+    pub community_id: Uuid,
+    #[validate(length(min = 2, max = 100, message = "Company name is too short or too long"))]
+    pub company_name: String,
+    pub contact_name: Option<String>,
+    #[validate(email(message = "Invalid email"))]
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    #[validate(length(min = 2, max = 50, message = "Service type is too short or too long"))]
+    pub service_type: String,
+    pub contract_start_date: Option<NaiveDate>,
+    pub contract_end_date: Option<NaiveDate>,
+    pub insurance_expiry_date: Option<NaiveDate>,
+    pub notes: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct VendorModelNew {
+    pub community_id: Uuid,
+    #[validate(length(min = 2, max = 100, message = "Company name is too short or too long"))]
+    pub company_name: String,
+    pub contact_name: Option<String>,
+    #[validate(email(message = "Invalid email"))]
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    #[validate(length(min = 2, max = 50, message = "Service type is too short or too long"))]
+    pub service_type: String,
+    pub contract_start_date: Option<NaiveDate>,
+    pub contract_end_date: Option<NaiveDate>,
+    pub insurance_expiry_date: Option<NaiveDate>,
+    pub notes: Option<String>,
+}
+
+/// Longest look-ahead accepted by `expiring_within_days`, about ten years.
+pub const VENDOR_MAX_EXPIRING_WITHIN_DAYS: i64 = 3650;
+
+#[derive(Deserialize, Validate)]
+pub struct VendorFilterParams {
+    pub service_type: Option<String>,
+    /// Only vendors whose contract or insurance expires within this many days.
+    #[validate(range(
+        min = 0,
+        max = VENDOR_MAX_EXPIRING_WITHIN_DAYS,
+        message = "Invalid number of days"
+    ))]
+    pub expiring_within_days: Option<i64>,
+}
+
+impl VendorModel {
+    /// Vendors are managed by the administration only, residents never see them.
+    pub fn db_read_by_id_matching_community(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        id: Uuid,
+    ) -> diesel::QueryResult<VendorModel> {
+        let vendor = VendorModel::db_read_by_id(conn, id)?;
+
+        match user_role.role {
+            UserRoles::Root => Ok(vendor),
+            UserRoles::Admin if user_role.community_id == Some(vendor.community_id) => Ok(vendor),
+            _ => Err(diesel::result::Error::NotFound),
+        }
+    }
+
+    fn filtered_query<'a>(
+        user_role: UserRoleModel,
+        filters: &'a VendorFilterParams,
+    ) -> vendors::BoxedQuery<'a, diesel::pg::Pg> {
+        let mut query = VendorModel::table().into_boxed();
+
+        match user_role.role {
+            UserRoles::Root => {}
+            UserRoles::Admin | UserRoles::Resident => {
+                query = query.filter(vendors::community_id.nullable().eq(user_role.community_id));
+            }
+        }
+
+        if let Some(service_type) = &filters.service_type {
+            query = query.filter(vendors::service_type.eq(service_type));
+        }
+
+        if let Some(days) = filters.expiring_within_days {
+            let cutoff = chrono::Utc::now().date_naive() + chrono::Duration::days(days);
+            query = query.filter(
+                vendors::contract_end_date
+                    .le(cutoff)
+                    .or(vendors::insurance_expiry_date.le(cutoff)),
+            );
+        }
+
+        query
+    }
+
+    pub fn db_count_all_matching(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        filters: &VendorFilterParams,
+    ) -> diesel::QueryResult<i64> {
+        Self::filtered_query(user_role, filters)
+            .count()
+            .get_result::<i64>(conn)
+    }
+
+    pub fn db_read_all_matching_by_range(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        filters: &VendorFilterParams,
+        per_page: i64,
+        offset: i64,
+    ) -> diesel::QueryResult<Vec<VendorModel>> {
+        Self::filtered_query(user_role, filters)
+            .order(vendors::company_name.asc())
+            .limit(per_page)
+            .offset(offset)
+            .load::<VendorModel>(conn)
+    }
+}
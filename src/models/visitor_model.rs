@@ -0,0 +1,292 @@
+use super::prelude::*;
+use super::*;
+use crate::internal::smtp::smtp_templates::{SmtpTemplateData, escape_html};
+use chrono::Datelike;
+use password_hash::rand_core::{OsRng, RngCore};
+
+/// Characters used for access codes, leaving out the ones easily mistaken for each other.
+const ACCESS_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const ACCESS_CODE_LENGTH: usize = 8;
+
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
+#[diesel(table_name = visitors)]
+pub struct VisitorModel {
+    pub id: Uuid,
+    pub resident_id: Uuid,
+    #[validate(length(min = 2, max = 100, message = "Name is too short or too long"))]
+    pub name: String,
+    #[validate(length(min = 2, max = 30, message = "Document is too short or too long"))]
+    pub document: String,
+    pub license_plate: Option<String>,
+    pub expected_from: NaiveDateTime,
+    pub expected_until: NaiveDateTime,
+    pub access_code: String,
+    pub status: VisitorStatus,
+    pub checked_in_at: Option<NaiveDateTime>,
+    pub checked_in_by: Option<Uuid>,
+    pub checked_out_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct VisitorModelNew {
+    /// Required for admins, residents always register visitors for themselves.
+    pub resident_id: Option<Uuid>,
+    #[validate(length(min = 2, max = 100, message = "Name is too short or too long"))]
+    pub name: String,
+    #[validate(length(min = 2, max = 30, message = "Document is too short or too long"))]
+    pub document: String,
+    pub license_plate: Option<String>,
+    pub expected_from: NaiveDateTime,
+    pub expected_until: NaiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct VisitorCheckInModel {
+    #[validate(length(equal = 8, message = "Invalid access code"))]
+    pub access_code: String,
+}
+
+#[derive(Deserialize)]
+pub struct VisitorFilterParams {
+    pub status: Option<VisitorStatus>,
+    /// Only visitors expected on this day.
+    pub date: Option<NaiveDate>,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    AsExpression,
+    FromSqlRow,
+    Default,
+)]
+#[diesel(sql_type = diesel::sql_types::Text)]
+pub enum VisitorStatus {
+    #[default]
+    Expected,
+    CheckedIn,
+    CheckedOut,
+    Cancelled,
+}
+
+impl VisitorModel {
+    pub fn generate_access_code() -> String {
+        (0..ACCESS_CODE_LENGTH)
+            .map(|_| {
+                let i = OsRng.next_u32() as usize % ACCESS_CODE_ALPHABET.len();
+                ACCESS_CODE_ALPHABET[i] as char
+            })
+            .collect()
+    }
+
+    /// Generates an access code that no other expected visitor is currently holding.
+    pub fn db_new_access_code(conn: &mut PgConnection) -> diesel::QueryResult<String> {
+        loop {
+            let code = Self::generate_access_code();
+
+            let in_use = visitors::table
+                .filter(visitors::access_code.eq(&code))
+                .filter(visitors::status.eq(VisitorStatus::Expected))
+                .count()
+                .get_result::<i64>(conn)?;
+
+            if in_use == 0 {
+                return Ok(code);
+            }
+        }
+    }
+
+    /// Template parameters for the visitor arrival email.
+    pub fn email_parameters(
+        &self,
+        resident: &resident_model::ResidentModel,
+    ) -> Vec<SmtpTemplateData> {
+        vec![
+            SmtpTemplateData {
+                key: "{{USER_NAME}}".to_string(),
+                value: escape_html(&resident.first_name),
+            },
+            SmtpTemplateData {
+                key: "{{VISITOR_NAME}}".to_string(),
+                value: escape_html(&self.name),
+            },
+            SmtpTemplateData {
+                key: "{{ARRIVAL_DATE}}".to_string(),
+                value: self
+                    .checked_in_at
+                    .unwrap_or(self.expected_from)
+                    .format("%d/%m/%Y %H:%M")
+                    .to_string(),
+            },
+            SmtpTemplateData {
+                key: "{{CURRENT_YEAR}}".to_string(),
+                value: chrono::Utc::now().year().to_string(),
+            },
+        ]
+    }
+
+    pub fn db_read_by_id_matching_resident(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        id: Uuid,
+    ) -> diesel::QueryResult<VisitorModel> {
+        let visitor = VisitorModel::db_read_by_id(conn, id)?;
+        let resident = resident_model::ResidentModel::db_get_user(conn, visitor.resident_id)?;
+
+        match user_role.role {
+            UserRoles::Root => {}
+            UserRoles::Admin => {
+                if user_role.community_id != resident.role.community_id {
+                    return Err(diesel::result::Error::NotFound);
+                }
+            }
+            UserRoles::Resident => {
+                if user_role.user_id != resident.user.id {
+                    return Err(diesel::result::Error::NotFound);
+                }
+            }
+        }
+
+        Ok(visitor)
+    }
+
+    /// Finds the expected visitor holding `access_code` among the residents the caller manages.
+    pub fn db_read_by_access_code(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        access_code: &str,
+    ) -> diesel::QueryResult<VisitorModel> {
+        let mut query = visitors::table
+            .inner_join(users::table.on(users::entity_id.eq(visitors::resident_id)))
+            .inner_join(user_roles::table.on(user_roles::user_id.eq(users::id)))
+            .filter(visitors::access_code.eq(access_code.to_uppercase()))
+            .filter(visitors::status.eq(VisitorStatus::Expected))
+            .into_boxed();
+
+        if user_role.role != UserRoles::Root {
+            query = query.filter(user_roles::community_id.eq(user_role.community_id));
+        }
+
+        query.select(VisitorModel::as_select()).first(conn)
+    }
+
+    fn filtered_query<'a>(
+        user_role: UserRoleModel,
+        filters: &'a VisitorFilterParams,
+    ) -> visitors::BoxedQuery<'a, diesel::pg::Pg> {
+        let mut query = VisitorModel::table().into_boxed();
+
+        match user_role.role {
+            UserRoles::Root => {}
+            UserRoles::Admin => {
+                query = query.filter(
+                    visitors::resident_id.eq_any(
+                        users::table
+                            .inner_join(user_roles::table.on(user_roles::user_id.eq(users::id)))
+                            .filter(user_roles::community_id.eq(user_role.community_id))
+                            .select(users::entity_id),
+                    ),
+                );
+            }
+            UserRoles::Resident => {
+                query = query.filter(
+                    visitors::resident_id.eq_any(
+                        users::table
+                            .filter(users::id.eq(user_role.user_id))
+                            .select(users::entity_id),
+                    ),
+                );
+            }
+        }
+
+        if let Some(status) = filters.status {
+            query = query.filter(visitors::status.eq(status));
+        }
+
+        if let Some(date) = filters.date {
+            let start = date.and_hms_opt(0, 0, 0).unwrap();
+            let end = start + chrono::Duration::days(1);
+            query = query
+                .filter(visitors::expected_from.lt(end))
+                .filter(visitors::expected_until.ge(start));
+        }
+
+        query
+    }
+
+    pub fn db_count_all_matching(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        filters: &VisitorFilterParams,
+    ) -> diesel::QueryResult<i64> {
+        Self::filtered_query(user_role, filters)
+            .count()
+            .get_result::<i64>(conn)
+    }
+
+    pub fn db_read_all_matching_by_range(
+        user_role: UserRoleModel,
+        conn: &mut PgConnection,
+        filters: &VisitorFilterParams,
+        per_page: i64,
+        offset: i64,
+    ) -> diesel::QueryResult<Vec<VisitorModel>> {
+        Self::filtered_query(user_role, filters)
+            .order(visitors::expected_from.asc())
+            .limit(per_page)
+            .offset(offset)
+            .load::<VisitorModel>(conn)
+    }
+}
+
+impl<DB> ToSql<diesel::sql_types::Text, DB> for VisitorStatus
+where
+    DB: Backend,
+    str: ToSql<diesel::sql_types::Text, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        let s = match self {
+            VisitorStatus::Expected => "Expected",
+            VisitorStatus::CheckedIn => "CheckedIn",
+            VisitorStatus::CheckedOut => "CheckedOut",
+            VisitorStatus::Cancelled => "Cancelled",
+        };
+        s.to_sql(out)
+    }
+}
+
+impl<DB> FromSql<diesel::sql_types::Text, DB> for VisitorStatus
+where
+    DB: Backend,
+    String: FromSql<diesel::sql_types::Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        match <String as FromSql<diesel::sql_types::Text, DB>>::from_sql(bytes)?.as_str() {
+            "Expected" => Ok(VisitorStatus::Expected),
+            "CheckedIn" => Ok(VisitorStatus::CheckedIn),
+            "CheckedOut" => Ok(VisitorStatus::CheckedOut),
+            "Cancelled" => Ok(VisitorStatus::Cancelled),
+            x => Err(format!("Unrecognized variant {}", x).into()),
+        }
+    }
+}
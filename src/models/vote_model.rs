@@ -1,6 +1,19 @@
 use super::prelude::*;
 
-#[derive(Debug, Queryable, Identifiable, Validate)]
+/// Vote cast for a unit. Each unit votes once per election.
+#[derive(
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+    AsChangeset,
+    Validate,
+    ToSchema,
+    DbOps,
+)]
 #[diesel(table_name = votes)]
 pub struct VoteModel {
     pub id: Uuid,
@@ -9,4 +22,13 @@ pub struct VoteModel {
     #[validate(length(max = 50, message = "Option is too long"))]
     pub vote_option: String,
     pub voted_at: NaiveDateTime,
+    pub unit_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Validate, ToSchema)]
+pub struct VoteModelNew {
+    /// Unit the vote is cast for.
+    pub unit_id: Uuid,
+    #[validate(length(min = 1, max = 50, message = "Option is too short or too long"))]
+    pub vote_option: String,
 }
@@ -24,12 +24,82 @@ pub fn admin_route() -> actix_web::Scope {
         )
 }
 
+pub fn announcement_route() -> actix_web::Scope {
+    web::scope("/api/announcement")
+        .route(
+            "/list",
+            web::get()
+                .to(crate::services::announcement_service::get_announcement::get_announcements),
+        )
+        .route(
+            "/get/{id}",
+            web::get().to(
+                crate::services::announcement_service::get_announcement::get_announcement_by_id,
+            ),
+        )
+        .route(
+            "/new",
+            web::post()
+                .to(crate::services::announcement_service::upsert_announcement::new_announcement),
+        )
+}
+
+pub fn attachment_route() -> actix_web::Scope {
+    web::scope("/api/attachment")
+        .route(
+            "/list/{entity_type}/{entity_id}",
+            web::get().to(crate::services::attachment_service::get_attachment::get_attachments),
+        )
+        .route(
+            "/download/{id}",
+            web::get().to(crate::services::attachment_service::get_attachment::download_attachment),
+        )
+        .route(
+            "/upload/{entity_type}/{entity_id}",
+            web::post()
+                .to(crate::services::attachment_service::upsert_attachment::upload_attachment),
+        )
+        .route(
+            "/delete/{id}",
+            web::delete()
+                .to(crate::services::attachment_service::upsert_attachment::delete_attachment),
+        )
+}
+
 pub fn auth_route() -> actix_web::Scope {
     web::scope("/api/auth")
         .route(
             "/signin",
             web::post().to(crate::services::auth_service::sign_in::sign_in),
         )
+        .route(
+            "/refresh",
+            web::post().to(crate::services::auth_service::refresh_token::refresh_token),
+        )
+        .route(
+            "/signin/magic_link/request",
+            web::post().to(crate::services::auth_service::magic_link::request_magic_link),
+        )
+        .route(
+            "/signin/magic_link",
+            web::post().to(crate::services::auth_service::magic_link::sign_in_magic_link),
+        )
+        .route(
+            "/jwks",
+            web::get().to(crate::services::auth_service::jwks::jwks),
+        )
+        .route(
+            "/oidc/providers",
+            web::get().to(crate::services::auth_service::oidc::get_oidc_providers),
+        )
+        .route(
+            "/oidc/{provider}/authorize",
+            web::get().to(crate::services::auth_service::oidc::authorize_oidc),
+        )
+        .route(
+            "/oidc/{provider}/callback",
+            web::post().to(crate::services::auth_service::oidc::oidc_callback),
+        )
         .route(
             "/auth",
             web::get().to(crate::services::auth_service::auth::auth),
@@ -51,6 +121,101 @@ pub fn auth_route() -> actix_web::Scope {
             "/password_reset",
             web::post().to(crate::services::auth_service::password_reset::password_reset),
         )
+        .route(
+            "/change_password",
+            web::post().to(crate::services::auth_service::account::change_password),
+        )
+        .route(
+            "/change_email",
+            web::post().to(crate::services::auth_service::account::request_email_change),
+        )
+        .route(
+            "/change_email/confirm",
+            web::post().to(crate::services::auth_service::account::confirm_email_change),
+        )
+        .route(
+            "/signin/2fa",
+            web::post().to(crate::services::auth_service::two_factor::sign_in_two_factor),
+        )
+        .route(
+            "/2fa/enroll",
+            web::post().to(crate::services::auth_service::two_factor::enroll_two_factor),
+        )
+        .route(
+            "/2fa/confirm",
+            web::post().to(crate::services::auth_service::two_factor::confirm_two_factor),
+        )
+        .route(
+            "/2fa/disable",
+            web::post().to(crate::services::auth_service::two_factor::disable_two_factor),
+        )
+        .route(
+            "/2fa/recovery_codes",
+            web::post().to(crate::services::auth_service::two_factor::regenerate_recovery_codes),
+        )
+        .route(
+            "/2fa/challenge/enroll",
+            web::post().to(crate::services::auth_service::two_factor::enroll_two_factor_challenge),
+        )
+        .route(
+            "/session/list",
+            web::get().to(crate::services::auth_service::sessions::get_sessions),
+        )
+        .route(
+            "/session/revoke_others",
+            web::delete().to(crate::services::auth_service::sessions::revoke_other_sessions),
+        )
+        .route(
+            "/session/revoke_resident/{id}",
+            web::delete().to(crate::services::auth_service::sessions::revoke_resident_sessions),
+        )
+        .route(
+            "/session/revoke/{id}",
+            web::delete().to(crate::services::auth_service::sessions::revoke_session),
+        )
+        .route(
+            "/lockout/unlock_resident/{id}",
+            web::post().to(crate::services::auth_service::lockout::unlock_resident),
+        )
+        .route(
+            "/lockout/unlock_admin/{id}",
+            web::post().to(crate::services::auth_service::lockout::unlock_admin),
+        )
+        .route(
+            "/api_key/list",
+            web::get().to(crate::services::auth_service::api_keys::get_api_keys),
+        )
+        .route(
+            "/api_key/new",
+            web::post().to(crate::services::auth_service::api_keys::new_api_key),
+        )
+        .route(
+            "/api_key/revoke/{id}",
+            web::delete().to(crate::services::auth_service::api_keys::revoke_api_key),
+        )
+        .route(
+            "/service_account/list",
+            web::get().to(crate::services::auth_service::service_accounts::get_service_accounts),
+        )
+        .route(
+            "/service_account/new",
+            web::post().to(crate::services::auth_service::service_accounts::new_service_account),
+        )
+        .route(
+            "/service_account/api_key/new/{id}",
+            web::post()
+                .to(crate::services::auth_service::service_accounts::new_service_account_key),
+        )
+        .route(
+            "/service_account/api_key/revoke/{id}",
+            web::delete()
+                .to(crate::services::auth_service::service_accounts::revoke_service_account_key),
+        )
+        .route(
+            "/service_account/delete/{id}",
+            web::delete()
+                .to(crate::services::auth_service::service_accounts::delete_service_account),
+        )
 }
 
 pub fn common_area_route() -> actix_web::Scope {
@@ -106,6 +271,26 @@ pub fn community_route() -> actix_web::Scope {
         )
 }
 
+pub fn election_route() -> actix_web::Scope {
+    web::scope("/api/election")
+        .route(
+            "/list",
+            web::get().to(crate::services::election_service::get_election::get_elections),
+        )
+        .route(
+            "/get/{id}",
+            web::get().to(crate::services::election_service::get_election::get_election_by_id),
+        )
+        .route(
+            "/new",
+            web::post().to(crate::services::election_service::upsert_election::new_election),
+        )
+        .route(
+            "/vote/{id}",
+            web::post().to(crate::services::election_service::upsert_election::vote_election),
+        )
+}
+
 pub fn incident_route() -> actix_web::Scope {
     web::scope("/api/incident")
         .route(
@@ -128,6 +313,58 @@ pub fn incident_route() -> actix_web::Scope {
             "/delete/{id}",
             web::delete().to(crate::services::incident_service::upsert_incident::delete_incident),
         )
+        .route(
+            "/comment/list/{id}",
+            web::get()
+                .to(crate::services::incident_service::incident_timeline::get_incident_comments),
+        )
+        .route(
+            "/comment/new/{id}",
+            web::post()
+                .to(crate::services::incident_service::incident_timeline::new_incident_comment),
+        )
+        .route(
+            "/history/{id}",
+            web::get().to(
+                crate::services::incident_service::incident_timeline::get_incident_status_history,
+            ),
+        )
+        .route(
+            "/report/resolution",
+            web::get().to(
+                crate::services::incident_service::get_incident::get_incident_resolution_report,
+            ),
+        )
+        .route(
+            "/category/list",
+            web::get()
+                .to(crate::services::incident_service::incident_category::get_incident_categories),
+        )
+        .route(
+            "/category/new",
+            web::post()
+                .to(crate::services::incident_service::incident_category::new_incident_category),
+        )
+        .route(
+            "/category/update/{id}",
+            web::put()
+                .to(crate::services::incident_service::incident_category::update_incident_category),
+        )
+        .route(
+            "/category/delete/{id}",
+            web::delete()
+                .to(crate::services::incident_service::incident_category::delete_incident_category),
+        )
+        .route(
+            "/maintenance/{id}",
+            web::get().to(
+                crate::services::incident_service::incident_maintenance::get_incident_maintenance,
+            ),
+        )
+        .route(
+            "/maintenance/new/{id}",
+            web::post().to(crate::services::incident_service::incident_maintenance::convert_incident_to_maintenance),
+        )
 }
 
 pub fn invoice_route() -> actix_web::Scope {
@@ -180,6 +417,30 @@ pub fn maintenance_schedule_route() -> actix_web::Scope {
             "/delete/{id}",
             web::delete().to(crate::services::maintenance_schedule_service::upsert_maintenance_schedule::delete_maintenance_schedule),
         )
+        .route(
+            "/report/expenses",
+            web::get().to(crate::services::maintenance_schedule_service::get_maintenance_schedule::get_maintenance_expense_report),
+        )
+        .route(
+            "/plan/list",
+            web::get().to(crate::services::maintenance_schedule_service::maintenance_plan::get_maintenance_plans),
+        )
+        .route(
+            "/plan/get/{id}",
+            web::get().to(crate::services::maintenance_schedule_service::maintenance_plan::get_maintenance_plan_by_id),
+        )
+        .route(
+            "/plan/new",
+            web::post().to(crate::services::maintenance_schedule_service::maintenance_plan::new_maintenance_plan),
+        )
+        .route(
+            "/plan/update/{id}",
+            web::put().to(crate::services::maintenance_schedule_service::maintenance_plan::update_maintenance_plan),
+        )
+        .route(
+            "/plan/delete/{id}",
+            web::delete().to(crate::services::maintenance_schedule_service::maintenance_plan::delete_maintenance_plan),
+        )
 }
 
 pub fn parcel_route() -> actix_web::Scope {
@@ -260,6 +521,35 @@ pub fn resident_route() -> actix_web::Scope {
             "/delete/{id}",
             web::delete().to(crate::services::resident_service::upsert_resident::delete_resident),
         )
+        .route(
+            "/import",
+            web::post().to(crate::services::resident_service::resident_import::import_residents),
+        )
+        .route(
+            "/move_out/{id}",
+            web::put().to(crate::services::resident_service::resident_lifecycle::move_out_resident),
+        )
+        .route(
+            "/move_in/{id}",
+            web::put().to(crate::services::resident_service::resident_lifecycle::move_in_resident),
+        )
+        .route(
+            "/checklist/list/{id}",
+            web::get()
+                .to(crate::services::resident_service::resident_lifecycle::get_resident_checklist),
+        )
+        .route(
+            "/checklist/new/{id}",
+            web::post().to(
+                crate::services::resident_service::resident_lifecycle::new_resident_checklist_item,
+            ),
+        )
+        .route(
+            "/checklist/complete/{id}",
+            web::put().to(
+                crate::services::resident_service::resident_lifecycle::complete_resident_checklist_item,
+            ),
+        )
         .route(
             "/invite/new",
             web::post().to(crate::services::resident_service::invite_resident::new_resident_invite),
@@ -283,6 +573,15 @@ pub fn resident_route() -> actix_web::Scope {
             web::get()
                 .to(crate::services::resident_service::invite_resident::count_resident_invite),
         )
+        .route(
+            "/invite/resend/{id}",
+            web::put().to(crate::services::resident_service::invite_resident::resend_resident_invite),
+        )
+        .route(
+            "/invite/bulk",
+            web::post()
+                .to(crate::services::resident_service::invite_resident::new_resident_invite_bulk),
+        )
         .route(
             "/invite/delete/{id}",
             web::delete()
@@ -295,6 +594,46 @@ pub fn resident_route() -> actix_web::Scope {
         )
 }
 
+pub fn unit_route() -> actix_web::Scope {
+    web::scope("/api/unit")
+        .route(
+            "/list",
+            web::get().to(crate::services::unit_service::get_unit::get_units),
+        )
+        .route(
+            "/get/{id}",
+            web::get().to(crate::services::unit_service::get_unit::get_unit_by_id),
+        )
+        .route(
+            "/new",
+            web::post().to(crate::services::unit_service::upsert_unit::new_unit),
+        )
+        .route(
+            "/update/{id}",
+            web::put().to(crate::services::unit_service::upsert_unit::update_unit),
+        )
+        .route(
+            "/delete/{id}",
+            web::delete().to(crate::services::unit_service::upsert_unit::delete_unit),
+        )
+        .route(
+            "/resident/list/{id}",
+            web::get().to(crate::services::unit_service::unit_resident::get_unit_residents),
+        )
+        .route(
+            "/resident/new/{id}",
+            web::post().to(crate::services::unit_service::unit_resident::new_unit_resident),
+        )
+        .route(
+            "/resident/update/{id}",
+            web::put().to(crate::services::unit_service::unit_resident::update_unit_resident),
+        )
+        .route(
+            "/resident/delete/{id}",
+            web::delete().to(crate::services::unit_service::unit_resident::delete_unit_resident),
+        )
+}
+
 pub fn vehicle_route() -> actix_web::Scope {
     web::scope("/api/vehicle")
         .route(
@@ -317,4 +656,80 @@ pub fn vehicle_route() -> actix_web::Scope {
             "/delete/{id}",
             web::delete().to(crate::services::vehicle_service::upsert_vehicle::delete_vehicle),
         )
+        .route(
+            "/access/{plate}",
+            web::get().to(crate::services::vehicle_service::get_vehicle::get_vehicle_access),
+        )
+        .route(
+            "/parking_spot/list",
+            web::get().to(crate::services::vehicle_service::parking_spot::get_parking_spots),
+        )
+        .route(
+            "/parking_spot/new",
+            web::post().to(crate::services::vehicle_service::parking_spot::new_parking_spot),
+        )
+        .route(
+            "/parking_spot/update/{id}",
+            web::put().to(crate::services::vehicle_service::parking_spot::update_parking_spot),
+        )
+        .route(
+            "/parking_spot/delete/{id}",
+            web::delete().to(crate::services::vehicle_service::parking_spot::delete_parking_spot),
+        )
+}
+
+pub fn vendor_route() -> actix_web::Scope {
+    web::scope("/api/vendor")
+        .route(
+            "/list",
+            web::get().to(crate::services::vendor_service::get_vendor::get_vendors),
+        )
+        .route(
+            "/get/{id}",
+            web::get().to(crate::services::vendor_service::get_vendor::get_vendor_by_id),
+        )
+        .route(
+            "/new",
+            web::post().to(crate::services::vendor_service::upsert_vendor::new_vendor),
+        )
+        .route(
+            "/update/{id}",
+            web::put().to(crate::services::vendor_service::upsert_vendor::update_vendor),
+        )
+        .route(
+            "/delete/{id}",
+            web::delete().to(crate::services::vendor_service::upsert_vendor::delete_vendor),
+        )
+}
+
+pub fn visitor_route() -> actix_web::Scope {
+    web::scope("/api/visitor")
+        .route(
+            "/list",
+            web::get().to(crate::services::visitor_service::get_visitor::get_visitors),
+        )
+        .route(
+            "/get/{id}",
+            web::get().to(crate::services::visitor_service::get_visitor::get_visitor_by_id),
+        )
+        .route(
+            "/new",
+            web::post().to(crate::services::visitor_service::upsert_visitor::new_visitor),
+        )
+        .route(
+            "/cancel/{id}",
+            web::put().to(crate::services::visitor_service::upsert_visitor::cancel_visitor),
+        )
+        .route(
+            "/delete/{id}",
+            web::delete().to(crate::services::visitor_service::upsert_visitor::delete_visitor),
+        )
+        .route(
+            "/check_in",
+            web::post().to(crate::services::visitor_service::visitor_desk::check_in_visitor),
+        )
+        .route(
+            "/check_out/{id}",
+            web::put().to(crate::services::visitor_service::visitor_desk::check_out_visitor),
+        )
 }
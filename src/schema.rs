@@ -1,5 +1,16 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    account_lockouts (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        failed_attempts -> Int4,
+        lockouts -> Int4,
+        locked_until -> Nullable<Timestamp>,
+        updated_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     admins (id) {
         id -> Uuid,
@@ -20,6 +31,53 @@ diesel::table! {
         community_id -> Nullable<Uuid>,
         message -> Text,
         sent_at -> Timestamp,
+        audience -> Text,
+    }
+}
+
+diesel::table! {
+    api_keys (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 100]
+        name -> Varchar,
+        #[max_length = 16]
+        prefix -> Varchar,
+        #[max_length = 64]
+        key_hash -> Varchar,
+        scopes -> Text,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+        last_used_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    attachments (id) {
+        id -> Uuid,
+        #[max_length = 30]
+        entity_type -> Varchar,
+        entity_id -> Uuid,
+        file_name -> Text,
+        #[max_length = 100]
+        content_type -> Varchar,
+        size_bytes -> Int8,
+        storage_key -> Text,
+        uploaded_by -> Nullable<Uuid>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    auth_challenges (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        enrolment_required -> Bool,
+        attempts -> Int4,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
     }
 }
 
@@ -58,6 +116,7 @@ diesel::table! {
         address -> Text,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        require_admin_2fa -> Bool,
     }
 }
 
@@ -74,6 +133,53 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    email_changes (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 255]
+        new_email -> Varchar,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    incident_categories (id) {
+        id -> Uuid,
+        community_id -> Uuid,
+        name -> Text,
+        response_sla_hours -> Int4,
+        resolution_sla_hours -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    incident_comments (id) {
+        id -> Uuid,
+        incident_id -> Uuid,
+        user_id -> Uuid,
+        message -> Text,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    incident_status_history (id) {
+        id -> Uuid,
+        incident_id -> Uuid,
+        #[max_length = 20]
+        old_status -> Nullable<Varchar>,
+        #[max_length = 20]
+        new_status -> Varchar,
+        changed_by -> Nullable<Uuid>,
+        changed_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     incidents (id) {
         id -> Uuid,
@@ -86,6 +192,13 @@ diesel::table! {
         report_date -> Timestamp,
         resolution_date -> Nullable<Timestamp>,
         notes -> Nullable<Text>,
+        assignee_id -> Nullable<Uuid>,
+        category_id -> Nullable<Uuid>,
+        #[max_length = 20]
+        priority -> Varchar,
+        response_date -> Nullable<Timestamp>,
+        response_sla_breached -> Bool,
+        resolution_sla_breached -> Bool,
     }
 }
 
@@ -102,6 +215,38 @@ diesel::table! {
         paid_date -> Nullable<Date>,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        unit_id -> Nullable<Uuid>,
+        charge_type -> Text,
+    }
+}
+
+diesel::table! {
+    magic_links (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    maintenance_plans (id) {
+        id -> Uuid,
+        community_id -> Uuid,
+        description -> Text,
+        details -> Nullable<Text>,
+        #[max_length = 20]
+        frequency -> Varchar,
+        frequency_interval -> Int4,
+        start_date -> Timestamp,
+        end_date -> Nullable<Timestamp>,
+        active -> Bool,
+        occurrence_count -> Int4,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        vendor_id -> Nullable<Uuid>,
     }
 }
 
@@ -116,6 +261,29 @@ diesel::table! {
         details -> Nullable<Text>,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        incident_id -> Nullable<Uuid>,
+        plan_id -> Nullable<Uuid>,
+        vendor_id -> Nullable<Uuid>,
+        cost -> Nullable<Numeric>,
+        invoice_number -> Nullable<Text>,
+        completed_at -> Nullable<Timestamp>,
+        reminder_sent_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    oidc_states (id) {
+        id -> Uuid,
+        #[max_length = 64]
+        provider -> Varchar,
+        #[max_length = 64]
+        state_hash -> Varchar,
+        #[max_length = 64]
+        nonce -> Varchar,
+        #[max_length = 128]
+        code_verifier -> Varchar,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
     }
 }
 
@@ -129,6 +297,31 @@ diesel::table! {
         arrival_date -> Timestamp,
         received -> Bool,
         received_at -> Nullable<Timestamp>,
+        reminder_sent_at -> Nullable<Timestamp>,
+        flagged_for_return -> Bool,
+        flagged_for_return_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    parking_spots (id) {
+        id -> Uuid,
+        community_id -> Uuid,
+        #[max_length = 20]
+        code -> Varchar,
+        notes -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        unit_id -> Nullable<Uuid>,
+    }
+}
+
+diesel::table! {
+    password_history (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        password -> Text,
+        created_at -> Timestamp,
     }
 }
 
@@ -139,6 +332,19 @@ diesel::table! {
         user_id -> Uuid,
         token -> Text,
         created_at -> Timestamp,
+        expires_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    refresh_tokens (id) {
+        id -> Uuid,
+        auth_token_id -> Uuid,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        used_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
     }
 }
 
@@ -157,6 +363,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    resident_checklist_items (id) {
+        id -> Uuid,
+        resident_id -> Uuid,
+        #[max_length = 150]
+        item -> Varchar,
+        completed_at -> Nullable<Timestamp>,
+        completed_by -> Nullable<Uuid>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     resident_invites (id) {
         id -> Uuid,
@@ -164,6 +382,10 @@ diesel::table! {
         community_id -> Uuid,
         key -> Text,
         created_at -> Timestamp,
+        status -> Text,
+        expires_at -> Timestamp,
+        opened_at -> Nullable<Timestamp>,
+        accepted_at -> Nullable<Timestamp>,
     }
 }
 
@@ -172,8 +394,6 @@ diesel::table! {
         id -> Uuid,
         first_name -> Text,
         last_name -> Text,
-        #[max_length = 20]
-        unit_number -> Nullable<Varchar>,
         address -> Nullable<Text>,
         phone -> Nullable<Text>,
         email -> Text,
@@ -181,6 +401,84 @@ diesel::table! {
         is_active -> Bool,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        moved_in_at -> Nullable<Date>,
+        moved_out_at -> Nullable<Date>,
+    }
+}
+
+diesel::table! {
+    service_accounts (id) {
+        id -> Uuid,
+        community_id -> Uuid,
+        #[max_length = 100]
+        name -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    sign_in_attempts (id) {
+        id -> Uuid,
+        #[max_length = 255]
+        email -> Varchar,
+        #[max_length = 45]
+        ip_address -> Varchar,
+        user_id -> Nullable<Uuid>,
+        successful -> Bool,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    unit_residents (id) {
+        id -> Uuid,
+        unit_id -> Uuid,
+        resident_id -> Uuid,
+        relation -> Text,
+        created_at -> Timestamp,
+        start_date -> Date,
+        end_date -> Nullable<Date>,
+        voting_proxy -> Bool,
+    }
+}
+
+diesel::table! {
+    units (id) {
+        id -> Uuid,
+        community_id -> Uuid,
+        #[max_length = 20]
+        block -> Nullable<Varchar>,
+        #[max_length = 20]
+        number -> Varchar,
+        ideal_fraction -> Numeric,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    user_identities (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 64]
+        provider -> Varchar,
+        #[max_length = 255]
+        subject -> Varchar,
+        #[max_length = 255]
+        email -> Varchar,
+        created_at -> Timestamp,
+        last_used_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    user_recovery_codes (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 64]
+        code_hash -> Varchar,
+        used_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
     }
 }
 
@@ -195,6 +493,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    user_totp (id) {
+        id -> Uuid,
+        user_id -> Uuid,
+        #[max_length = 64]
+        secret -> Varchar,
+        enabled_at -> Nullable<Timestamp>,
+        last_used_step -> Nullable<Int8>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     users (id) {
         id -> Uuid,
@@ -206,6 +516,7 @@ diesel::table! {
         password -> Text,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        service_account_id -> Nullable<Uuid>,
     }
 }
 
@@ -221,6 +532,48 @@ diesel::table! {
         color -> Nullable<Varchar>,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        parking_spot_id -> Nullable<Uuid>,
+        authorized -> Bool,
+    }
+}
+
+diesel::table! {
+    vendors (id) {
+        id -> Uuid,
+        community_id -> Uuid,
+        company_name -> Text,
+        contact_name -> Nullable<Text>,
+        email -> Nullable<Text>,
+        phone -> Nullable<Text>,
+        service_type -> Text,
+        contract_start_date -> Nullable<Date>,
+        contract_end_date -> Nullable<Date>,
+        insurance_expiry_date -> Nullable<Date>,
+        notes -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    visitors (id) {
+        id -> Uuid,
+        resident_id -> Uuid,
+        name -> Text,
+        #[max_length = 30]
+        document -> Varchar,
+        #[max_length = 20]
+        license_plate -> Nullable<Varchar>,
+        expected_from -> Timestamp,
+        expected_until -> Timestamp,
+        #[max_length = 8]
+        access_code -> Varchar,
+        status -> Text,
+        checked_in_at -> Nullable<Timestamp>,
+        checked_in_by -> Nullable<Uuid>,
+        checked_out_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
     }
 }
 
@@ -232,48 +585,112 @@ diesel::table! {
         #[max_length = 50]
         vote_option -> Varchar,
         voted_at -> Timestamp,
+        unit_id -> Nullable<Uuid>,
     }
 }
 
+diesel::joinable!(account_lockouts -> users (user_id));
 diesel::joinable!(announcements -> communities (community_id));
+diesel::joinable!(api_keys -> users (user_id));
+diesel::joinable!(attachments -> users (uploaded_by));
+diesel::joinable!(auth_challenges -> users (user_id));
 diesel::joinable!(auth_tokens -> users (user_id));
 diesel::joinable!(common_areas -> communities (community_id));
 diesel::joinable!(elections -> communities (community_id));
+diesel::joinable!(email_changes -> users (user_id));
+diesel::joinable!(incident_categories -> communities (community_id));
+diesel::joinable!(incident_comments -> incidents (incident_id));
+diesel::joinable!(incident_comments -> users (user_id));
+diesel::joinable!(incident_status_history -> incidents (incident_id));
+diesel::joinable!(incident_status_history -> users (changed_by));
 diesel::joinable!(incidents -> communities (community_id));
+diesel::joinable!(incidents -> incident_categories (category_id));
 diesel::joinable!(incidents -> residents (resident_id));
+diesel::joinable!(incidents -> users (assignee_id));
 diesel::joinable!(invoices -> communities (community_id));
 diesel::joinable!(invoices -> residents (resident_id));
+diesel::joinable!(invoices -> units (unit_id));
+diesel::joinable!(magic_links -> users (user_id));
+diesel::joinable!(maintenance_plans -> communities (community_id));
+diesel::joinable!(maintenance_plans -> vendors (vendor_id));
 diesel::joinable!(maintenance_schedules -> communities (community_id));
+diesel::joinable!(maintenance_schedules -> incidents (incident_id));
+diesel::joinable!(maintenance_schedules -> maintenance_plans (plan_id));
+diesel::joinable!(maintenance_schedules -> vendors (vendor_id));
 diesel::joinable!(parcels -> residents (resident_id));
+diesel::joinable!(parking_spots -> communities (community_id));
+diesel::joinable!(parking_spots -> units (unit_id));
+diesel::joinable!(password_history -> users (user_id));
 diesel::joinable!(password_reset -> users (user_id));
+diesel::joinable!(refresh_tokens -> auth_tokens (auth_token_id));
 diesel::joinable!(reservations -> common_areas (common_area_id));
 diesel::joinable!(reservations -> residents (resident_id));
+diesel::joinable!(resident_checklist_items -> residents (resident_id));
+diesel::joinable!(resident_checklist_items -> users (completed_by));
 diesel::joinable!(resident_invites -> communities (community_id));
+diesel::joinable!(service_accounts -> communities (community_id));
+diesel::joinable!(sign_in_attempts -> users (user_id));
+diesel::joinable!(unit_residents -> residents (resident_id));
+diesel::joinable!(unit_residents -> units (unit_id));
+diesel::joinable!(units -> communities (community_id));
+diesel::joinable!(user_identities -> users (user_id));
+diesel::joinable!(user_recovery_codes -> users (user_id));
 diesel::joinable!(user_roles -> communities (community_id));
 diesel::joinable!(user_roles -> users (user_id));
+diesel::joinable!(user_totp -> users (user_id));
 diesel::joinable!(users -> admins (admin_id));
 diesel::joinable!(users -> residents (resident_id));
+diesel::joinable!(users -> service_accounts (service_account_id));
+diesel::joinable!(vehicles -> parking_spots (parking_spot_id));
 diesel::joinable!(vehicles -> residents (resident_id));
+diesel::joinable!(vendors -> communities (community_id));
+diesel::joinable!(visitors -> residents (resident_id));
+diesel::joinable!(visitors -> users (checked_in_by));
 diesel::joinable!(votes -> elections (election_id));
 diesel::joinable!(votes -> residents (resident_id));
+diesel::joinable!(votes -> units (unit_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    account_lockouts,
     admins,
     announcements,
+    api_keys,
+    attachments,
+    auth_challenges,
     auth_tokens,
     common_areas,
     communities,
     elections,
+    email_changes,
+    incident_categories,
+    incident_comments,
+    incident_status_history,
     incidents,
     invoices,
+    magic_links,
+    maintenance_plans,
     maintenance_schedules,
+    oidc_states,
     parcels,
+    parking_spots,
+    password_history,
     password_reset,
+    refresh_tokens,
     reservations,
+    resident_checklist_items,
     resident_invites,
     residents,
+    service_accounts,
+    sign_in_attempts,
+    unit_residents,
+    units,
+    user_identities,
+    user_recovery_codes,
     user_roles,
+    user_totp,
     users,
     vehicles,
+    vendors,
+    visitors,
     votes,
 );
@@ -33,7 +33,7 @@ pub async fn get_admins(
 
     let conn = &mut establish_connection_pg(&conf);
 
-    let admin_role = match authenticate_user(req.clone(), conn, conf) {
+    let admin_role = match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 role
@@ -55,7 +55,7 @@ pub async fn get_admins(
     let mut total_items_query = user_role_model::UserRoleModel::table()
         .inner_join(users::table.on(users::id.eq(user_roles::user_id)))
         .inner_join(admins::table.on(admins::id.eq(users::entity_id)))
-        .filter(users::entity_type.eq("admin"))
+        .filter(users::entity_type.eq(UserTypes::Admin))
         .into_boxed();
 
     match admin_role.role {
@@ -159,7 +159,7 @@ pub async fn get_admin_by_id(
         }
     };
 
-    let admin_role = match authenticate_user(req.clone(), conn, conf) {
+    let admin_role = match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 role
@@ -39,7 +39,7 @@ pub async fn new_admin(
             }
         };
         if total_root_admins > 0 {
-            match authenticate_user(req.clone(), conn, conf) {
+            match authenticate_user(req.clone(), conn) {
                 Ok((role, _claims, _token)) => {
                     if role.role != UserRoles::Root {
                         return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -57,7 +57,7 @@ pub async fn new_admin(
             }
         }
     } else if body.role == UserRoles::Admin {
-        match authenticate_user(req.clone(), conn, conf) {
+        match authenticate_user(req.clone(), conn) {
             Ok((role, _claims, _token)) => {
                 if role.role != UserRoles::Root {
                     if body.community_id.is_none() || role.community_id.is_none() {
@@ -106,6 +106,17 @@ pub async fn new_admin(
         }
     }
 
+    if let Err(e) = check_password_policy(
+        &body.password,
+        &conf.password_policy,
+        &[&body.first_name, &body.last_name, &body.email],
+    ) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: e.to_string(),
+        });
+    }
+
     let new_obj = admin_model::AdminModel {
         id: admin_model::AdminModel::new_id_user(conn),
         first_name: body.first_name,
@@ -147,6 +158,7 @@ pub async fn new_admin(
         password: hashed_password,
         created_at: chrono::Utc::now().naive_utc(),
         updated_at: chrono::Utc::now().naive_utc(),
+        service_account_id: None,
     };
 
     match new_obj_user.db_insert(conn) {
@@ -214,7 +226,7 @@ pub async fn update_admin(
     let conn = &mut establish_connection_pg(&conf);
     let body = body.into_inner();
 
-    match authenticate_user(req.clone(), conn, conf) {
+    match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => {
             if body.role == UserRoles::Admin {
                 if role.role != UserRoles::Root {
@@ -380,7 +392,7 @@ pub async fn delete_admin(
         }
     };
 
-    match authenticate_user(req.clone(), conn, conf) {
+    match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => {
             if role.role != UserRoles::Root {
                 if adm_user_role.community_id.is_none() || role.community_id.is_none() {
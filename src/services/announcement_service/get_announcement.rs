@@ -0,0 +1,103 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "Announcement",
+    path = "/list",
+    responses(
+        (status = 200, description = "Got Announcements successfully", body = AnnouncementListHttpResponse),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_announcements(
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match announcement_model::AnnouncementModel::db_read_all_matching(role, conn) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Announcements successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Announcements: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Error getting Announcements".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    tag = "Announcement",
+    path = "/get/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Announcement ID"),
+    ),
+    responses(
+        (status = 200, description = "Got Announcement successfully", body = AnnouncementGetHttpResponse),
+        (status = 400, description = "Invalid Announcement ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 404, description = "Announcement not found", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_announcement_by_id(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Announcement ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match announcement_model::AnnouncementModel::db_read_by_id_matching_community(role, conn, id) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Announcement successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(_) => HttpResponse::NotFound().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Announcement not found".to_string(),
+        }),
+    }
+}
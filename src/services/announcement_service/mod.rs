@@ -0,0 +1,21 @@
+pub mod get_announcement;
+pub mod upsert_announcement;
+
+use super::prelude::*;
+type AnnouncementListHttpResponse = HttpResponseObject<Vec<announcement_model::AnnouncementModel>>;
+type AnnouncementGetHttpResponse = HttpResponseObject<announcement_model::AnnouncementModel>;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_announcement::get_announcements,
+        get_announcement::get_announcement_by_id,
+        upsert_announcement::new_announcement,
+    ),
+    components(schemas(
+        announcement_model::AnnouncementModel,
+        announcement_model::AnnouncementModelNew,
+        announcement_model::AnnouncementAudience
+    ))
+)]
+pub struct AnnouncementApi;
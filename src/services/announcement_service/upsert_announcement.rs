@@ -0,0 +1,121 @@
+use super::*;
+
+/// Emails the announcement to its audience. Returns how many emails were queued.
+async fn notify_announcement_recipients(
+    conn: &mut PgConnection,
+    conf: &MyCondominiumConfig,
+    announcement: &announcement_model::AnnouncementModel,
+    community_id: Uuid,
+) -> usize {
+    let recipients = match announcement.db_read_recipients(conn, community_id) {
+        Ok(recipients) => recipients,
+        Err(e) => {
+            log::error!("Error reading Announcement recipients: {}", e);
+            return 0;
+        }
+    };
+
+    let rabbitmq = match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await
+    {
+        Ok(rabbitmq) => rabbitmq,
+        Err(e) => {
+            log::error!("Error connecting to RabbitMQ: {}", e);
+            return 0;
+        }
+    };
+
+    let mut sent = 0;
+
+    for resident in recipients {
+        let email = SmtpEmailPayload::from_template(
+            resident.email.clone(),
+            announcement.title.clone(),
+            SmtpTemplate::Announcement,
+            announcement.email_parameters(&resident),
+        );
+
+        match email.publish(&rabbitmq).await {
+            Ok(_) => sent += 1,
+            Err(e) => log::error!(
+                "Error queueing Announcement email to {}: {}",
+                resident.email,
+                e
+            ),
+        }
+    }
+
+    sent
+}
+
+#[utoipa::path(
+    post,
+    tag = "Announcement",
+    path = "/new",
+    request_body = announcement_model::AnnouncementModelNew,
+    responses (
+        (status = 200, description = "Announcement sent successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Error sending Announcement", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error sending Announcement", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_announcement(
+    body: web::Json<announcement_model::AnnouncementModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if !(role.role == UserRoles::Root
+        || (role.role == UserRoles::Admin && role.community_id == Some(body.community_id)))
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let new_obj = announcement_model::AnnouncementModel {
+        id: announcement_model::AnnouncementModel::new_id(conn),
+        title: body.title,
+        community_id: Some(body.community_id),
+        message: body.message,
+        sent_at: chrono::Utc::now().naive_utc(),
+        audience: body.audience,
+    };
+
+    if let Err(e) = new_obj.db_insert(conn) {
+        log::error!("Error creating Announcement: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Error sending Announcement".to_string(),
+        });
+    }
+
+    let sent = notify_announcement_recipients(conn, &conf, &new_obj, body.community_id).await;
+
+    HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+        error: false,
+        message: format!("Announcement sent to {} residents", sent),
+        entity_id: Some(new_obj.id),
+    })
+}
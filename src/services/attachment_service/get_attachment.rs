@@ -0,0 +1,207 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "Attachment",
+    path = "/list/{entity_type}/{entity_id}",
+    params(
+        ("entity_type" = attachment_model::AttachmentEntityType, Path, description = "Type of the record the attachments belong to"),
+        ("entity_id" = Uuid, Path, description = "ID of the record the attachments belong to"),
+    ),
+    responses(
+        (status = 200, description = "Got Attachments successfully", body = AttachmentListHttpResponse),
+        (status = 400, description = "Invalid ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_attachments(
+    path: web::Path<(attachment_model::AttachmentEntityType, String)>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let (entity_type, entity_id) = path.into_inner();
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    let entity_id = match Uuid::parse_str(&entity_id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if attachment_model::AttachmentModel::db_check_entity_access(
+        role,
+        conn,
+        entity_type,
+        entity_id,
+        false,
+    )
+    .is_err()
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match attachment_model::AttachmentModel::db_read_all_by_entity(conn, entity_type, entity_id) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Attachments successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Attachments: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Attachments: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    tag = "Attachment",
+    path = "/download/{id}",
+    description = "Redirects to a presigned storage URL, or streams the file through the API when presigned URLs are disabled.",
+    params(
+        ("id" = Uuid, Path, description = "Attachment ID"),
+    ),
+    responses(
+        (status = 200, description = "Attachment content", content_type = "application/octet-stream"),
+        (status = 302, description = "Redirect to a presigned download URL"),
+        (status = 400, description = "Invalid Attachment ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn download_attachment(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Attachment ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let attachment = match attachment_model::AttachmentModel::db_read_by_id(conn, id) {
+        Ok(res) => res,
+        Err(e) => {
+            log::error!("Error getting Attachment: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Error getting Attachment".to_string(),
+            });
+        }
+    };
+
+    if attachment_model::AttachmentModel::db_check_entity_access(
+        role,
+        conn,
+        attachment.entity_type,
+        attachment.entity_id,
+        false,
+    )
+    .is_err()
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    let storage = match StorageClient::new(&conf.minio) {
+        Ok(storage) => storage,
+        Err(e) => {
+            log::error!("Error connecting to storage: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error getting Attachment".to_string(),
+            });
+        }
+    };
+
+    if conf.attachment.presigned_url_expiration_secs > 0 {
+        match storage
+            .presigned_get_url(
+                &attachment.storage_key,
+                std::time::Duration::from_secs(conf.attachment.presigned_url_expiration_secs),
+            )
+            .await
+        {
+            Ok(Some(url)) => {
+                return HttpResponse::Found()
+                    .append_header((header::LOCATION, url))
+                    .finish();
+            }
+            Ok(None) => {}
+            Err(e) => {
+                log::error!("Error signing Attachment URL: {}", e);
+                return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Error getting Attachment".to_string(),
+                });
+            }
+        }
+    }
+
+    match storage.get(&attachment.storage_key).await {
+        Ok(data) => HttpResponse::Ok()
+            .insert_header((header::CONTENT_TYPE, attachment.content_type))
+            .insert_header((
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"{}\"",
+                    attachment.file_name.replace('"', "")
+                ),
+            ))
+            .body(data),
+        Err(e) => {
+            log::error!("Error reading Attachment from storage: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error getting Attachment".to_string(),
+            })
+        }
+    }
+}
@@ -0,0 +1,22 @@
+pub mod get_attachment;
+pub mod upsert_attachment;
+
+use super::prelude::*;
+use crate::internal::storage::storage_client::StorageClient;
+type AttachmentListHttpResponse = HttpResponseObject<Vec<attachment_model::AttachmentModel>>;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_attachment::get_attachments,
+        get_attachment::download_attachment,
+        upsert_attachment::upload_attachment,
+        upsert_attachment::delete_attachment,
+    ),
+    components(schemas(
+        attachment_model::AttachmentModel,
+        attachment_model::AttachmentEntityType,
+        attachment_model::AttachmentUploadForm
+    ))
+)]
+pub struct AttachmentApi;
@@ -0,0 +1,311 @@
+use super::*;
+use actix_multipart::Multipart;
+use futures_util::StreamExt;
+
+#[utoipa::path(
+    post,
+    tag = "Attachment",
+    path = "/upload/{entity_type}/{entity_id}",
+    request_body(content = attachment_model::AttachmentUploadForm, content_type = "multipart/form-data"),
+    params(
+        ("entity_type" = attachment_model::AttachmentEntityType, Path, description = "Type of the record to attach the file to"),
+        ("entity_id" = Uuid, Path, description = "ID of the record to attach the file to"),
+    ),
+    responses (
+        (status = 200, description = "Attachment uploaded successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Invalid file, size or content type", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error uploading Attachment", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn upload_attachment(
+    path: web::Path<(attachment_model::AttachmentEntityType, String)>,
+    mut payload: Multipart,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let (entity_type, entity_id) = path.into_inner();
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    let entity_id = match Uuid::parse_str(&entity_id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if attachment_model::AttachmentModel::db_check_entity_access(
+        role.clone(),
+        conn,
+        entity_type,
+        entity_id,
+        true,
+    )
+    .is_err()
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    let mut file: Option<(String, String, Vec<u8>)> = None;
+
+    while let Some(item) = payload.next().await {
+        let mut field = match item {
+            Ok(field) => field,
+            Err(e) => {
+                log::error!("Error reading multipart payload: {}", e);
+                return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Invalid multipart payload".to_string(),
+                });
+            }
+        };
+
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let file_name = field
+            .content_disposition()
+            .and_then(|cd| cd.get_filename())
+            .unwrap_or("attachment")
+            .to_string();
+
+        let content_type = match field.content_type() {
+            Some(mime) => mime.essence_str().to_string(),
+            None => "application/octet-stream".to_string(),
+        };
+
+        if !conf
+            .attachment
+            .allowed_content_types
+            .iter()
+            .any(|allowed| allowed == &content_type)
+        {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Content type {} is not allowed", content_type),
+            });
+        }
+
+        let mut data: Vec<u8> = Vec::new();
+
+        while let Some(chunk) = field.next().await {
+            match chunk {
+                Ok(bytes) => {
+                    if data.len() + bytes.len() > conf.attachment.max_size_bytes {
+                        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                            error: true,
+                            message: format!(
+                                "File exceeds the maximum size of {} bytes",
+                                conf.attachment.max_size_bytes
+                            ),
+                        });
+                    }
+                    data.extend_from_slice(&bytes);
+                }
+                Err(e) => {
+                    log::error!("Error reading multipart field: {}", e);
+                    return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                        error: true,
+                        message: "Invalid multipart payload".to_string(),
+                    });
+                }
+            }
+        }
+
+        file = Some((file_name, content_type, data));
+        break;
+    }
+
+    let (file_name, content_type, data) = match file {
+        Some(file) => file,
+        None => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Missing file field".to_string(),
+            });
+        }
+    };
+
+    let id = attachment_model::AttachmentModel::new_id(conn);
+
+    let new_obj = attachment_model::AttachmentModel {
+        id,
+        entity_type,
+        entity_id,
+        file_name,
+        content_type,
+        size_bytes: data.len() as i64,
+        storage_key: attachment_model::AttachmentModel::storage_key(entity_type, entity_id, id),
+        uploaded_by: Some(role.user_id),
+        created_at: chrono::Utc::now().naive_utc(),
+    };
+
+    if let Err(validation_errors) = new_obj.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let storage = match StorageClient::new(&conf.minio) {
+        Ok(storage) => storage,
+        Err(e) => {
+            log::error!("Error connecting to storage: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error uploading Attachment".to_string(),
+            });
+        }
+    };
+
+    if let Err(e) = storage.put(&new_obj.storage_key, data).await {
+        log::error!("Error uploading Attachment to storage: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Error uploading Attachment".to_string(),
+        });
+    }
+
+    match new_obj.db_insert(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+            error: false,
+            message: "Attachment uploaded successfully".to_string(),
+            entity_id: Some(new_obj.id),
+        }),
+        Err(e) => {
+            log::error!("Error creating Attachment: {}", e);
+
+            if let Err(e) = storage.delete(&new_obj.storage_key).await {
+                log::error!("Error removing orphaned Attachment from storage: {}", e);
+            }
+
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error creating Attachment: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Attachment",
+    path = "/delete/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Attachment ID"),
+    ),
+    responses (
+        (status = 200, description = "Attachment deleted successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid Attachment ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error deleting Attachment", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn delete_attachment(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Attachment ID format".to_string(),
+            });
+        }
+    };
+
+    let curr_obj = match attachment_model::AttachmentModel::db_read_by_id(conn, id) {
+        Ok(res) => res,
+        Err(e) => {
+            log::error!("Error deleting Attachment: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Error deleting Attachment".to_string(),
+            });
+        }
+    };
+
+    if attachment_model::AttachmentModel::db_check_entity_access(
+        role.clone(),
+        conn,
+        curr_obj.entity_type,
+        curr_obj.entity_id,
+        true,
+    )
+    .is_err()
+        || (role.role == UserRoles::Resident && curr_obj.uploaded_by != Some(role.user_id))
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    let storage = match StorageClient::new(&conf.minio) {
+        Ok(storage) => storage,
+        Err(e) => {
+            log::error!("Error connecting to storage: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error deleting Attachment".to_string(),
+            });
+        }
+    };
+
+    if let Err(e) = storage.delete(&curr_obj.storage_key).await {
+        log::error!("Error deleting Attachment from storage: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Error deleting Attachment".to_string(),
+        });
+    }
+
+    match attachment_model::AttachmentModel::db_delete_by_id(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Attachment deleted successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error deleting Attachment: {}", e);
+            HttpResponse::Ok().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error deleting Attachment: {}", e),
+            })
+        }
+    }
+}
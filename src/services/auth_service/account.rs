@@ -0,0 +1,349 @@
+use super::*;
+use chrono::Datelike;
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/change_password",
+    request_body = auth_model::ChangePasswordModel,
+    responses (
+        (status = 200, description = "Password changed successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid password", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error changing password", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn change_password(
+    body: web::Json<auth_model::ChangePasswordModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (_role, claims, token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let body = body.into_inner();
+
+    let mut user = match UserModel::db_read_by_id(conn, claims.user_id) {
+        Ok(usr) => usr,
+        Err(e) => {
+            log::error!("Error reading user: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error changing password".to_string(),
+            });
+        }
+    };
+
+    if !check_password(body.current_password, user.password.clone()).unwrap_or(false) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Current password is incorrect".to_string(),
+        });
+    }
+
+    let (personal_info, previous_passwords) = match (
+        user.db_read_personal_info(conn),
+        user.db_read_password_history(conn, conf.password_policy.history_count),
+    ) {
+        (Ok(info), Ok(history)) => (info, history),
+        (Err(e), _) | (_, Err(e)) => {
+            log::error!("Error reading user: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error changing password".to_string(),
+            });
+        }
+    };
+
+    if let Err(e) = check_password_policy(
+        &body.new_password,
+        &conf.password_policy,
+        &personal_info.words(),
+    )
+    .and_then(|_| check_password_reuse(&body.new_password, &previous_passwords))
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: e.to_string(),
+        });
+    }
+
+    let hashed_password = match hash_password(body.new_password) {
+        Ok(passwd) => passwd,
+        Err(e) => {
+            log::error!("Error hashing password: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error changing password".to_string(),
+            });
+        }
+    };
+
+    if let Err(e) =
+        user.db_update_password(conn, hashed_password, conf.password_policy.history_count)
+    {
+        log::error!("Error updating user: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Error changing password".to_string(),
+        });
+    }
+
+    // Whoever knew the old password may still hold a session, so only this one is kept.
+    if let Err(e) =
+        auth_token_model::AuthTokenModel::db_delete_by_user(conn, user.id, Some(token.id))
+    {
+        log::error!("Error revoking sessions: {}", e);
+    }
+
+    queue_email(
+        &conf,
+        SmtpEmailPayload::from_template(
+            personal_info.email,
+            "Senha Alterada - MyCondominium".to_string(),
+            SmtpTemplate::PasswordResetWarning,
+            vec![
+                SmtpTemplateData {
+                    key: "{{USER_NAME}}".to_string(),
+                    value: escape_html(&personal_info.first_name),
+                },
+                SmtpTemplateData {
+                    key: "{{CURRENT_YEAR}}".to_string(),
+                    value: chrono::Utc::now().year().to_string(),
+                },
+            ],
+        ),
+    )
+    .await;
+
+    HttpResponse::Ok().json(HttpResponseObjectEmpty {
+        error: false,
+        message: "Password changed successfully".to_string(),
+    })
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/change_email",
+    request_body = auth_model::ChangeEmailRequestModel,
+    responses (
+        (status = 200, description = "Confirmation link sent successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid email or password", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error changing email", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn request_email_change(
+    body: web::Json<auth_model::ChangeEmailRequestModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (_role, claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let body = body.into_inner();
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let new_email = body.new_email.trim().to_lowercase();
+
+    let (user, personal_info) = match UserModel::db_read_by_id(conn, claims.user_id)
+        .and_then(|usr| usr.db_read_personal_info(conn).map(|info| (usr, info)))
+    {
+        Ok(result) => result,
+        Err(e) => {
+            log::error!("Error reading user: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error changing email".to_string(),
+            });
+        }
+    };
+
+    if !check_password(body.password, user.password.clone()).unwrap_or(false) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Password is incorrect".to_string(),
+        });
+    }
+
+    if check_email_exist(conn, new_email.clone()).is_err() {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Email already in use".to_string(),
+        });
+    }
+
+    let token = generate_token();
+    let email_change = auth_model::EmailChangeModel::new(conn, user.id, new_email.clone(), &token);
+
+    if let Err(e) = email_change.db_replace(conn) {
+        log::error!("Error inserting email change: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Error changing email".to_string(),
+        });
+    }
+
+    queue_email(
+        &conf,
+        SmtpEmailPayload::from_template(
+            new_email,
+            "Confirmação de E-mail - MyCondominium".to_string(),
+            SmtpTemplate::EmailChangeConfirmation,
+            vec![
+                SmtpTemplateData {
+                    key: "{{USER_NAME}}".to_string(),
+                    value: escape_html(&personal_info.first_name),
+                },
+                SmtpTemplateData {
+                    key: "{{CONFIRM_LINK}}".to_string(),
+                    value: format!(
+                        "{}/redirect/onboarding/confirm_email/{}",
+                        conf.smtp.base_url, token
+                    ),
+                },
+                SmtpTemplateData {
+                    key: "{{CURRENT_YEAR}}".to_string(),
+                    value: chrono::Utc::now().year().to_string(),
+                },
+            ],
+        ),
+    )
+    .await;
+
+    HttpResponse::Ok().json(HttpResponseObjectEmpty {
+        error: false,
+        message: "A confirmation link was sent to the new email".to_string(),
+    })
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/change_email/confirm",
+    request_body = auth_model::ChangeEmailConfirmModel,
+    responses (
+        (status = 200, description = "Email changed successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid token", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error changing email", body = HttpResponseObjectEmptyError),
+    ),
+)]
+pub async fn confirm_email_change(
+    body: web::Json<auth_model::ChangeEmailConfirmModel>,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let email_change = match auth_model::EmailChangeModel::db_read_valid_by_token(conn, &body.token)
+    {
+        Ok(change) => change,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid token".to_string(),
+            });
+        }
+    };
+
+    let (user, personal_info) = match UserModel::db_read_by_id(conn, email_change.user_id)
+        .and_then(|usr| usr.db_read_personal_info(conn).map(|info| (usr, info)))
+    {
+        Ok(result) => result,
+        Err(e) => {
+            log::error!("Error reading user: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error changing email".to_string(),
+            });
+        }
+    };
+
+    // The address may have been taken while the link was waiting to be used.
+    if check_email_exist(conn, email_change.new_email.clone()).is_err() {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Email already in use".to_string(),
+        });
+    }
+
+    let updated = conn.transaction(|conn| {
+        user.db_update_email(conn, &email_change.new_email)?;
+        auth_model::EmailChangeModel::db_delete_by_id(conn, email_change.id)
+    });
+
+    if let Err(e) = updated {
+        log::error!("Error updating email: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Error changing email".to_string(),
+        });
+    }
+
+    queue_email(
+        &conf,
+        SmtpEmailPayload::from_template(
+            personal_info.email,
+            "E-mail Alterado - MyCondominium".to_string(),
+            SmtpTemplate::EmailChangedWarning,
+            vec![
+                SmtpTemplateData {
+                    key: "{{USER_NAME}}".to_string(),
+                    value: escape_html(&personal_info.first_name),
+                },
+                SmtpTemplateData {
+                    key: "{{NEW_EMAIL}}".to_string(),
+                    value: escape_html(&email_change.new_email),
+                },
+                SmtpTemplateData {
+                    key: "{{CURRENT_YEAR}}".to_string(),
+                    value: chrono::Utc::now().year().to_string(),
+                },
+            ],
+        ),
+    )
+    .await;
+
+    HttpResponse::Ok().json(HttpResponseObjectEmpty {
+        error: false,
+        message: "Email changed successfully".to_string(),
+    })
+}
+
+async fn queue_email(conf: &MyCondominiumConfig, email: SmtpEmailPayload) {
+    match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+        Ok(rmq) => {
+            if let Err(e) = email.publish(&rmq).await {
+                log::error!("Error queueing email: {}", e);
+            }
+        }
+        Err(e) => log::error!("Error connecting to RabbitMQ: {}", e),
+    }
+}
@@ -0,0 +1,198 @@
+use super::*;
+use api_key_model::ApiKeyModel;
+
+// Keys are never scoped for `/api/auth`, so only a signed-in user can manage them.
+
+#[utoipa::path(
+    get,
+    tag = "Authentication",
+    path = "/api_key/list",
+    responses (
+        (status = 200, description = "Got API keys successfully", body = ApiKeyListHttpResponse),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error getting API keys", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_api_keys(
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (_role, claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match ApiKeyModel::db_read_by_user(conn, claims.user_id) {
+        Ok(api_keys) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got API keys successfully".to_string(),
+            object: Some(
+                api_keys
+                    .iter()
+                    .map(|api_key| api_key.to_result())
+                    .collect::<Vec<_>>(),
+            ),
+        }),
+        Err(e) => {
+            log::error!("Error getting API keys: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error getting API keys".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/api_key/new",
+    request_body = api_key_model::ApiKeyNewModel,
+    responses (
+        (status = 201, description = "API key created successfully", body = ApiKeyNewHttpResponse),
+        (status = 400, description = "Invalid API key", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error creating API key", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_api_key(
+    body: web::Json<api_key_model::ApiKeyNewModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (_role, claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let scopes = match api_key_model::normalize_scopes(&body.scopes) {
+        Ok(scopes) => scopes,
+        Err(e) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: e.to_string(),
+            });
+        }
+    };
+
+    let (api_key, key) = ApiKeyModel::new(
+        conn,
+        claims.user_id,
+        body.name.trim().to_string(),
+        scopes,
+        body.expires_in_days,
+    );
+
+    match api_key.db_insert(conn) {
+        Ok(_) => HttpResponse::Created().json(HttpResponseObject {
+            error: false,
+            message: "API key created successfully".to_string(),
+            object: Some(api_key_model::ApiKeyNewResult {
+                key,
+                api_key: api_key.to_result(),
+            }),
+        }),
+        Err(e) => {
+            log::error!("Error creating API key: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error creating API key".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Authentication",
+    path = "/api_key/revoke/{id}",
+    params(
+        ("id" = Uuid, Path, description = "API key ID"),
+    ),
+    responses (
+        (status = 200, description = "API key revoked successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid API key ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 404, description = "API key not found", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error revoking API key", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn revoke_api_key(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid API key ID format".to_string(),
+            });
+        }
+    };
+
+    let (_role, claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    // Keys of other users are reported as missing rather than forbidden.
+    match ApiKeyModel::db_read_by_id(conn, id) {
+        Ok(api_key) if api_key.user_id == claims.user_id => (),
+        _ => {
+            return HttpResponse::NotFound().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "API key not found".to_string(),
+            });
+        }
+    }
+
+    match ApiKeyModel::db_delete_by_id(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "API key revoked successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error revoking API key: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error revoking API key".to_string(),
+            })
+        }
+    }
+}
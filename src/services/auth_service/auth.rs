@@ -21,7 +21,7 @@ use actix_web::{HttpRequest, HttpResponse, web};
 pub async fn auth(req: HttpRequest, conf: web::Data<Arc<MyCondominiumConfig>>) -> HttpResponse {
     let conn = &mut establish_connection_pg(&conf);
 
-    match authenticate_user(req, conn, conf) {
+    match authenticate_user(req, conn) {
         Ok((role, _claims, _token)) => {
             let user = match user_model::UserModel::db_read_by_id(conn, role.user_id) {
                 Ok(user) => user,
@@ -131,7 +131,7 @@ pub async fn auth(req: HttpRequest, conf: web::Data<Arc<MyCondominiumConfig>>) -
 pub async fn sign_out(req: HttpRequest, conf: web::Data<Arc<MyCondominiumConfig>>) -> HttpResponse {
     let conn = &mut establish_connection_pg(&conf);
 
-    let (_role, _claims, token) = match authenticate_user(req, conn, conf) {
+    let (_role, _claims, token) = match authenticate_user(req, conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -0,0 +1,17 @@
+use super::*;
+use crate::utilities::jwt_utils::JwtKeys;
+
+#[utoipa::path(
+    get,
+    tag = "Authentication",
+    path = "/jwks",
+    responses (
+        (status = 200, description = "JSON Web Key Set (RFC 7517) with the keys that verify access tokens", body = Object),
+    ),
+)]
+pub async fn jwks(keys: web::Data<JwtKeys>) -> HttpResponse {
+    // Served as a bare key set, which is what JWT libraries of other services expect.
+    HttpResponse::Ok()
+        .insert_header((header::CACHE_CONTROL, "public, max-age=300"))
+        .json(keys.jwks())
+}
@@ -0,0 +1,183 @@
+use super::*;
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/lockout/unlock_resident/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Resident ID"),
+    ),
+    responses (
+        (status = 200, description = "Resident account unlocked successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid Resident ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error unlocking account", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn unlock_resident(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Resident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if resident_model::ResidentModel::db_read_by_id_matching_community(role, conn, id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match sign_in_attempt_model::AccountLockoutModel::db_delete_by_resident(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Resident account unlocked successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error unlocking Resident account: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error unlocking account".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/lockout/unlock_admin/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Admin ID"),
+    ),
+    responses (
+        (status = 200, description = "Admin account unlocked successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid Admin ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error unlocking account", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn unlock_admin(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Admin ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let adm_user = match user_model::UserModel::table()
+        .filter(crate::schema::users::entity_id.eq(id))
+        .filter(crate::schema::users::entity_type.eq(UserTypes::Admin))
+        .first::<user_model::UserModel>(conn)
+    {
+        Ok(adm_user) => adm_user,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let adm_user_role = match user_role_model::UserRoleModel::table()
+        .filter(crate::schema::user_roles::user_id.eq(adm_user.id))
+        .first::<user_role_model::UserRoleModel>(conn)
+    {
+        Ok(adm_user_role) => adm_user_role,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    // Admins unlock the admins of their own community, only Root unlocks Root.
+    if role.role != UserRoles::Root
+        && !(adm_user_role.role == UserRoles::Admin
+            && adm_user_role.community_id.is_some()
+            && adm_user_role.community_id == role.community_id)
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match sign_in_attempt_model::AccountLockoutModel::db_delete_by_user(conn, adm_user.id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Admin account unlocked successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error unlocking Admin account: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error unlocking account".to_string(),
+            })
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use super::sign_in::start_session;
+use super::*;
+use chrono::Datelike;
+
+fn magic_link_disabled() -> HttpResponse {
+    HttpResponse::Forbidden().json(HttpResponseObjectEmptyError {
+        error: true,
+        message: "Magic link sign-in is disabled".to_string(),
+    })
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/signin/magic_link/request",
+    request_body = auth_model::MagicLinkRequestModel,
+    responses (
+        (status = 200, description = "Magic link requested successfully", body = HttpResponseObjectEmpty),
+        (status = 403, description = "Magic link sign-in is disabled", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error requesting magic link", body = HttpResponseObjectEmptyError),
+    ),
+)]
+pub async fn request_magic_link(
+    body: web::Json<auth_model::MagicLinkRequestModel>,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    if !conf.magic_link.enabled {
+        return magic_link_disabled();
+    }
+
+    let conn = &mut establish_connection_pg(&conf);
+    let email = body.email.trim().to_string();
+
+    // Unknown emails get the same answer, so the endpoint can't tell which accounts exist.
+    let user = match UserModel::find_user_by_email(conn, email.clone()) {
+        Ok(user) => user,
+        Err(_) => {
+            return HttpResponse::Ok().json(HttpResponseObjectEmpty {
+                error: false,
+                message: "If an account with the provided email exists, it will be sent"
+                    .to_string(),
+            });
+        }
+    };
+
+    let first_name = match (&user.admin, &user.resident) {
+        (Some(adm), _) => adm.first_name.clone(),
+        (None, Some(res)) => res.first_name.clone(),
+        (None, None) => String::new(),
+    };
+
+    let token = generate_token();
+    let magic_link = auth_model::MagicLinkModel::new(
+        conn,
+        user.user.id,
+        &token,
+        conf.magic_link.expiration_minutes as i64,
+    );
+
+    if let Err(e) = magic_link.db_replace(conn) {
+        log::error!("Error inserting magic link: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Error requesting magic link".to_string(),
+        });
+    }
+
+    let email = SmtpEmailPayload::from_template(
+        email,
+        "Entrar no MyCondominium".to_string(),
+        SmtpTemplate::MagicLink,
+        vec![
+            SmtpTemplateData {
+                key: "{{USER_NAME}}".to_string(),
+                value: escape_html(&first_name),
+            },
+            SmtpTemplateData {
+                key: "{{LOGIN_LINK}}".to_string(),
+                value: format!(
+                    "{}/redirect/onboarding/magic_link/{}",
+                    conf.smtp.base_url, token
+                ),
+            },
+            SmtpTemplateData {
+                key: "{{EXPIRATION_MINUTES}}".to_string(),
+                value: conf.magic_link.expiration_minutes.to_string(),
+            },
+            SmtpTemplateData {
+                key: "{{CURRENT_YEAR}}".to_string(),
+                value: chrono::Utc::now().year().to_string(),
+            },
+        ],
+    );
+
+    match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+        Ok(rmq) => {
+            if let Err(e) = email.publish(&rmq).await {
+                log::error!("Error queueing magic link email: {}", e);
+            }
+        }
+        Err(e) => log::error!("Error connecting to RabbitMQ: {}", e),
+    }
+
+    HttpResponse::Ok().json(HttpResponseObjectEmpty {
+        error: false,
+        message: "If an account with the provided email exists, it will be sent".to_string(),
+    })
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/signin/magic_link",
+    request_body = auth_model::MagicLinkSignInModel,
+    responses (
+        (status = 200, description = "Signed in successfully", body = AuthTokenPairHttpResponse),
+        (status = 202, description = "Second factor required", body = TwoFactorChallengeHttpResponse),
+        (status = 401, description = "Invalid or expired link", body = HttpResponseObjectEmptyError),
+        (status = 403, description = "Magic link sign-in is disabled", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error signing in", body = HttpResponseObjectEmptyError),
+    ),
+)]
+pub async fn sign_in_magic_link(
+    body: web::Json<auth_model::MagicLinkSignInModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    if !conf.magic_link.enabled {
+        return magic_link_disabled();
+    }
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    // Taking the link deletes it, so it can only be used once.
+    let magic_link = match auth_model::MagicLinkModel::db_take_valid(conn, &body.token) {
+        Ok(magic_link) => magic_link,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid or expired link".to_string(),
+            });
+        }
+    };
+
+    start_session(conn, &conf, &req, magic_link.user_id)
+}
@@ -1,27 +1,108 @@
+pub mod account;
+pub mod api_keys;
 pub mod auth;
+pub mod jwks;
+pub mod lockout;
+pub mod magic_link;
 pub mod new_admin_self_service;
+pub mod oidc;
 pub mod password_reset;
+pub mod refresh_token;
+pub mod service_accounts;
+pub mod sessions;
 pub mod sign_in;
+pub mod two_factor;
 
 use super::prelude::*;
+type AuthTokenPairHttpResponse = HttpResponseObject<auth_model::AuthTokenPairResult>;
+type AuthSessionListHttpResponse = HttpResponseObject<Vec<auth_token_model::AuthSessionResult>>;
+type TwoFactorChallengeHttpResponse =
+    HttpResponseObject<two_factor_model::TwoFactorChallengeResult>;
+type TwoFactorEnrolmentHttpResponse =
+    HttpResponseObject<two_factor_model::TwoFactorEnrolmentResult>;
+type TwoFactorRecoveryCodesHttpResponse =
+    HttpResponseObject<two_factor_model::TwoFactorRecoveryCodesResult>;
+type OidcProviderListHttpResponse = HttpResponseObject<Vec<oidc_model::OidcProviderResult>>;
+type OidcAuthorizationHttpResponse = HttpResponseObject<oidc_model::OidcAuthorizationResult>;
+type ApiKeyListHttpResponse = HttpResponseObject<Vec<api_key_model::ApiKeyResult>>;
+type ApiKeyNewHttpResponse = HttpResponseObject<api_key_model::ApiKeyNewResult>;
+type ServiceAccountListHttpResponse =
+    HttpResponseObject<Vec<service_account_model::ServiceAccountResult>>;
+type ServiceAccountNewHttpResponse =
+    HttpResponseObject<service_account_model::ServiceAccountNewResult>;
+type TwoFactorSignInHttpResponse = HttpResponseObject<two_factor_model::TwoFactorSignInResult>;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         sign_in::sign_in,
+        refresh_token::refresh_token,
+        magic_link::request_magic_link,
+        magic_link::sign_in_magic_link,
+        jwks::jwks,
+        oidc::get_oidc_providers,
+        oidc::authorize_oidc,
+        oidc::oidc_callback,
         auth::auth,
         auth::sign_out,
         new_admin_self_service::new_admin_self_service,
         password_reset::password_reset,
         password_reset::request_password_reset,
+        account::change_password,
+        account::request_email_change,
+        account::confirm_email_change,
+        two_factor::enroll_two_factor,
+        two_factor::confirm_two_factor,
+        two_factor::disable_two_factor,
+        two_factor::regenerate_recovery_codes,
+        two_factor::enroll_two_factor_challenge,
+        two_factor::sign_in_two_factor,
+        sessions::get_sessions,
+        sessions::revoke_session,
+        sessions::revoke_other_sessions,
+        sessions::revoke_resident_sessions,
+        lockout::unlock_resident,
+        lockout::unlock_admin,
+        api_keys::get_api_keys,
+        api_keys::new_api_key,
+        api_keys::revoke_api_key,
+        service_accounts::get_service_accounts,
+        service_accounts::new_service_account,
+        service_accounts::new_service_account_key,
+        service_accounts::revoke_service_account_key,
+        service_accounts::delete_service_account,
     ),
     components(schemas(
         auth_model::AuthModel,
+        auth_model::AuthTokenPairResult,
+        auth_model::RefreshTokenRequestModel,
+        auth_model::MagicLinkRequestModel,
+        auth_model::MagicLinkSignInModel,
         auth_model::AuthAdminNewSelfServiceModel,
         auth_model::AuthUserModelResult,
         auth_model::PasswordResetModel,
         auth_model::PasswordResetChangeModel,
-        auth_model::PasswordResetRequestModel
+        auth_model::PasswordResetRequestModel,
+        auth_model::ChangePasswordModel,
+        auth_model::ChangeEmailRequestModel,
+        auth_model::ChangeEmailConfirmModel,
+        oidc_model::OidcProviderResult,
+        oidc_model::OidcAuthorizationResult,
+        oidc_model::OidcCallbackModel,
+        two_factor_model::TwoFactorEnrolmentResult,
+        two_factor_model::TwoFactorCodeModel,
+        two_factor_model::TwoFactorChallengeModel,
+        two_factor_model::TwoFactorSignInModel,
+        two_factor_model::TwoFactorChallengeResult,
+        two_factor_model::TwoFactorSignInResult,
+        two_factor_model::TwoFactorRecoveryCodesResult,
+        auth_token_model::AuthSessionResult,
+        api_key_model::ApiKeyNewModel,
+        api_key_model::ApiKeyResult,
+        api_key_model::ApiKeyNewResult,
+        service_account_model::ServiceAccountNewModel,
+        service_account_model::ServiceAccountResult,
+        service_account_model::ServiceAccountNewResult
     ))
 )]
 pub struct AuthApi;
@@ -1,7 +1,7 @@
 use super::*;
 use crate::internal::rabbitmq::rabbitmq_client::RabbitMqClient;
 use crate::internal::smtp::smtp_client::SmtpEmailPayload;
-use crate::internal::smtp::smtp_templates::{SmtpTemplate, SmtpTemplateData};
+use crate::internal::smtp::smtp_templates::{SmtpTemplate, SmtpTemplateData, escape_html};
 use crate::utilities::user_utils::check_email_exist;
 use chrono::Datelike;
 use std::time::Duration;
@@ -43,6 +43,21 @@ pub async fn new_admin_self_service(
         }
     }
 
+    if let Err(e) = check_password_policy(
+        &body.admin.password,
+        &conf.password_policy,
+        &[
+            &body.admin.first_name,
+            &body.admin.last_name,
+            &body.admin.email,
+        ],
+    ) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: e.to_string(),
+        });
+    }
+
     let new_obj_community = community_model::CommunityModel {
         id: community_model::CommunityModel::new_id(conn),
         name: body.community.name,
@@ -50,6 +65,7 @@ pub async fn new_admin_self_service(
         address: body.community.address,
         created_at: chrono::Utc::now().naive_utc(),
         updated_at: chrono::Utc::now().naive_utc(),
+        require_admin_2fa: body.community.require_admin_2fa,
     };
 
     match new_obj_community.db_insert(conn) {
@@ -104,6 +120,7 @@ pub async fn new_admin_self_service(
         password: hashed_password,
         created_at: chrono::Utc::now().naive_utc(),
         updated_at: chrono::Utc::now().naive_utc(),
+        service_account_id: None,
     };
 
     match new_obj_user.db_insert(conn) {
@@ -146,7 +163,7 @@ pub async fn new_admin_self_service(
     let parameters: Vec<SmtpTemplateData> = vec![
         SmtpTemplateData {
             key: "{{USER_NAME}}".to_string(),
-            value: new_obj_admin.first_name,
+            value: escape_html(&new_obj_admin.first_name),
         },
         SmtpTemplateData {
             key: "{{APP_LINK}}".to_string(),
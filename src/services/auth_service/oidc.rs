@@ -0,0 +1,313 @@
+use super::sign_in::start_session;
+use super::*;
+use crate::utilities::oidc_utils;
+use actix_web::cookie::{Cookie, SameSite};
+use oidc_model::{OIDC_STATE_EXPIRATION_MINUTES, OidcStateModel, UserIdentityModel};
+
+/// Cookie holding the `state` of the login the browser started. The callback must come with it,
+/// so a login started elsewhere can't be completed in this browser.
+const OIDC_STATE_COOKIE: &str = "mycondominium_oidc_state";
+
+fn state_cookie(state: &str) -> Cookie<'static> {
+    Cookie::build(OIDC_STATE_COOKIE, state.to_string())
+        .path("/api/auth/oidc")
+        .http_only(true)
+        .secure(true)
+        .same_site(SameSite::Lax)
+        .max_age(actix_web::cookie::time::Duration::minutes(
+            OIDC_STATE_EXPIRATION_MINUTES,
+        ))
+        .finish()
+}
+
+fn find_provider<'a>(
+    conf: &'a MyCondominiumConfig,
+    name: &str,
+) -> Result<&'a ConfigOidcProvider, HttpResponse> {
+    conf.oidc
+        .iter()
+        .find(|provider| provider.name == name)
+        .ok_or_else(|| {
+            HttpResponse::NotFound().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Provider not found".to_string(),
+            })
+        })
+}
+
+#[utoipa::path(
+    get,
+    tag = "Authentication",
+    path = "/oidc/providers",
+    responses (
+        (status = 200, description = "Got providers successfully", body = OidcProviderListHttpResponse),
+    ),
+)]
+pub async fn get_oidc_providers(conf: web::Data<Arc<MyCondominiumConfig>>) -> HttpResponse {
+    HttpResponse::Ok().json(HttpResponseObject {
+        error: false,
+        message: "Got providers successfully".to_string(),
+        object: Some(
+            conf.oidc
+                .iter()
+                .map(|provider| oidc_model::OidcProviderResult {
+                    name: provider.name.clone(),
+                })
+                .collect::<Vec<_>>(),
+        ),
+    })
+}
+
+#[utoipa::path(
+    get,
+    tag = "Authentication",
+    path = "/oidc/{provider}/authorize",
+    params(
+        ("provider" = String, Path, description = "Provider name"),
+    ),
+    responses (
+        (status = 200, description = "Authorization started successfully, the state is also set in an HttpOnly cookie the callback requires", body = OidcAuthorizationHttpResponse),
+        (status = 404, description = "Provider not found", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error starting authorization", body = HttpResponseObjectEmptyError),
+    ),
+)]
+pub async fn authorize_oidc(
+    provider: web::Path<String>,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let provider = match find_provider(&conf, &provider) {
+        Ok(provider) => provider,
+        Err(response) => return response,
+    };
+
+    let metadata = match oidc_utils::discover(&provider.issuer).await {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            log::error!("Error discovering OIDC provider {}: {}", provider.name, e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error starting authorization".to_string(),
+            });
+        }
+    };
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    let state = generate_token();
+    let login = OidcStateModel::new(
+        conn,
+        provider.name.clone(),
+        &state,
+        generate_token(),
+        generate_token(),
+    );
+
+    let authorization_url = match oidc_utils::authorization_url(
+        &metadata,
+        provider,
+        &state,
+        &login.nonce,
+        &login.code_verifier,
+    ) {
+        Ok(url) => url,
+        Err(e) => {
+            log::error!("Error building OIDC authorization URL: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error starting authorization".to_string(),
+            });
+        }
+    };
+
+    match login.db_insert(conn) {
+        Ok(_) => HttpResponse::Ok()
+            .cookie(state_cookie(&state))
+            .json(HttpResponseObject {
+                error: false,
+                message: "Authorization started successfully".to_string(),
+                object: Some(oidc_model::OidcAuthorizationResult {
+                    authorization_url,
+                    expires_at: login.expires_at,
+                }),
+            }),
+        Err(e) => {
+            log::error!("Error inserting OIDC state: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error starting authorization".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/oidc/{provider}/callback",
+    params(
+        ("provider" = String, Path, description = "Provider name"),
+    ),
+    request_body = oidc_model::OidcCallbackModel,
+    responses (
+        (status = 200, description = "Signed in successfully", body = AuthTokenPairHttpResponse),
+        (status = 202, description = "Second factor required", body = TwoFactorChallengeHttpResponse),
+        (status = 400, description = "Invalid state, or the state cookie is missing", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 404, description = "Provider not found", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error signing in", body = HttpResponseObjectEmptyError),
+    ),
+)]
+pub async fn oidc_callback(
+    provider: web::Path<String>,
+    body: web::Json<oidc_model::OidcCallbackModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let provider = match find_provider(&conf, &provider) {
+        Ok(provider) => provider,
+        Err(response) => return response,
+    };
+
+    if req
+        .cookie(OIDC_STATE_COOKIE)
+        .is_none_or(|cookie| cookie.value() != body.state)
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid state".to_string(),
+        });
+    }
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    // The state is spent right away, so a callback can't be replayed.
+    let login = match OidcStateModel::db_take_valid(conn, &provider.name, &body.state) {
+        Ok(login) => login,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid state".to_string(),
+            });
+        }
+    };
+
+    let metadata = match oidc_utils::discover(&provider.issuer).await {
+        Ok(metadata) => metadata,
+        Err(e) => {
+            log::error!("Error discovering OIDC provider {}: {}", provider.name, e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error signing in".to_string(),
+            });
+        }
+    };
+
+    let id_token = match oidc_utils::exchange_code(
+        &metadata,
+        provider,
+        &body.code,
+        &login.code_verifier,
+    )
+    .await
+    {
+        Ok(id_token) => id_token,
+        Err(e) => {
+            log::warn!("Rejected sign-in with {}: {}", provider.name, e);
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let jwks = match oidc_utils::signing_keys(&metadata, &id_token).await {
+        Ok(jwks) => jwks,
+        Err(e) => {
+            log::error!("Error fetching keys of {}: {}", provider.name, e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error signing in".to_string(),
+            });
+        }
+    };
+
+    let claims = match oidc_utils::validate_id_token(
+        &id_token,
+        &jwks,
+        &metadata.issuer,
+        &provider.client_id,
+        &login.nonce,
+    ) {
+        Ok(claims) => claims,
+        Err(e) => {
+            log::warn!("Rejected sign-in with {}: {}", provider.name, e);
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let user_id = match UserIdentityModel::db_read_by_subject(conn, &provider.name, &claims.sub) {
+        Ok(Some(identity)) => {
+            if let Err(e) = identity.db_touch(conn) {
+                log::error!("Error updating identity last use: {}", e);
+            }
+            identity.user_id
+        }
+        Ok(None) => {
+            // Unverified addresses could be claimed by anyone, so they never link accounts.
+            let email = match claims.email.filter(|_| claims.email_verified) {
+                Some(email) => email.trim().to_string(),
+                None => {
+                    return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                        error: true,
+                        message: "The provider did not confirm a verified email".to_string(),
+                    });
+                }
+            };
+
+            let user = match UserModel::find_user_by_email(conn, email.clone()) {
+                Ok(user) => user,
+                Err(_) => {
+                    return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                        error: true,
+                        message: "No account uses this email".to_string(),
+                    });
+                }
+            };
+
+            let identity = UserIdentityModel::new(
+                conn,
+                user.user.id,
+                provider.name.clone(),
+                claims.sub,
+                email,
+            );
+            if let Err(e) = identity.db_insert(conn) {
+                log::error!("Error linking identity: {}", e);
+                return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Error signing in".to_string(),
+                });
+            }
+
+            log::info!("Linked {} identity to user {}", provider.name, user.user.id);
+            user.user.id
+        }
+        Err(e) => {
+            log::error!("Error reading identity: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error signing in".to_string(),
+            });
+        }
+    };
+
+    let mut response = start_session(conn, &conf, &req, user_id);
+    if let Err(e) = response.add_removal_cookie(&state_cookie("")) {
+        log::error!("Error clearing OIDC state cookie: {}", e);
+    }
+
+    response
+}
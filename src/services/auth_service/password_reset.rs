@@ -25,15 +25,12 @@ pub async fn request_password_reset(
     let user = UserModel::find_user_by_email(conn, email.clone()).ok();
 
     if user.is_some() {
-        let pw_id = auth_model::PasswordResetModel::new_id(conn);
-
-        let password_reset = auth_model::PasswordResetModel {
-            id: pw_id,
-            email: email.clone(),
-            user_id: user.clone().unwrap().user.id,
-            token: format!("{}{}", pw_id, Uuid::new_v4().to_string()),
-            created_at: chrono::Utc::now().naive_utc(),
-        };
+        let password_reset = auth_model::PasswordResetModel::new(
+            conn,
+            user.clone().unwrap().user.id,
+            email.clone(),
+            chrono::Duration::minutes(auth_model::PASSWORD_RESET_EXPIRATION_MINUTES),
+        );
 
         let rmq = RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string())
             .await
@@ -42,19 +39,18 @@ pub async fn request_password_reset(
         let first_name = match user.clone().unwrap().user.entity_type {
             UserTypes::Admin => user.unwrap().admin.unwrap().first_name,
             UserTypes::Resident => user.unwrap().resident.unwrap().first_name,
+            // Service accounts have no email, so no email finds them.
+            UserTypes::Service => String::new(),
         };
 
         let parameters: Vec<SmtpTemplateData> = vec![
             SmtpTemplateData {
                 key: "{{USER_NAME}}".to_string(),
-                value: first_name,
+                value: escape_html(&first_name),
             },
             SmtpTemplateData {
                 key: "{{RESET_LINK}}".to_string(),
-                value: format!(
-                    "{}/redirect/onboarding/password_reset/{}",
-                    conf.smtp.base_url, password_reset.token
-                ),
+                value: password_reset.link(&conf.smtp.base_url),
             },
             SmtpTemplateData {
                 key: "{{CURRENT_YEAR}}".to_string(),
@@ -106,7 +102,7 @@ pub async fn request_password_reset(
     request_body = auth_model::PasswordResetChangeModel,
     responses (
         (status = 200, description = "Password Reset successfully", body = HttpResponseObjectEmpty),
-        (status = 400, description = "Invalid token", body = HttpResponseObjectEmptyError),
+        (status = 400, description = "Invalid token or password", body = HttpResponseObjectEmptyError),
         (status = 500, description = "Error resetting password", body = HttpResponseObjectEmptyError),
     ),
 )]
@@ -120,16 +116,17 @@ pub async fn password_reset(
 
     let pw_reset = auth_model::PasswordResetModel::table()
         .filter(crate::schema::password_reset::token.eq(body.clone().token))
+        .filter(crate::schema::password_reset::expires_at.gt(chrono::Utc::now().naive_utc()))
         .first::<auth_model::PasswordResetModel>(conn)
         .ok();
 
     let body = body.clone();
 
     if pw_reset.is_some() {
-        let hashed_password = match hash_password(body.password) {
-            Ok(passwd) => passwd,
+        let mut user = match UserModel::db_read_by_id(conn, pw_reset.clone().unwrap().user_id) {
+            Ok(usr) => usr,
             Err(e) => {
-                log::error!("Error hashing password: {}", e);
+                log::error!("Error reading user: {}", e);
                 return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
                     error: true,
                     message: "Error resetting password".to_string(),
@@ -137,9 +134,12 @@ pub async fn password_reset(
             }
         };
 
-        let mut user = match UserModel::db_read_by_id(conn, pw_reset.clone().unwrap().user_id) {
-            Ok(usr) => usr,
-            Err(e) => {
+        let (personal_info, previous_passwords) = match (
+            user.db_read_personal_info(conn),
+            user.db_read_password_history(conn, conf.password_policy.history_count),
+        ) {
+            (Ok(info), Ok(history)) => (info, history),
+            (Err(e), _) | (_, Err(e)) => {
                 log::error!("Error reading user: {}", e);
                 return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
                     error: true,
@@ -148,10 +148,31 @@ pub async fn password_reset(
             }
         };
 
-        user.password = hashed_password;
-        user.updated_at = chrono::Utc::now().naive_utc();
+        if let Err(e) = check_password_policy(
+            &body.password,
+            &conf.password_policy,
+            &personal_info.words(),
+        )
+        .and_then(|_| check_password_reuse(&body.password, &previous_passwords))
+        {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: e.to_string(),
+            });
+        }
 
-        match user.db_update(conn) {
+        let hashed_password = match hash_password(body.password) {
+            Ok(passwd) => passwd,
+            Err(e) => {
+                log::error!("Error hashing password: {}", e);
+                return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Error resetting password".to_string(),
+                });
+            }
+        };
+
+        match user.db_update_password(conn, hashed_password, conf.password_policy.history_count) {
             Ok(_) => {}
             Err(e) => {
                 log::error!("Error updating user: {}", e);
@@ -195,12 +216,18 @@ pub async fn password_reset(
                     }
                 }
             }
+            UserTypes::Service => {
+                return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Error getting user".to_string(),
+                });
+            }
         };
 
         let parameters: Vec<SmtpTemplateData> = vec![
             SmtpTemplateData {
                 key: "{{USER_NAME}}".to_string(),
-                value: first_name,
+                value: escape_html(&first_name),
             },
             SmtpTemplateData {
                 key: "{{CURRENT_YEAR}}".to_string(),
@@ -0,0 +1,99 @@
+use super::sign_in::issue_token_pair;
+use super::*;
+use crate::utilities::jwt_utils::JwtKeys;
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/refresh",
+    request_body = auth_model::RefreshTokenRequestModel,
+    responses (
+        (status = 200, description = "Tokens refreshed successfully", body = AuthTokenPairHttpResponse),
+        (status = 401, description = "Invalid refresh token", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error refreshing tokens", body = HttpResponseObjectEmptyError),
+    ),
+)]
+pub async fn refresh_token(
+    body: web::Json<auth_model::RefreshTokenRequestModel>,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+    keys: web::Data<JwtKeys>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let refresh =
+        match auth_token_model::RefreshTokenModel::db_read_by_token(conn, &body.refresh_token) {
+            Ok(refresh) => refresh,
+            Err(_) => {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Invalid refresh token".to_string(),
+                });
+            }
+        };
+
+    if refresh.is_expired(chrono::Utc::now().naive_utc()) {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid refresh token".to_string(),
+        });
+    }
+
+    let mut session =
+        match auth_token_model::AuthTokenModel::db_read_by_id(conn, refresh.auth_token_id) {
+            Ok(session) if session.active => session,
+            _ => {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Invalid refresh token".to_string(),
+                });
+            }
+        };
+
+    // A refresh token presented twice was copied by someone else, so the session is revoked
+    // for both parties.
+    match refresh.db_mark_used(conn) {
+        Ok(true) => (),
+        Ok(false) => {
+            log::warn!(
+                "Refresh token reuse detected, revoking session {} of user {}",
+                session.id,
+                session.user_id
+            );
+
+            if let Err(e) = auth_token_model::AuthTokenModel::db_delete_by_id(conn, session.id) {
+                log::error!("Error revoking session {}: {}", session.id, e);
+            }
+
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid refresh token".to_string(),
+            });
+        }
+        Err(e) => {
+            log::error!("Error using refresh token: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error refreshing tokens".to_string(),
+            });
+        }
+    }
+
+    if let Err(e) = session.db_touch(conn) {
+        log::error!("Error updating token last use: {}", e);
+    }
+
+    match issue_token_pair(conn, &conf, &keys, &session) {
+        Ok(tokens) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Tokens refreshed successfully".to_string(),
+            object: Some(tokens),
+        }),
+        Err(e) => {
+            log::error!("Error issuing tokens: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error refreshing tokens".to_string(),
+            })
+        }
+    }
+}
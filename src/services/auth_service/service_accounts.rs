@@ -0,0 +1,428 @@
+use super::*;
+use api_key_model::ApiKeyModel;
+use service_account_model::ServiceAccountModel;
+
+/// Normalises the scopes of a new key, answering 400 when one isn't known.
+fn service_key_scopes(body: &api_key_model::ApiKeyNewModel) -> Result<String, HttpResponse> {
+    api_key_model::normalize_scopes(&body.scopes).map_err(|e| {
+        HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: e.to_string(),
+        })
+    })
+}
+
+#[utoipa::path(
+    get,
+    tag = "Authentication",
+    path = "/service_account/list",
+    responses (
+        (status = 200, description = "Got service accounts successfully", body = ServiceAccountListHttpResponse),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error getting service accounts", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_service_accounts(
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token))
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin =>
+        {
+            (role, claims, token)
+        }
+        _ => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let accounts = ServiceAccountModel::db_read_all_matching(role, conn).and_then(|accounts| {
+        accounts
+            .iter()
+            .map(|account| account.db_to_result(conn))
+            .collect::<QueryResult<Vec<_>>>()
+    });
+
+    match accounts {
+        Ok(accounts) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got service accounts successfully".to_string(),
+            object: Some(accounts),
+        }),
+        Err(e) => {
+            log::error!("Error getting service accounts: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error getting service accounts".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/service_account/new",
+    request_body = service_account_model::ServiceAccountNewModel,
+    responses (
+        (status = 201, description = "Service account created successfully", body = ServiceAccountNewHttpResponse),
+        (status = 400, description = "Invalid service account", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error creating service account", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_service_account(
+    body: web::Json<service_account_model::ServiceAccountNewModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if !(role.role == UserRoles::Root
+        || (role.role == UserRoles::Admin && role.community_id == Some(body.community_id)))
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let scopes = match service_key_scopes(&body.api_key) {
+        Ok(scopes) => scopes,
+        Err(response) => return response,
+    };
+
+    // Nobody ever learns the password, so the account can't be signed in to.
+    let hashed_password = match hash_password(generate_token()) {
+        Ok(hashed_password) => hashed_password,
+        Err(e) => {
+            log::error!("Error hashing service account password: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error creating service account".to_string(),
+            });
+        }
+    };
+
+    let account = ServiceAccountModel {
+        id: ServiceAccountModel::new_id(conn),
+        community_id: body.community_id,
+        name: body.name.trim().to_string(),
+        created_at: chrono::Utc::now().naive_utc(),
+    };
+
+    let result = conn.transaction(|conn| {
+        let user = account.db_create(conn, hashed_password)?;
+
+        let (api_key, key) = ApiKeyModel::new(
+            conn,
+            user.id,
+            body.api_key.name.trim().to_string(),
+            scopes,
+            body.api_key.expires_in_days,
+        );
+        api_key.db_insert(conn)?;
+
+        Ok::<_, diesel::result::Error>((api_key, key))
+    });
+
+    match result {
+        Ok((api_key, key)) => HttpResponse::Created().json(HttpResponseObject {
+            error: false,
+            message: "Service account created successfully".to_string(),
+            object: Some(service_account_model::ServiceAccountNewResult {
+                id: account.id,
+                api_key: api_key_model::ApiKeyNewResult {
+                    key,
+                    api_key: api_key.to_result(),
+                },
+            }),
+        }),
+        Err(e) => {
+            log::error!("Error creating service account: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error creating service account".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/service_account/api_key/new/{id}",
+    request_body = api_key_model::ApiKeyNewModel,
+    params(
+        ("id" = Uuid, Path, description = "Service account ID"),
+    ),
+    responses (
+        (status = 201, description = "API key created successfully", body = ApiKeyNewHttpResponse),
+        (status = 400, description = "Invalid API key", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 404, description = "Service account not found", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error creating API key", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_service_account_key(
+    id: web::Path<String>,
+    body: web::Json<api_key_model::ApiKeyNewModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid service account ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token))
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin =>
+        {
+            (role, claims, token)
+        }
+        _ => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let user = match ServiceAccountModel::db_read_by_id_matching_community(role, conn, id)
+        .and_then(|account| account.db_read_user(conn))
+    {
+        Ok(user) => user,
+        Err(_) => {
+            return HttpResponse::NotFound().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Service account not found".to_string(),
+            });
+        }
+    };
+
+    let scopes = match service_key_scopes(&body) {
+        Ok(scopes) => scopes,
+        Err(response) => return response,
+    };
+
+    let (api_key, key) = ApiKeyModel::new(
+        conn,
+        user.id,
+        body.name.trim().to_string(),
+        scopes,
+        body.expires_in_days,
+    );
+
+    match api_key.db_insert(conn) {
+        Ok(_) => HttpResponse::Created().json(HttpResponseObject {
+            error: false,
+            message: "API key created successfully".to_string(),
+            object: Some(api_key_model::ApiKeyNewResult {
+                key,
+                api_key: api_key.to_result(),
+            }),
+        }),
+        Err(e) => {
+            log::error!("Error creating API key: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error creating API key".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Authentication",
+    path = "/service_account/api_key/revoke/{id}",
+    params(
+        ("id" = Uuid, Path, description = "API key ID"),
+    ),
+    responses (
+        (status = 200, description = "API key revoked successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid API key ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 404, description = "API key not found", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error revoking API key", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn revoke_service_account_key(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid API key ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token))
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin =>
+        {
+            (role, claims, token)
+        }
+        _ => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    // Only keys of service accounts the caller manages, others are reported as missing.
+    let found = ApiKeyModel::db_read_by_id(conn, id)
+        .and_then(|api_key| UserModel::db_read_by_id(conn, api_key.user_id))
+        .and_then(|user| match user.service_account_id {
+            Some(account_id) => {
+                ServiceAccountModel::db_read_by_id_matching_community(role, conn, account_id)
+            }
+            None => Err(diesel::result::Error::NotFound),
+        });
+    if found.is_err() {
+        return HttpResponse::NotFound().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "API key not found".to_string(),
+        });
+    }
+
+    match ApiKeyModel::db_delete_by_id(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "API key revoked successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error revoking API key: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error revoking API key".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Authentication",
+    path = "/service_account/delete/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Service account ID"),
+    ),
+    responses (
+        (status = 200, description = "Service account deleted successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid service account ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 404, description = "Service account not found", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error deleting service account", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn delete_service_account(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid service account ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token))
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin =>
+        {
+            (role, claims, token)
+        }
+        _ => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if ServiceAccountModel::db_read_by_id_matching_community(role, conn, id).is_err() {
+        return HttpResponse::NotFound().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Service account not found".to_string(),
+        });
+    }
+
+    // Its user and keys go with it.
+    match ServiceAccountModel::db_delete_by_id(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Service account deleted successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error deleting service account: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error deleting service account".to_string(),
+            })
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "Authentication",
+    path = "/session/list",
+    responses (
+        (status = 200, description = "Got sessions successfully", body = AuthSessionListHttpResponse),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error getting sessions", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_sessions(
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (_role, claims, token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match auth_token_model::AuthTokenModel::db_read_active_by_user(conn, claims.user_id) {
+        Ok(sessions) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got sessions successfully".to_string(),
+            object: Some(
+                sessions
+                    .iter()
+                    .map(|session| session.to_session_result(token.id))
+                    .collect::<Vec<_>>(),
+            ),
+        }),
+        Err(e) => {
+            log::error!("Error getting sessions: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error getting sessions".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Authentication",
+    path = "/session/revoke/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Session ID"),
+    ),
+    responses (
+        (status = 200, description = "Session revoked successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid Session ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 404, description = "Session not found", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error revoking session", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn revoke_session(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Session ID format".to_string(),
+            });
+        }
+    };
+
+    let (_role, claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    // Sessions of other users are reported as missing rather than forbidden.
+    match auth_token_model::AuthTokenModel::db_read_by_id(conn, id) {
+        Ok(session) if session.user_id == claims.user_id => (),
+        _ => {
+            return HttpResponse::NotFound().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Session not found".to_string(),
+            });
+        }
+    }
+
+    match auth_token_model::AuthTokenModel::db_delete_by_id(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Session revoked successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error revoking session: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error revoking session".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Authentication",
+    path = "/session/revoke_others",
+    responses (
+        (status = 200, description = "Other sessions revoked successfully", body = HttpResponseObjectEmpty),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error revoking sessions", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn revoke_other_sessions(
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (_role, claims, token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match auth_token_model::AuthTokenModel::db_delete_by_user(conn, claims.user_id, Some(token.id))
+    {
+        Ok(count) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: format!("Revoked {} other sessions", count),
+        }),
+        Err(e) => {
+            log::error!("Error revoking sessions: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error revoking sessions".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Authentication",
+    path = "/session/revoke_resident/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Resident ID"),
+    ),
+    responses (
+        (status = 200, description = "Resident sessions revoked successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid Resident ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error revoking sessions", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn revoke_resident_sessions(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Resident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if resident_model::ResidentModel::db_read_by_id_matching_community(role, conn, id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match auth_token_model::AuthTokenModel::db_delete_by_resident(conn, id) {
+        Ok(count) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: format!("Revoked {} sessions of the Resident", count),
+        }),
+        Err(e) => {
+            log::error!("Error revoking Resident sessions: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error revoking sessions".to_string(),
+            })
+        }
+    }
+}
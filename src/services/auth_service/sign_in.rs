@@ -1,16 +1,30 @@
 use super::*;
-use crate::utilities::auth_utils::{check_password, parse_user_agent};
-use std::time::Duration;
-use tokio::time::sleep;
+use crate::utilities::auth_utils::{
+    check_password, client_ip, generate_access_token, generate_token, parse_user_agent,
+};
+use crate::utilities::jwt_utils::JwtKeys;
+use chrono::Datelike;
+use diesel::Connection;
+use sign_in_attempt_model::{AccountLockoutModel, SignInAttemptModel};
 
+/// Argon2 hash checked against when there's no account to check, so an unknown email takes as long
+/// to answer as a wrong password.
+const UNKNOWN_USER_PASSWORD_HASH: &str = "$argon2id$v=19$m=19456,t=2,p=1$LTw9lap27D4Y0b+G2EGWfQ$JAvHYTII9zhbLkZg/f6w6oAYQASjLoXAMA1krNGgJY8";
+
+/// Signs in with email and password.
+///
+/// Returns an access and refresh token pair. **Breaking change:** `object` used to be the bare
+/// access token, which is now `object.access_token`.
 #[utoipa::path(
     post,
     tag = "Authentication",
     path = "/signin",
     request_body = auth_model::AuthModel,
     responses (
-        (status = 200, description = "Signed in successfully", body = HttpResponseObject<String>),
-        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 200, description = "Signed in successfully", body = AuthTokenPairHttpResponse),
+        (status = 202, description = "Second factor required", body = TwoFactorChallengeHttpResponse),
+        (status = 401, description = "Invalid email or password", body = HttpResponseObjectEmptyError),
+        (status = 429, description = "Too many failed sign-in attempts from this IP", body = HttpResponseObjectEmptyError),
         (status = 500, description = "Error signing in", body = HttpResponseObjectEmptyError),
     ),
 )]
@@ -22,13 +36,49 @@ pub async fn sign_in(
     let conn = &mut establish_connection_pg(&conf);
     let email = body.email.trim().to_string();
     let password = body.password.trim().to_string();
+    let ip_address = client_ip(&req, conf.lockout.trust_forwarded_for);
+    let now = chrono::Utc::now().naive_utc();
 
-    sleep(Duration::from_secs(3)).await;
+    // Failures are counted per IP as well, so guessing across many accounts is throttled too.
+    // The attempt counts as failed until the password is proven right.
+    let ip_window = chrono::Duration::minutes(conf.lockout.ip_window_minutes as i64);
+    let mut attempt = SignInAttemptModel::new(conn, email.clone(), ip_address.clone(), None, false);
+    match attempt.db_reserve(
+        conn,
+        now - ip_window,
+        conf.lockout.ip_max_failed_attempts as i64,
+    ) {
+        Ok(true) => (),
+        Ok(false) => return too_many_attempts(ip_window.num_seconds()),
+        Err(e) => {
+            log::error!("Error recording sign-in attempt: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Error signing in".to_string(),
+            });
+        }
+    }
 
-    let user = match UserModel::find_user_by_email(conn, email) {
+    // Unknown emails, wrong passwords and locked accounts get the same answer, so the response
+    // doesn't tell which emails have an account. The password is hashed either way for the same
+    // reason.
+    let user = match UserModel::find_user_by_email(conn, email.clone()) {
         Ok(usr) => usr,
+        Err(_) => {
+            let _ = check_password(password, UNKNOWN_USER_PASSWORD_HASH.to_string());
+            return invalid_credentials();
+        }
+    };
+
+    attempt.user_id = Some(user.user.id);
+    if let Err(e) = attempt.db_update(conn) {
+        log::error!("Error updating sign-in attempt: {}", e);
+    }
+
+    let lockout = match AccountLockoutModel::db_read_by_user(conn, user.user.id) {
+        Ok(lockout) => lockout,
         Err(e) => {
-            log::error!("Error getting user: {}", e);
+            log::error!("Error reading account lockout: {}", e);
             return HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
                 error: true,
                 message: "Error signing in".to_string(),
@@ -36,14 +86,20 @@ pub async fn sign_in(
         }
     };
 
-    match check_password(password, user.user.password) {
-        Ok(val) => {
-            if !val {
-                return HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
-                    error: true,
-                    message: "Error signing in".to_string(),
-                });
-            }
+    // The password isn't checked while the account is locked, so it can't be guessed meanwhile.
+    if lockout
+        .as_ref()
+        .is_some_and(|lockout| lockout.is_locked(now))
+    {
+        let _ = check_password(password, UNKNOWN_USER_PASSWORD_HASH.to_string());
+        return invalid_credentials();
+    }
+
+    match check_password(password, user.user.password.clone()) {
+        Ok(true) => (),
+        Ok(false) => {
+            register_sign_in_failure(conn, &conf, user.user.id, &ip_address, now).await;
+            return invalid_credentials();
         }
         Err(e) => {
             log::error!("Error checking password: {}", e);
@@ -54,17 +110,35 @@ pub async fn sign_in(
         }
     };
 
-    let token_id = auth_token_model::AuthTokenModel::new_id(conn);
+    attempt.successful = true;
+    if let Err(e) = attempt.db_update(conn) {
+        log::error!("Error updating sign-in attempt: {}", e);
+    }
 
-    let token = match generate_jwt_token_no_env(
-        user.user.id,
-        token_id,
-        conf.auth.token_secret_key.clone(),
-        conf.auth.token_expiration_days as i64,
+    // The failed attempts are only cleared once the session is created, as wrong second factors
+    // count towards the lockout too.
+    start_session(conn, &conf, &req, user.user.id)
+}
+
+/// Signs the user in once their identity is proven: asks for the second factor when the user has
+/// one or must enrol one, otherwise opens the session.
+pub(super) fn start_session(
+    conn: &mut PgConnection,
+    conf: &MyCondominiumConfig,
+    req: &HttpRequest,
+    user_id: Uuid,
+) -> HttpResponse {
+    if let Err(response) = check_user_active(conn, user_id) {
+        return response;
+    }
+
+    let (totp_enabled, totp_required) = match (
+        two_factor_model::UserTotpModel::db_read_by_user(conn, user_id),
+        two_factor_model::UserTotpModel::db_is_required(conn, user_id),
     ) {
-        Ok(new_token) => new_token,
-        Err(e) => {
-            log::error!("Error generating JWT token: {}", e);
+        (Ok(totp), Ok(required)) => (totp.is_some_and(|t| t.is_enabled()), required),
+        (Err(e), _) | (_, Err(e)) => {
+            log::error!("Error checking second factor: {}", e);
             return HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
                 error: true,
                 message: "Error signing in".to_string(),
@@ -72,29 +146,211 @@ pub async fn sign_in(
         }
     };
 
+    // The session is only created once the second factor is checked against the challenge.
+    if totp_enabled || totp_required {
+        let challenge_token = generate_token();
+        let challenge = two_factor_model::AuthChallengeModel::new(
+            conn,
+            user_id,
+            &challenge_token,
+            !totp_enabled,
+        );
+
+        return match challenge.db_insert(conn) {
+            Ok(_) => HttpResponse::Accepted().json(HttpResponseObject {
+                error: false,
+                message: "Second factor required".to_string(),
+                object: Some(two_factor_model::TwoFactorChallengeResult {
+                    challenge_token,
+                    enrolment_required: challenge.enrolment_required,
+                    expires_at: challenge.expires_at,
+                }),
+            }),
+            Err(e) => {
+                log::error!("Error inserting auth challenge: {}", e);
+                HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Internal server error".to_string(),
+                })
+            }
+        };
+    }
+
+    match create_session(conn, conf, req, user_id) {
+        Ok(tokens) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Successfully authenticated".to_string(),
+            object: Some(tokens),
+        }),
+        Err(response) => response,
+    }
+}
+
+fn invalid_credentials() -> HttpResponse {
+    HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+        error: true,
+        message: "Invalid email or password".to_string(),
+    })
+}
+
+fn too_many_attempts(retry_after_seconds: i64) -> HttpResponse {
+    HttpResponse::TooManyRequests()
+        .insert_header((header::RETRY_AFTER, retry_after_seconds.max(1).to_string()))
+        .json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Too many failed sign-in attempts, try again later".to_string(),
+        })
+}
+
+/// Counts a wrong password or second factor towards the account lockout, emailing the user when
+/// it locks the account.
+pub(super) async fn register_sign_in_failure(
+    conn: &mut PgConnection,
+    conf: &MyCondominiumConfig,
+    user_id: Uuid,
+    ip_address: &str,
+    now: NaiveDateTime,
+) {
+    match AccountLockoutModel::db_register_failure(conn, user_id, now, &conf.lockout) {
+        Ok((lockout, true)) => {
+            let locked_until = lockout.locked_until.unwrap_or(now);
+            log::warn!(
+                "Locked user {} until {} after failed sign-ins from {}",
+                user_id,
+                locked_until,
+                ip_address
+            );
+
+            match UserModel::db_read_by_id(conn, user_id)
+                .and_then(|user| user.db_read_personal_info(conn))
+            {
+                Ok(personal_info) => {
+                    send_account_locked_email(conf, personal_info, ip_address, locked_until).await
+                }
+                Err(e) => log::error!("Error reading user: {}", e),
+            }
+        }
+        Ok((_, false)) => (),
+        Err(e) => log::error!("Error saving account lockout: {}", e),
+    }
+}
+
+async fn send_account_locked_email(
+    conf: &MyCondominiumConfig,
+    personal_info: user_model::UserPersonalInfo,
+    ip_address: &str,
+    locked_until: NaiveDateTime,
+) {
+    let email = SmtpEmailPayload::from_template(
+        personal_info.email,
+        "Conta Bloqueada - MyCondominium".to_string(),
+        SmtpTemplate::AccountLocked,
+        vec![
+            SmtpTemplateData {
+                key: "{{USER_NAME}}".to_string(),
+                value: escape_html(&personal_info.first_name),
+            },
+            SmtpTemplateData {
+                key: "{{IP_ADDRESS}}".to_string(),
+                value: escape_html(ip_address),
+            },
+            SmtpTemplateData {
+                key: "{{UNLOCK_AT}}".to_string(),
+                value: locked_until.format("%d/%m/%Y %H:%M").to_string(),
+            },
+            SmtpTemplateData {
+                key: "{{CURRENT_YEAR}}".to_string(),
+                value: chrono::Utc::now().year().to_string(),
+            },
+        ],
+    );
+
+    match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+        Ok(rmq) => {
+            if let Err(e) = email.publish(&rmq).await {
+                log::error!("Error queueing account locked email: {}", e);
+            }
+        }
+        Err(e) => log::error!("Error connecting to RabbitMQ: {}", e),
+    }
+}
+
+/// Refuses residents who have moved out, whichever way they proved their identity.
+fn check_user_active(conn: &mut PgConnection, user_id: Uuid) -> Result<(), HttpResponse> {
+    // Service accounts only authenticate with their API keys.
+    let active = UserModel::db_read_by_id(conn, user_id).and_then(|user| {
+        if user.entity_type == UserTypes::Service {
+            Ok(false)
+        } else {
+            UserModel::db_is_active(conn, user_id)
+        }
+    });
+
+    match active {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(
+            HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Account is not active".to_string(),
+            }),
+        ),
+        Err(e) => {
+            log::error!("Error checking user status: {}", e);
+            Err(
+                HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Error signing in".to_string(),
+                }),
+            )
+        }
+    }
+}
+
+/// Opens a session for the user, recorded in `auth_tokens`, and issues its first token pair.
+pub(super) fn create_session(
+    conn: &mut PgConnection,
+    conf: &MyCondominiumConfig,
+    req: &HttpRequest,
+    user_id: Uuid,
+) -> Result<auth_model::AuthTokenPairResult, HttpResponse> {
+    check_user_active(conn, user_id)?;
+
+    let keys = match JwtKeys::from_request(req) {
+        Ok(keys) => keys,
+        Err(e) => {
+            log::error!("Error creating session: {}", e);
+            return Err(
+                HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Error signing in".to_string(),
+                }),
+            );
+        }
+    };
+
     let user_agent = match req.headers().get("user-agent") {
         Some(ua) => ua.to_str().unwrap_or("").to_string(),
         None => {
-            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+            return Err(HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
                 error: true,
                 message: "User-Agent header is missing".to_string(),
-            });
+            }));
         }
     };
 
     let ua = match parse_user_agent(user_agent) {
         Ok(ua) => ua,
         Err(_) => {
-            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+            return Err(HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
                 error: true,
                 message: "User-Agent header is missing".to_string(),
-            });
+            }));
         }
     };
 
     let auth_token = auth_token_model::AuthTokenModel {
-        user_id: user.user.id,
-        id: token_id,
+        user_id,
+        id: auth_token_model::AuthTokenModel::new_id(conn),
         time_added: chrono::Utc::now().naive_utc(),
         active: true,
         time_last_used: chrono::Utc::now().naive_utc(),
@@ -104,18 +360,50 @@ pub async fn sign_in(
         cpu_arch: ua.cpu.architecture,
     };
 
-    match auth_token.db_insert(conn) {
-        Ok(_) => HttpResponse::Ok().json(HttpResponseObject {
-            error: false,
-            message: "Successfully authenticated".to_string(),
-            object: Some(token),
-        }),
+    let tokens = conn.transaction(|conn| {
+        auth_token.db_insert(conn)?;
+        AccountLockoutModel::db_delete_by_user(conn, user_id)?;
+        issue_token_pair(conn, conf, keys, &auth_token)
+    });
+
+    match tokens {
+        Ok(tokens) => Ok(tokens),
         Err(e) => {
-            log::error!("Error inserting auth token: {}", e);
-            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
-                error: true,
-                message: "Internal server error".to_string(),
-            })
+            log::error!("Error creating session: {}", e);
+            Err(
+                HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Internal server error".to_string(),
+                }),
+            )
         }
     }
 }
+
+/// Issues a new access token for the session together with the refresh token that renews it.
+pub(super) fn issue_token_pair(
+    conn: &mut PgConnection,
+    conf: &MyCondominiumConfig,
+    keys: &JwtKeys,
+    session: &auth_token_model::AuthTokenModel,
+) -> diesel::QueryResult<auth_model::AuthTokenPairResult> {
+    let access_token = generate_access_token(session.user_id, session.id, &conf.auth, keys)
+        .map_err(|e| diesel::result::Error::QueryBuilderError(e.into()))?;
+
+    let refresh_token = generate_token();
+    let refresh = auth_token_model::RefreshTokenModel::new(
+        conn,
+        session.id,
+        &refresh_token,
+        conf.auth.token_expiration_days as i64,
+    );
+    refresh.db_insert(conn)?;
+
+    Ok(auth_model::AuthTokenPairResult {
+        access_token,
+        access_token_expires_at: chrono::Utc::now().naive_utc()
+            + chrono::Duration::minutes(conf.auth.access_token_expiration_minutes as i64),
+        refresh_token,
+        refresh_token_expires_at: refresh.expires_at,
+    })
+}
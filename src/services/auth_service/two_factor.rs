@@ -0,0 +1,568 @@
+use super::sign_in::{create_session, register_sign_in_failure};
+use super::*;
+use crate::utilities::totp_utils::{
+    generate_recovery_codes, generate_totp_secret, totp_provisioning_uri, verify_totp_code,
+};
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/2fa/enroll",
+    responses (
+        (status = 200, description = "Two-factor enrolment started", body = TwoFactorEnrolmentHttpResponse),
+        (status = 400, description = "Two-factor authentication is already enabled", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error starting two-factor enrolment", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn enroll_two_factor(
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (_role, claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match two_factor_model::UserTotpModel::db_read_by_user(conn, claims.user_id) {
+        Ok(Some(totp)) if totp.is_enabled() => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Two-factor authentication is already enabled".to_string(),
+            });
+        }
+        Ok(_) => (),
+        Err(e) => {
+            log::error!("Error getting second factor: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error starting two-factor enrolment".to_string(),
+            });
+        }
+    }
+
+    start_enrolment(conn, claims.user_id)
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/2fa/confirm",
+    request_body = two_factor_model::TwoFactorCodeModel,
+    responses (
+        (status = 200, description = "Two-factor authentication enabled", body = TwoFactorRecoveryCodesHttpResponse),
+        (status = 400, description = "Invalid code", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error enabling two-factor authentication", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn confirm_two_factor(
+    body: web::Json<two_factor_model::TwoFactorCodeModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (_role, claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let mut totp = match two_factor_model::UserTotpModel::db_read_by_user(conn, claims.user_id) {
+        Ok(Some(totp)) if !totp.is_enabled() => totp,
+        Ok(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "No pending two-factor enrolment".to_string(),
+            });
+        }
+        Err(e) => {
+            log::error!("Error getting second factor: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error enabling two-factor authentication".to_string(),
+            });
+        }
+    };
+
+    match complete_enrolment(conn, &mut totp, &body.code) {
+        Ok(Some(recovery_codes)) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Two-factor authentication enabled".to_string(),
+            object: Some(two_factor_model::TwoFactorRecoveryCodesResult { recovery_codes }),
+        }),
+        Ok(None) => HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid code".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error enabling two-factor authentication: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error enabling two-factor authentication".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/2fa/disable",
+    request_body = two_factor_model::TwoFactorCodeModel,
+    responses (
+        (status = 200, description = "Two-factor authentication disabled", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid code", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error disabling two-factor authentication", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn disable_two_factor(
+    body: web::Json<two_factor_model::TwoFactorCodeModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (_role, claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    match two_factor_model::UserTotpModel::db_is_required(conn, claims.user_id) {
+        Ok(false) => (),
+        Ok(true) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Two-factor authentication is required by your Community".to_string(),
+            });
+        }
+        Err(e) => {
+            log::error!("Error checking second factor requirement: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error disabling two-factor authentication".to_string(),
+            });
+        }
+    }
+
+    let mut totp = match read_enabled_totp(conn, claims.user_id) {
+        Ok(totp) => totp,
+        Err(response) => return response,
+    };
+
+    match check_second_factor(conn, &mut totp, &body.code) {
+        Ok(true) => (),
+        Ok(false) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid code".to_string(),
+            });
+        }
+        Err(e) => {
+            log::error!("Error checking second factor: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error disabling two-factor authentication".to_string(),
+            });
+        }
+    }
+
+    match two_factor_model::UserTotpModel::db_delete_by_user(conn, claims.user_id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Two-factor authentication disabled".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error disabling two-factor authentication: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error disabling two-factor authentication".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/2fa/recovery_codes",
+    request_body = two_factor_model::TwoFactorCodeModel,
+    responses (
+        (status = 200, description = "Recovery codes regenerated", body = TwoFactorRecoveryCodesHttpResponse),
+        (status = 400, description = "Invalid code", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error regenerating recovery codes", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn regenerate_recovery_codes(
+    body: web::Json<two_factor_model::TwoFactorCodeModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (_role, claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let mut totp = match read_enabled_totp(conn, claims.user_id) {
+        Ok(totp) => totp,
+        Err(response) => return response,
+    };
+
+    match check_second_factor(conn, &mut totp, &body.code) {
+        Ok(true) => (),
+        Ok(false) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid code".to_string(),
+            });
+        }
+        Err(e) => {
+            log::error!("Error checking second factor: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error regenerating recovery codes".to_string(),
+            });
+        }
+    }
+
+    match issue_recovery_codes(conn, claims.user_id) {
+        Ok(recovery_codes) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Recovery codes regenerated".to_string(),
+            object: Some(two_factor_model::TwoFactorRecoveryCodesResult { recovery_codes }),
+        }),
+        Err(e) => {
+            log::error!("Error regenerating recovery codes: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error regenerating recovery codes".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/2fa/challenge/enroll",
+    request_body = two_factor_model::TwoFactorChallengeModel,
+    responses (
+        (status = 200, description = "Two-factor enrolment started", body = TwoFactorEnrolmentHttpResponse),
+        (status = 400, description = "Two-factor authentication is already enabled", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Invalid or expired challenge", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error starting two-factor enrolment", body = HttpResponseObjectEmptyError),
+    ),
+)]
+pub async fn enroll_two_factor_challenge(
+    body: web::Json<two_factor_model::TwoFactorChallengeModel>,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let challenge = match two_factor_model::AuthChallengeModel::db_read_valid_by_token(
+        conn,
+        &body.challenge_token,
+    ) {
+        Ok(challenge) => challenge,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid or expired challenge".to_string(),
+            });
+        }
+    };
+
+    if !challenge.enrolment_required {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Two-factor authentication is already enabled".to_string(),
+        });
+    }
+
+    start_enrolment(conn, challenge.user_id)
+}
+
+#[utoipa::path(
+    post,
+    tag = "Authentication",
+    path = "/signin/2fa",
+    request_body = two_factor_model::TwoFactorSignInModel,
+    responses (
+        (status = 200, description = "Signed in successfully", body = TwoFactorSignInHttpResponse),
+        (status = 400, description = "No pending two-factor enrolment", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Invalid code or challenge", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error signing in", body = HttpResponseObjectEmptyError),
+    ),
+)]
+pub async fn sign_in_two_factor(
+    body: web::Json<two_factor_model::TwoFactorSignInModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let challenge = match two_factor_model::AuthChallengeModel::db_read_valid_by_token(
+        conn,
+        &body.challenge_token,
+    ) {
+        Ok(challenge) => challenge,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid or expired challenge".to_string(),
+            });
+        }
+    };
+
+    let mut totp = match two_factor_model::UserTotpModel::db_read_by_user(conn, challenge.user_id) {
+        Ok(Some(totp)) if totp.is_enabled() != challenge.enrolment_required => totp,
+        Ok(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "No pending two-factor enrolment".to_string(),
+            });
+        }
+        Err(e) => {
+            log::error!("Error getting second factor: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error signing in".to_string(),
+            });
+        }
+    };
+
+    // Second factors count towards the account lockout, as a new challenge can be started with
+    // each sign-in. None is checked while the account is locked.
+    let now = chrono::Utc::now().naive_utc();
+    match sign_in_attempt_model::AccountLockoutModel::db_read_by_user(conn, challenge.user_id) {
+        Ok(lockout)
+            if lockout
+                .as_ref()
+                .is_some_and(|lockout| lockout.is_locked(now)) =>
+        {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid code".to_string(),
+            });
+        }
+        Ok(_) => (),
+        Err(e) => {
+            log::error!("Error reading account lockout: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error signing in".to_string(),
+            });
+        }
+    }
+
+    // Enrolling through the challenge needs a TOTP code, as there are no recovery codes yet.
+    let checked = if challenge.enrolment_required {
+        complete_enrolment(conn, &mut totp, &body.code).map(|codes| codes.map(Some))
+    } else {
+        check_second_factor(conn, &mut totp, &body.code).map(|valid| valid.then_some(None))
+    };
+
+    let recovery_codes = match checked {
+        Ok(Some(recovery_codes)) => recovery_codes,
+        Ok(None) => {
+            if let Err(e) = challenge.db_register_failure(conn) {
+                log::error!("Error registering failed challenge attempt: {}", e);
+            }
+            let ip_address = client_ip(&req, conf.lockout.trust_forwarded_for);
+            register_sign_in_failure(conn, &conf, challenge.user_id, &ip_address, now).await;
+
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid code".to_string(),
+            });
+        }
+        Err(e) => {
+            log::error!("Error checking second factor: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error signing in".to_string(),
+            });
+        }
+    };
+
+    if let Err(e) = two_factor_model::AuthChallengeModel::db_delete_by_id(conn, challenge.id) {
+        log::error!("Error deleting auth challenge: {}", e);
+    }
+
+    match create_session(conn, &conf, &req, challenge.user_id) {
+        Ok(tokens) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Successfully authenticated".to_string(),
+            object: Some(two_factor_model::TwoFactorSignInResult {
+                tokens,
+                recovery_codes,
+            }),
+        }),
+        Err(response) => response,
+    }
+}
+
+/// Creates a new unconfirmed secret for the user and returns what the authenticator app needs.
+fn start_enrolment(conn: &mut PgConnection, user_id: Uuid) -> HttpResponse {
+    let email = match UserModel::db_read_by_id(conn, user_id).and_then(|u| u.db_read_email(conn)) {
+        Ok(email) => email,
+        Err(e) => {
+            log::error!("Error getting user: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error starting two-factor enrolment".to_string(),
+            });
+        }
+    };
+
+    let totp = match two_factor_model::UserTotpModel::db_start_enrolment(
+        conn,
+        user_id,
+        generate_totp_secret(),
+    ) {
+        Ok(totp) => totp,
+        Err(e) => {
+            log::error!("Error starting two-factor enrolment: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error starting two-factor enrolment".to_string(),
+            });
+        }
+    };
+
+    match totp_provisioning_uri(&totp.secret, &email) {
+        Ok(provisioning_uri) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Scan the QR code and confirm with a code from the app".to_string(),
+            object: Some(two_factor_model::TwoFactorEnrolmentResult {
+                secret: totp.secret,
+                provisioning_uri,
+            }),
+        }),
+        Err(e) => {
+            log::error!("Error building TOTP provisioning URI: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error starting two-factor enrolment".to_string(),
+            })
+        }
+    }
+}
+
+/// Enables a pending second factor if the code matches, returning the new recovery codes.
+fn complete_enrolment(
+    conn: &mut PgConnection,
+    totp: &mut two_factor_model::UserTotpModel,
+    code: &str,
+) -> diesel::QueryResult<Option<Vec<String>>> {
+    let now = chrono::Utc::now().timestamp() as u64;
+
+    match verify_totp_code(&totp.secret, code, now, totp.last_used_step) {
+        Some(step) => {
+            totp.db_use_step(conn, step)?;
+            issue_recovery_codes(conn, totp.user_id).map(Some)
+        }
+        None => Ok(None),
+    }
+}
+
+/// Accepts either a TOTP code or an unused recovery code.
+fn check_second_factor(
+    conn: &mut PgConnection,
+    totp: &mut two_factor_model::UserTotpModel,
+    code: &str,
+) -> diesel::QueryResult<bool> {
+    let now = chrono::Utc::now().timestamp() as u64;
+
+    match verify_totp_code(&totp.secret, code, now, totp.last_used_step) {
+        Some(step) => totp.db_use_step(conn, step).map(|_| true),
+        None => two_factor_model::UserRecoveryCodeModel::db_use(conn, totp.user_id, code),
+    }
+}
+
+fn issue_recovery_codes(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+) -> diesel::QueryResult<Vec<String>> {
+    let codes = generate_recovery_codes();
+    two_factor_model::UserRecoveryCodeModel::db_replace_all(conn, user_id, &codes)?;
+    Ok(codes)
+}
+
+fn read_enabled_totp(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+) -> Result<two_factor_model::UserTotpModel, HttpResponse> {
+    match two_factor_model::UserTotpModel::db_read_by_user(conn, user_id) {
+        Ok(Some(totp)) if totp.is_enabled() => Ok(totp),
+        Ok(_) => Err(
+            HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Two-factor authentication is not enabled".to_string(),
+            }),
+        ),
+        Err(e) => {
+            log::error!("Error getting second factor: {}", e);
+            Err(
+                HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Internal server error".to_string(),
+                }),
+            )
+        }
+    }
+}
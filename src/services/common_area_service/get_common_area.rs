@@ -31,7 +31,7 @@ pub async fn get_common_areas(
 
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -121,7 +121,7 @@ pub async fn get_common_area_by_id(
         }
     };
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
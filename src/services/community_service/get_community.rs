@@ -33,7 +33,7 @@ pub async fn get_communities(
 
     let conn = &mut establish_connection_pg(&conf);
 
-    match authenticate_user(req.clone(), conn, conf) {
+    match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => {
             if role.role != UserRoles::Root {
                 return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -134,7 +134,7 @@ pub async fn get_community_by_id(
         }
     };
 
-    match authenticate_user(req.clone(), conn, conf) {
+    match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => {
             if !(role.role == UserRoles::Root
                 || (role.role == UserRoles::Admin && role.community_id == Some(id)))
@@ -24,7 +24,7 @@ pub async fn new_community(
 
     let body = body.into_inner();
 
-    match authenticate_user(req.clone(), conn, conf) {
+    match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => {
             if role.role != UserRoles::Root {
                 return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -52,6 +52,7 @@ pub async fn new_community(
         address: body.address,
         created_at: chrono::Utc::now().naive_utc(),
         updated_at: chrono::Utc::now().naive_utc(),
+        require_admin_2fa: body.require_admin_2fa,
     };
 
     match new_obj.db_insert(conn) {
@@ -109,7 +110,7 @@ pub async fn update_community(
         }
     };
 
-    match authenticate_user(req.clone(), conn, conf) {
+    match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => match role.role {
             UserRoles::Root => (),
             UserRoles::Admin => {
@@ -157,6 +158,7 @@ pub async fn update_community(
         address: body.address,
         created_at: curr_obj.created_at,
         updated_at: chrono::Utc::now().naive_utc(),
+        require_admin_2fa: body.require_admin_2fa,
     };
 
     match new_obj.db_update(conn) {
@@ -208,7 +210,7 @@ pub async fn delete_community(
         }
     };
 
-    match authenticate_user(req.clone(), conn, conf) {
+    match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => match role.role {
             UserRoles::Root => (),
             _ => {
@@ -0,0 +1,103 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "Election",
+    path = "/list",
+    responses(
+        (status = 200, description = "Got Elections successfully", body = ElectionListHttpResponse),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_elections(
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match election_model::ElectionModel::db_read_all_matching(role, conn) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Elections successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Elections: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Error getting Elections".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    tag = "Election",
+    path = "/get/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Election ID"),
+    ),
+    responses(
+        (status = 200, description = "Got Election successfully", body = ElectionGetHttpResponse),
+        (status = 400, description = "Invalid Election ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 404, description = "Election not found", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_election_by_id(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Election ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match election_model::ElectionModel::db_read_by_id_matching_community(role, conn, id) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Election successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(_) => HttpResponse::NotFound().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Election not found".to_string(),
+        }),
+    }
+}
@@ -0,0 +1,22 @@
+pub mod get_election;
+pub mod upsert_election;
+
+use super::prelude::*;
+type ElectionListHttpResponse = HttpResponseObject<Vec<election_model::ElectionModel>>;
+type ElectionGetHttpResponse = HttpResponseObject<election_model::ElectionModel>;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_election::get_elections,
+        get_election::get_election_by_id,
+        upsert_election::new_election,
+        upsert_election::vote_election,
+    ),
+    components(schemas(
+        election_model::ElectionModel,
+        election_model::ElectionModelNew,
+        vote_model::VoteModelNew
+    ))
+)]
+pub struct ElectionApi;
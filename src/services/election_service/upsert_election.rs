@@ -0,0 +1,207 @@
+use super::*;
+
+#[utoipa::path(
+    post,
+    tag = "Election",
+    path = "/new",
+    request_body = election_model::ElectionModelNew,
+    responses (
+        (status = 200, description = "Election created successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Error creating Election", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error creating Election", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_election(
+    body: web::Json<election_model::ElectionModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if !(role.role == UserRoles::Root
+        || (role.role == UserRoles::Admin && role.community_id == Some(body.community_id)))
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    if body.end_date <= body.start_date {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Election must end after it starts".to_string(),
+        });
+    }
+
+    let new_obj = election_model::ElectionModel {
+        id: election_model::ElectionModel::new_id(conn),
+        community_id: Some(body.community_id),
+        title: body.title,
+        description: body.description,
+        start_date: body.start_date,
+        end_date: body.end_date,
+        created_at: chrono::Utc::now().naive_utc(),
+    };
+
+    match new_obj.db_insert(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+            error: false,
+            message: "Election created successfully".to_string(),
+            entity_id: Some(new_obj.id),
+        }),
+        Err(e) => {
+            log::error!("Error creating Election: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error creating Election".to_string(),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Election",
+    path = "/vote/{id}",
+    request_body = vote_model::VoteModelNew,
+    params(
+        ("id" = Uuid, Path, description = "Election ID"),
+    ),
+    responses (
+        (status = 200, description = "Vote cast successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Invalid vote", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 403, description = "Not allowed to vote for this unit", body = HttpResponseObjectEmptyError),
+        (status = 404, description = "Election not found", body = HttpResponseObjectEmptyError),
+        (status = 409, description = "The unit has already voted", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error casting vote", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn vote_election(
+    id: web::Path<String>,
+    body: web::Json<vote_model::VoteModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Election ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) if role.role == UserRoles::Resident => (role, claims, token),
+        _ => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let user_id = role.user_id;
+    let election =
+        match election_model::ElectionModel::db_read_by_id_matching_community(role, conn, id) {
+            Ok(election) => election,
+            Err(_) => {
+                return HttpResponse::NotFound().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Election not found".to_string(),
+                });
+            }
+        };
+
+    let now = chrono::Utc::now().naive_utc();
+    if !election.is_open(now) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Election is not open".to_string(),
+        });
+    }
+
+    let resident_id = match UserModel::db_read_by_id(conn, user_id) {
+        Ok(user) => user.entity_id,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match election.db_is_voter(conn, body.unit_id, resident_id) {
+        Ok(true) => (),
+        _ => {
+            return HttpResponse::Forbidden().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Not allowed to vote for this unit".to_string(),
+            });
+        }
+    }
+
+    let vote = vote_model::VoteModel {
+        id: vote_model::VoteModel::new_id(conn),
+        election_id: election.id,
+        resident_id,
+        vote_option: body.vote_option.clone(),
+        voted_at: now,
+        unit_id: Some(body.unit_id),
+    };
+
+    // Each unit votes once, which the unique index enforces even for concurrent requests.
+    match vote.db_insert(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+            error: false,
+            message: "Vote cast successfully".to_string(),
+            entity_id: Some(vote.id),
+        }),
+        Err(diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::UniqueViolation,
+            _,
+        )) => HttpResponse::Conflict().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "The unit has already voted".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error casting vote: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error casting vote".to_string(),
+            })
+        }
+    }
+}
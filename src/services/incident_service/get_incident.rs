@@ -7,6 +7,9 @@ use super::*;
     params(
         ("page" = Option<i64>, Query, description = "Page number for pagination (default: 1)"),
         ("per_page" = Option<i64>, Query, description = "Number of items per page for pagination (default: 10)"),
+        ("category_id" = Option<Uuid>, Query, description = "Only return Incidents of this category"),
+        ("priority" = Option<incident_model::IncidentPriority>, Query, description = "Only return Incidents with this priority"),
+        ("sla_breached" = Option<bool>, Query, description = "Only return Incidents that did (or did not) breach an SLA"),
     ),
     responses(
         (status = 200, description = "Got Incidents successfully", body = IncidentListHttpResponse, headers(
@@ -22,6 +25,7 @@ use super::*;
 )]
 pub async fn get_incidents(
     query: web::Query<PaginationParams>,
+    filters: web::Query<incident_model::IncidentFilterParams>,
     req: HttpRequest,
     conf: web::Data<Arc<MyCondominiumConfig>>,
 ) -> HttpResponse {
@@ -31,7 +35,7 @@ pub async fn get_incidents(
 
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -41,20 +45,21 @@ pub async fn get_incidents(
         }
     };
 
-    let total_items = match incident_model::IncidentModel::db_count_all_matching(role.clone(), conn)
-    {
-        Ok(res) => res,
-        Err(e) => {
-            log::error!("Error getting Incidents: {}", e);
-            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
-                error: true,
-                message: "Error getting Incidents".to_string(),
-            });
-        }
-    };
+    let total_items =
+        match incident_model::IncidentModel::db_count_all_matching(role.clone(), conn, &filters) {
+            Ok(res) => res,
+            Err(e) => {
+                log::error!("Error getting Incidents: {}", e);
+                return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Error getting Incidents".to_string(),
+                });
+            }
+        };
 
-    match incident_model::IncidentModel::db_read_all_matching_by_range(role, conn, per_page, offset)
-    {
+    match incident_model::IncidentModel::db_read_all_matching_by_range(
+        role, conn, &filters, per_page, offset,
+    ) {
         Ok(res) => {
             let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
             let remaining_pages = total_pages - page;
@@ -120,7 +125,7 @@ pub async fn get_incident_by_id(
         }
     };
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -145,3 +150,70 @@ pub async fn get_incident_by_id(
         }
     }
 }
+
+#[utoipa::path(
+    get,
+    tag = "Incident",
+    path = "/report/resolution",
+    params(
+        ("community_id" = Option<Uuid>, Query, description = "Community to report on (Root only; Admins always get their own community)"),
+        ("from" = Option<NaiveDateTime>, Query, description = "Only include Incidents reported at or after this date"),
+        ("to" = Option<NaiveDateTime>, Query, description = "Only include Incidents reported at or before this date"),
+    ),
+    responses(
+        (status = 200, description = "Got Incident resolution report successfully", body = IncidentResolutionReportHttpResponse),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_incident_resolution_report(
+    query: web::Query<incident_model::IncidentReportParams>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let community_id = match role.role {
+        UserRoles::Root => query.community_id,
+        UserRoles::Admin => role.community_id,
+        UserRoles::Resident => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match incident_model::IncidentModel::db_resolution_report(
+        conn,
+        community_id,
+        query.from,
+        query.to,
+    ) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Incident resolution report successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Incident resolution report: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Incident resolution report: {}", e),
+            })
+        }
+    }
+}
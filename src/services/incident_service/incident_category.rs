@@ -0,0 +1,290 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "Incident",
+    path = "/category/list",
+    responses(
+        (status = 200, description = "Got Incident categories successfully", body = IncidentCategoryListHttpResponse),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_incident_categories(
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match incident_model::IncidentCategoryModel::db_read_all_matching(role, conn) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Incident categories successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Incident categories: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Incident categories: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Incident",
+    path = "/category/new",
+    request_body = incident_model::IncidentCategoryModelNew,
+    responses (
+        (status = 200, description = "Incident category added successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Error adding Incident category", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error adding Incident category", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_incident_category(
+    body: web::Json<incident_model::IncidentCategoryModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if !(role.role == UserRoles::Root
+        || (role.role == UserRoles::Admin && role.community_id == Some(body.community_id)))
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let new_obj = incident_model::IncidentCategoryModel {
+        id: incident_model::IncidentCategoryModel::new_id(conn),
+        community_id: body.community_id,
+        name: body.name,
+        response_sla_hours: body.response_sla_hours,
+        resolution_sla_hours: body.resolution_sla_hours,
+        created_at: chrono::Utc::now().naive_utc(),
+    };
+
+    match new_obj.db_insert(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+            error: false,
+            message: "Incident category created successfully".to_string(),
+            entity_id: Some(new_obj.id),
+        }),
+        Err(e) => {
+            log::error!("Error creating Incident category: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error creating Incident category: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    tag = "Incident",
+    path = "/category/update/{id}",
+    request_body = incident_model::IncidentCategoryModelNew,
+    params(
+        ("id" = Uuid, Path, description = "Incident category ID"),
+    ),
+    responses (
+        (status = 200, description = "Incident category updated successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Error updating Incident category", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn update_incident_category(
+    id: web::Path<String>,
+    body: web::Json<incident_model::IncidentCategoryModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Incident category ID format".to_string(),
+            });
+        }
+    };
+
+    let curr_obj =
+        match incident_model::IncidentCategoryModel::db_read_by_id_matching(role.clone(), conn, id)
+        {
+            Ok(res) => res,
+            Err(e) => {
+                log::error!("Error reading Incident category: {}", e);
+                return HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                    error: true,
+                    message: "Error updating Incident category".to_string(),
+                });
+            }
+        };
+
+    if !(role.role == UserRoles::Root
+        || (role.role == UserRoles::Admin && curr_obj.community_id == body.community_id))
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let new_obj = incident_model::IncidentCategoryModel {
+        id: curr_obj.id,
+        community_id: body.community_id,
+        name: body.name,
+        response_sla_hours: body.response_sla_hours,
+        resolution_sla_hours: body.resolution_sla_hours,
+        created_at: curr_obj.created_at,
+    };
+
+    match new_obj.db_update(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Incident category updated successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error updating Incident category: {}", e);
+            HttpResponse::Ok().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error updating Incident category: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Incident",
+    path = "/category/delete/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Incident category ID"),
+    ),
+    responses (
+        (status = 200, description = "Incident category deleted successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid Incident category ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error deleting Incident category", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn delete_incident_category(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Incident category ID format".to_string(),
+            });
+        }
+    };
+
+    if incident_model::IncidentCategoryModel::db_read_by_id_matching(role, conn, id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match incident_model::IncidentCategoryModel::db_delete_by_id(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Incident category deleted successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error deleting Incident category: {}", e);
+            HttpResponse::Ok().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error deleting Incident category: {}", e),
+            })
+        }
+    }
+}
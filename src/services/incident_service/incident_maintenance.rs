@@ -0,0 +1,206 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "Incident",
+    path = "/maintenance/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Incident ID"),
+    ),
+    responses(
+        (status = 200, description = "Got Incident Maintenance Schedule successfully", body = IncidentMaintenanceGetHttpResponse),
+        (status = 400, description = "Invalid Incident ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 404, description = "Incident has no Maintenance Schedule", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_incident_maintenance(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Incident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if incident_model::IncidentModel::db_read_by_id_matching_resident(role, conn, id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match maintenance_schedule_model::MaintenanceScheduleModel::db_read_by_incident(conn, id) {
+        Ok(Some(res)) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Incident Maintenance Schedule successfully".to_string(),
+            object: Some(res),
+        }),
+        Ok(None) => HttpResponse::NotFound().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Incident has no Maintenance Schedule".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error getting Incident Maintenance Schedule: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Incident Maintenance Schedule: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Incident",
+    path = "/maintenance/new/{id}",
+    request_body = incident_model::IncidentMaintenanceModelNew,
+    params(
+        ("id" = Uuid, Path, description = "Incident ID"),
+    ),
+    responses (
+        (status = 200, description = "Maintenance Schedule created from Incident successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Incident already has a Maintenance Schedule", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error creating Maintenance Schedule", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn convert_incident_to_maintenance(
+    id: web::Path<String>,
+    body: web::Json<incident_model::IncidentMaintenanceModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Incident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let mut incident = match incident_model::IncidentModel::db_read_by_id_matching_resident(
+        role.clone(),
+        conn,
+        id,
+    ) {
+        Ok(incident) => incident,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    match maintenance_schedule_model::MaintenanceScheduleModel::db_read_by_incident(conn, id) {
+        Ok(None) => {}
+        Ok(Some(_)) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Incident already has a Maintenance Schedule".to_string(),
+            });
+        }
+        Err(e) => {
+            log::error!("Error reading Incident Maintenance Schedule: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error creating Maintenance Schedule".to_string(),
+            });
+        }
+    }
+
+    let new_obj = maintenance_schedule_model::MaintenanceScheduleModel {
+        id: maintenance_schedule_model::MaintenanceScheduleModel::new_id(conn),
+        community_id: Some(incident.community_id),
+        description: incident.name.clone(),
+        scheduled_date: body.scheduled_date,
+        status: body.status,
+        details: Some(body.details.unwrap_or_else(|| incident.description.clone())),
+        created_at: chrono::Utc::now().naive_utc(),
+        updated_at: chrono::Utc::now().naive_utc(),
+        incident_id: Some(incident.id),
+        plan_id: None,
+        vendor_id: None,
+        cost: None,
+        invoice_number: None,
+        completed_at: None,
+        reminder_sent_at: None,
+    };
+
+    if let Err(e) = new_obj.db_insert(conn) {
+        log::error!("Error creating Maintenance Schedule: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: format!("Error creating Maintenance Schedule: {}", e),
+        });
+    }
+
+    if incident.status == incident_model::IncidentStatus::Reported {
+        match incident.db_transition(conn, incident_model::IncidentStatus::Ongoing, role.user_id) {
+            Ok(old_status) => {
+                notify_incident_status_change(conn, &conf, &incident, old_status).await;
+            }
+            Err(e) => log::error!("Error updating Incident status: {}", e),
+        }
+    }
+
+    HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+        error: false,
+        message: "Maintenance Schedule created from Incident successfully".to_string(),
+        entity_id: Some(new_obj.id),
+    })
+}
@@ -0,0 +1,232 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "Incident",
+    path = "/comment/list/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Incident ID"),
+    ),
+    responses(
+        (status = 200, description = "Got Incident comments successfully", body = IncidentCommentListHttpResponse),
+        (status = 400, description = "Invalid Incident ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_incident_comments(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Incident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if incident_model::IncidentModel::db_read_by_id_matching_resident(role, conn, id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match incident_model::IncidentCommentModel::db_read_all_by_incident(conn, id) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Incident comments successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Incident comments: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Incident comments: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Incident",
+    path = "/comment/new/{id}",
+    request_body = incident_model::IncidentCommentModelNew,
+    params(
+        ("id" = Uuid, Path, description = "Incident ID"),
+    ),
+    responses (
+        (status = 200, description = "Incident comment added successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Error adding Incident comment", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error adding Incident comment", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_incident_comment(
+    id: web::Path<String>,
+    body: web::Json<incident_model::IncidentCommentModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Incident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let mut incident = match incident_model::IncidentModel::db_read_by_id_matching_resident(
+        role.clone(),
+        conn,
+        id,
+    ) {
+        Ok(incident) => incident,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let new_obj = incident_model::IncidentCommentModel {
+        id: incident_model::IncidentCommentModel::new_id(conn),
+        incident_id: id,
+        user_id: role.user_id,
+        message: body.message,
+        created_at: chrono::Utc::now().naive_utc(),
+    };
+
+    if let Err(e) = new_obj.db_insert(conn) {
+        log::error!("Error creating Incident comment: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: format!("Error creating Incident comment: {}", e),
+        });
+    }
+
+    // The first reply from the administration counts as the response for SLA purposes.
+    if role.role != UserRoles::Resident && incident.response_date.is_none() {
+        incident.response_date = Some(new_obj.created_at);
+
+        if let Err(e) = incident.db_update(conn) {
+            log::error!("Error updating Incident response date: {}", e);
+        }
+    }
+
+    HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+        error: false,
+        message: "Incident comment created successfully".to_string(),
+        entity_id: Some(new_obj.id),
+    })
+}
+
+#[utoipa::path(
+    get,
+    tag = "Incident",
+    path = "/history/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Incident ID"),
+    ),
+    responses(
+        (status = 200, description = "Got Incident status history successfully", body = IncidentStatusHistoryListHttpResponse),
+        (status = 400, description = "Invalid Incident ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_incident_status_history(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Incident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if incident_model::IncidentModel::db_read_by_id_matching_resident(role, conn, id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match incident_model::IncidentStatusHistoryModel::db_read_all_by_incident(conn, id) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Incident status history successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Incident status history: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Incident status history: {}", e),
+            })
+        }
+    }
+}
@@ -1,23 +1,84 @@
 pub mod get_incident;
+pub mod incident_category;
+pub mod incident_maintenance;
+pub mod incident_timeline;
 pub mod upsert_incident;
 
 use super::prelude::*;
 type IncidentListHttpResponse = HttpResponseObject<Vec<incident_model::IncidentModel>>;
 type IncidentGetHttpResponse = HttpResponseObject<incident_model::IncidentModel>;
+type IncidentCommentListHttpResponse =
+    HttpResponseObject<Vec<incident_model::IncidentCommentModel>>;
+type IncidentStatusHistoryListHttpResponse =
+    HttpResponseObject<Vec<incident_model::IncidentStatusHistoryModel>>;
+type IncidentCategoryListHttpResponse =
+    HttpResponseObject<Vec<incident_model::IncidentCategoryModel>>;
+type IncidentResolutionReportHttpResponse =
+    HttpResponseObject<incident_model::IncidentResolutionReport>;
+type IncidentMaintenanceGetHttpResponse =
+    HttpResponseObject<maintenance_schedule_model::MaintenanceScheduleModel>;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         get_incident::get_incidents,
         get_incident::get_incident_by_id,
+        get_incident::get_incident_resolution_report,
         upsert_incident::new_incident,
         upsert_incident::update_incident,
         upsert_incident::delete_incident,
+        incident_timeline::get_incident_comments,
+        incident_timeline::new_incident_comment,
+        incident_timeline::get_incident_status_history,
+        incident_category::get_incident_categories,
+        incident_category::new_incident_category,
+        incident_category::update_incident_category,
+        incident_category::delete_incident_category,
+        incident_maintenance::get_incident_maintenance,
+        incident_maintenance::convert_incident_to_maintenance,
     ),
     components(schemas(
         incident_model::IncidentModel,
         incident_model::IncidentModelNew,
-        incident_model::IncidentStatus
+        incident_model::IncidentStatus,
+        incident_model::IncidentCommentModel,
+        incident_model::IncidentCommentModelNew,
+        incident_model::IncidentStatusHistoryModel,
+        incident_model::IncidentPriority,
+        incident_model::IncidentCategoryModel,
+        incident_model::IncidentCategoryModelNew,
+        incident_model::IncidentResolutionReport,
+        incident_model::IncidentCategoryResolutionReport,
+        incident_model::IncidentMaintenanceModelNew
     ))
 )]
 pub struct IncidentApi;
+
+/// Emails the reporting resident that `incident` moved away from `old_status`.
+pub(crate) async fn notify_incident_status_change(
+    conn: &mut PgConnection,
+    conf: &MyCondominiumConfig,
+    incident: &incident_model::IncidentModel,
+    old_status: incident_model::IncidentStatus,
+) {
+    match resident_model::ResidentModel::db_read_by_id(conn, incident.resident_id) {
+        Ok(reporter) => {
+            let email = SmtpEmailPayload::from_template(
+                reporter.email.clone(),
+                "Atualização de Ocorrência - MyCondominium".to_string(),
+                SmtpTemplate::IncidentStatusChanged,
+                incident.email_parameters(&reporter, old_status),
+            );
+
+            match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+                Ok(rmq) => {
+                    if let Err(e) = email.publish(&rmq).await {
+                        log::error!("Error queueing Incident status email: {}", e);
+                    }
+                }
+                Err(e) => log::error!("Error connecting to RabbitMQ: {}", e),
+            }
+        }
+        Err(e) => log::error!("Error reading Incident resident: {}", e),
+    }
+}
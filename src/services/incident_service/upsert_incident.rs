@@ -24,7 +24,7 @@ pub async fn new_incident(
 
     let body = body.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -59,6 +59,31 @@ pub async fn new_incident(
         return HttpResponse::BadRequest().json(validation_errors);
     }
 
+    if let Some(assignee_id) = body.assignee_id
+        && role.role != UserRoles::Resident
+        && !matches!(
+            incident_model::IncidentModel::db_check_assignee(conn, body.community_id, assignee_id),
+            Ok(true)
+        )
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid Incident assignee".to_string(),
+        });
+    }
+
+    if let Some(category_id) = body.category_id
+        && !matches!(
+            incident_model::IncidentModel::db_check_category(conn, body.community_id, category_id),
+            Ok(true)
+        )
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid Incident category".to_string(),
+        });
+    }
+
     let body_clone = body.clone();
 
     let mut new_obj = incident_model::IncidentModel {
@@ -71,6 +96,12 @@ pub async fn new_incident(
         report_date: chrono::Utc::now().naive_utc(),
         resolution_date: body_clone.resolution_date,
         notes: body_clone.notes,
+        assignee_id: body_clone.assignee_id,
+        category_id: body_clone.category_id,
+        priority: body_clone.priority,
+        response_date: None,
+        response_sla_breached: false,
+        resolution_sla_breached: false,
     };
 
     let body = body.clone();
@@ -86,9 +117,17 @@ pub async fn new_incident(
             report_date: chrono::Utc::now().naive_utc(),
             resolution_date: None,
             notes: None,
+            assignee_id: None,
+            category_id: body.category_id,
+            priority: incident_model::IncidentPriority::default(),
+            response_date: None,
+            response_sla_breached: false,
+            resolution_sla_breached: false,
         };
     }
 
+    new_obj.track_progress(new_obj.report_date);
+
     match new_obj.db_insert(conn) {
         Ok(_) => (),
         Err(e) => {
@@ -100,6 +139,10 @@ pub async fn new_incident(
         }
     };
 
+    if let Err(e) = new_obj.db_record_status_change(conn, None, role.user_id) {
+        log::error!("Error recording Incident status history: {}", e);
+    }
+
     HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
         error: false,
         message: "Incident created successfully".to_string(),
@@ -134,7 +177,7 @@ pub async fn update_incident(
     let conn = &mut establish_connection_pg(&conf);
     let body = body.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -190,6 +233,42 @@ pub async fn update_incident(
         return HttpResponse::BadRequest().json(validation_errors);
     }
 
+    if let Some(assignee_id) = body.assignee_id
+        && role.role != UserRoles::Resident
+        && curr_obj.assignee_id != Some(assignee_id)
+        && !matches!(
+            incident_model::IncidentModel::db_check_assignee(
+                conn,
+                curr_obj.community_id,
+                assignee_id
+            ),
+            Ok(true)
+        )
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid Incident assignee".to_string(),
+        });
+    }
+
+    if let Some(category_id) = body.category_id
+        && curr_obj.category_id != Some(category_id)
+        && !matches!(
+            incident_model::IncidentModel::db_check_category(
+                conn,
+                curr_obj.community_id,
+                category_id
+            ),
+            Ok(true)
+        )
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid Incident category".to_string(),
+        });
+    }
+
+    let old_status = curr_obj.status;
     let body_clone = body.clone();
 
     let mut new_obj = incident_model::IncidentModel {
@@ -202,6 +281,12 @@ pub async fn update_incident(
         report_date: curr_obj.report_date,
         resolution_date: body_clone.resolution_date,
         notes: body_clone.notes,
+        assignee_id: body_clone.assignee_id,
+        category_id: body_clone.category_id,
+        priority: body_clone.priority,
+        response_date: curr_obj.response_date,
+        response_sla_breached: curr_obj.response_sla_breached,
+        resolution_sla_breached: curr_obj.resolution_sla_breached,
     };
 
     let body = body.clone();
@@ -217,22 +302,73 @@ pub async fn update_incident(
             report_date: curr_obj.report_date,
             resolution_date: curr_obj.resolution_date,
             notes: curr_obj.notes,
+            assignee_id: curr_obj.assignee_id,
+            category_id: curr_obj.category_id,
+            priority: curr_obj.priority,
+            response_date: curr_obj.response_date,
+            response_sla_breached: curr_obj.response_sla_breached,
+            resolution_sla_breached: curr_obj.resolution_sla_breached,
         };
     }
 
+    let now = chrono::Utc::now().naive_utc();
+    new_obj.track_progress(now);
+
+    if let Some(category_id) = new_obj.category_id {
+        match incident_model::IncidentCategoryModel::db_read_by_id(conn, category_id) {
+            Ok(category) => {
+                new_obj.check_sla(&category, now);
+            }
+            Err(e) => log::error!("Error reading Incident category: {}", e),
+        }
+    }
+
     match new_obj.db_update(conn) {
-        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
-            error: false,
-            message: "Incident updated successfully".to_string(),
-        }),
+        Ok(_) => (),
         Err(e) => {
             log::error!("Error updating Incident: {}", e);
-            HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            return HttpResponse::Ok().json(HttpResponseObjectEmpty {
                 error: true,
                 message: format!("Error updating Incident: {}", e),
-            })
+            });
         }
     }
+
+    // `db_update` skips the fields that became `None`, so they are cleared on their own.
+    let cleared = conn.transaction(|conn| {
+        if new_obj.assignee_id.is_none() && curr_obj.assignee_id.is_some() {
+            new_obj.db_unassign(conn)?;
+        }
+        if new_obj.category_id.is_none() && curr_obj.category_id.is_some() {
+            new_obj.db_uncategorise(conn)?;
+        }
+        if new_obj.resolution_date.is_none() && curr_obj.resolution_date.is_some() {
+            new_obj.db_clear_resolution(conn)?;
+        }
+
+        Ok::<_, diesel::result::Error>(())
+    });
+
+    if let Err(e) = cleared {
+        log::error!("Error clearing Incident fields: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+            error: true,
+            message: "Error updating Incident".to_string(),
+        });
+    }
+
+    if new_obj.status != old_status {
+        if let Err(e) = new_obj.db_record_status_change(conn, Some(old_status), role.user_id) {
+            log::error!("Error recording Incident status history: {}", e);
+        }
+
+        notify_incident_status_change(conn, &conf, &new_obj, old_status).await;
+    }
+
+    HttpResponse::Ok().json(HttpResponseObjectEmpty {
+        error: false,
+        message: "Incident updated successfully".to_string(),
+    })
 }
 
 #[utoipa::path(
@@ -259,7 +395,7 @@ pub async fn delete_incident(
 ) -> HttpResponse {
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
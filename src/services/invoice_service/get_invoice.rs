@@ -31,7 +31,7 @@ pub async fn get_invoices(
 
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -118,7 +118,7 @@ pub async fn get_invoice_by_id(
         }
     };
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
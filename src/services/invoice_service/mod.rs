@@ -17,7 +17,8 @@ type InvoiceGetHttpResponse = HttpResponseObject<invoice_model::InvoiceModel>;
     components(schemas(
         invoice_model::InvoiceModel,
         invoice_model::InvoiceModelNew,
-        invoice_model::InvoiceStatus
+        invoice_model::InvoiceStatus,
+        invoice_model::InvoiceChargeType
     ))
 )]
 pub struct InvoiceApi;
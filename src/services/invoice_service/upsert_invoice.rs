@@ -24,7 +24,7 @@ pub async fn new_invoice(
 
     let body = body.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
@@ -43,7 +43,23 @@ pub async fn new_invoice(
         }
     };
 
-    let resident = match resident_model::ResidentModel::db_get_user(conn, body.resident_id) {
+    let resident_id = match body.db_resolve_resident(conn) {
+        Ok(Some(resident_id)) => resident_id,
+        Ok(None) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "No resident is responsible for this charge".to_string(),
+            });
+        }
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid Unit".to_string(),
+            });
+        }
+    };
+
+    let resident = match resident_model::ResidentModel::db_get_user(conn, resident_id) {
         Ok(resident) => resident,
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -68,7 +84,7 @@ pub async fn new_invoice(
 
     let new_obj = invoice_model::InvoiceModel {
         id: invoice_model::InvoiceModel::new_id(conn),
-        resident_id: body.resident_id,
+        resident_id,
         community_id: body.community_id,
         issue_date: body.issue_date,
         due_date: body.due_date,
@@ -77,6 +93,8 @@ pub async fn new_invoice(
         paid_date: body.paid_date,
         created_at: chrono::Utc::now().naive_utc(),
         updated_at: chrono::Utc::now().naive_utc(),
+        unit_id: body.unit_id,
+        charge_type: body.charge_type,
     };
 
     match new_obj.db_insert(conn) {
@@ -124,7 +142,7 @@ pub async fn update_invoice(
     let conn = &mut establish_connection_pg(&conf);
     let body = body.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
@@ -164,7 +182,23 @@ pub async fn update_invoice(
         }
     };
 
-    let resident = match resident_model::ResidentModel::db_get_user(conn, body.resident_id) {
+    let resident_id = match body.db_resolve_resident(conn) {
+        Ok(Some(resident_id)) => resident_id,
+        Ok(None) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "No resident is responsible for this charge".to_string(),
+            });
+        }
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid Unit".to_string(),
+            });
+        }
+    };
+
+    let resident = match resident_model::ResidentModel::db_get_user(conn, resident_id) {
         Ok(resident) => resident,
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -200,7 +234,7 @@ pub async fn update_invoice(
 
     let new_obj = invoice_model::InvoiceModel {
         id: curr_obj.id,
-        resident_id: body.resident_id,
+        resident_id,
         community_id: body.community_id,
         issue_date: body.issue_date,
         due_date: body.due_date,
@@ -209,9 +243,21 @@ pub async fn update_invoice(
         paid_date: body.paid_date,
         created_at: curr_obj.created_at,
         updated_at: chrono::Utc::now().naive_utc(),
+        unit_id: body.unit_id,
+        charge_type: body.charge_type,
     };
 
-    match new_obj.db_update(conn) {
+    let updated = conn.transaction(|conn| {
+        new_obj.db_update(conn)?;
+
+        if new_obj.status != invoice_model::InvoiceStatus::Paid && curr_obj.paid_date.is_some() {
+            new_obj.db_clear_paid_date(conn)?;
+        }
+
+        Ok::<_, diesel::result::Error>(())
+    });
+
+    match updated {
         Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
             error: false,
             message: "Invoice updated successfully".to_string(),
@@ -250,7 +296,7 @@ pub async fn delete_invoice(
 ) -> HttpResponse {
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
@@ -31,7 +31,7 @@ pub async fn get_maintenance_schedules(
 
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -133,7 +133,7 @@ pub async fn count_maintenance_schedule(
 ) -> HttpResponse {
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -199,7 +199,7 @@ pub async fn get_maintenance_schedule_by_id(
         }
     };
 
-    let role = match authenticate_user(req.clone(), conn, conf) {
+    let role = match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => {
             if role.role == UserRoles::Root
                 || role.role == UserRoles::Admin
@@ -238,3 +238,70 @@ pub async fn get_maintenance_schedule_by_id(
         }
     }
 }
+
+#[utoipa::path(
+    get,
+    tag = "MaintenanceSchedule",
+    path = "/report/expenses",
+    params(
+        ("community_id" = Option<Uuid>, Query, description = "Community to report on (Root only; Admins always get their own community)"),
+        ("from" = Option<NaiveDateTime>, Query, description = "Only include maintenance completed at or after this date"),
+        ("to" = Option<NaiveDateTime>, Query, description = "Only include maintenance completed at or before this date"),
+    ),
+    responses(
+        (status = 200, description = "Got Maintenance expense report successfully", body = MaintenanceExpenseReportHttpResponse),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_maintenance_expense_report(
+    query: web::Query<maintenance_schedule_model::MaintenanceExpenseReportParams>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let community_id = match role.role {
+        UserRoles::Root => query.community_id,
+        UserRoles::Admin => role.community_id,
+        UserRoles::Resident => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match maintenance_schedule_model::MaintenanceScheduleModel::db_expense_report(
+        conn,
+        community_id,
+        query.from,
+        query.to,
+    ) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Maintenance expense report successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Maintenance expense report: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Maintenance expense report: {}", e),
+            })
+        }
+    }
+}
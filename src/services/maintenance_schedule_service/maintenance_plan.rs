@@ -0,0 +1,433 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "MaintenanceSchedule",
+    path = "/plan/list",
+    responses(
+        (status = 200, description = "Got Maintenance Plans successfully", body = MaintenancePlanListHttpResponse),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_maintenance_plans(
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match maintenance_plan_model::MaintenancePlanModel::db_read_all_matching_community(role, conn) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Maintenance Plans successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Maintenance Plans: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Maintenance Plans: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    tag = "MaintenanceSchedule",
+    path = "/plan/get/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Maintenance Plan ID"),
+    ),
+    responses(
+        (status = 200, description = "Got Maintenance Plan successfully", body = MaintenancePlanGetHttpResponse),
+        (status = 400, description = "Invalid Maintenance Plan ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_maintenance_plan_by_id(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Maintenance Plan ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match maintenance_plan_model::MaintenancePlanModel::db_read_by_id_matching_community(
+        role, conn, id,
+    ) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Maintenance Plan successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Maintenance Plan: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Maintenance Plan: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "MaintenanceSchedule",
+    path = "/plan/new",
+    request_body = maintenance_plan_model::MaintenancePlanModelNew,
+    responses (
+        (status = 200, description = "Maintenance Plan added successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Error adding Maintenance Plan", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error adding Maintenance Plan", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_maintenance_plan(
+    body: web::Json<maintenance_plan_model::MaintenancePlanModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if !(role.role == UserRoles::Root
+        || (role.role == UserRoles::Admin && role.community_id == Some(body.community_id)))
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    if let Some(vendor_id) = body.vendor_id
+        && !matches!(
+            maintenance_schedule_model::MaintenanceScheduleModel::db_check_vendor(
+                conn,
+                Some(body.community_id),
+                vendor_id
+            ),
+            Ok(true)
+        )
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid Vendor".to_string(),
+        });
+    }
+
+    let mut new_obj = maintenance_plan_model::MaintenancePlanModel {
+        id: maintenance_plan_model::MaintenancePlanModel::new_id(conn),
+        community_id: body.community_id,
+        description: body.description,
+        details: body.details,
+        frequency: body.frequency,
+        frequency_interval: body.frequency_interval,
+        start_date: body.start_date,
+        end_date: body.end_date,
+        active: body.active,
+        occurrence_count: 0,
+        created_at: chrono::Utc::now().naive_utc(),
+        updated_at: chrono::Utc::now().naive_utc(),
+        vendor_id: body.vendor_id,
+    };
+
+    // Plans starting in the past begin with their next upcoming occurrence.
+    let now = chrono::Utc::now().naive_utc();
+    if new_obj.start_date < now {
+        new_obj.skip_until(now);
+    }
+
+    if let Err(e) = new_obj.db_insert(conn) {
+        log::error!("Error creating Maintenance Plan: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: format!("Error creating Maintenance Plan: {}", e),
+        });
+    }
+
+    let until = now + chrono::Duration::days(conf.maintenance.plan_lookahead_days as i64);
+    if let Err(e) = new_obj.db_materialise_until(conn, until) {
+        log::error!("Error scheduling Maintenance Plan {}: {}", new_obj.id, e);
+    }
+
+    HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+        error: false,
+        message: "Maintenance Plan created successfully".to_string(),
+        entity_id: Some(new_obj.id),
+    })
+}
+
+#[utoipa::path(
+    put,
+    tag = "MaintenanceSchedule",
+    path = "/plan/update/{id}",
+    request_body = maintenance_plan_model::MaintenancePlanModelNew,
+    params(
+        ("id" = Uuid, Path, description = "Maintenance Plan ID"),
+    ),
+    responses (
+        (status = 200, description = "Maintenance Plan updated successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Error updating Maintenance Plan", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn update_maintenance_plan(
+    id: web::Path<String>,
+    body: web::Json<maintenance_plan_model::MaintenancePlanModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Maintenance Plan ID format".to_string(),
+            });
+        }
+    };
+
+    let curr_obj =
+        match maintenance_plan_model::MaintenancePlanModel::db_read_by_id_matching_community(
+            role.clone(),
+            conn,
+            id,
+        ) {
+            Ok(res) => res,
+            Err(e) => {
+                log::error!("Error updating Maintenance Plan: {}", e);
+                return HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                    error: true,
+                    message: "Error updating Maintenance Plan".to_string(),
+                });
+            }
+        };
+
+    if !(role.role == UserRoles::Root || curr_obj.community_id == body.community_id) {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    if let Some(vendor_id) = body.vendor_id
+        && !matches!(
+            maintenance_schedule_model::MaintenanceScheduleModel::db_check_vendor(
+                conn,
+                Some(body.community_id),
+                vendor_id
+            ),
+            Ok(true)
+        )
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid Vendor".to_string(),
+        });
+    }
+
+    let mut new_obj = maintenance_plan_model::MaintenancePlanModel {
+        id: curr_obj.id,
+        community_id: body.community_id,
+        description: body.description,
+        details: body.details,
+        frequency: body.frequency,
+        frequency_interval: body.frequency_interval,
+        start_date: body.start_date,
+        end_date: body.end_date,
+        active: body.active,
+        occurrence_count: curr_obj.occurrence_count,
+        created_at: curr_obj.created_at,
+        updated_at: chrono::Utc::now().naive_utc(),
+        vendor_id: body.vendor_id,
+    };
+
+    // A new cadence continues after the last occurrence that was already scheduled.
+    if new_obj.frequency != curr_obj.frequency
+        || new_obj.frequency_interval != curr_obj.frequency_interval
+        || new_obj.start_date != curr_obj.start_date
+    {
+        let last_scheduled = curr_obj
+            .occurrence(curr_obj.occurrence_count - 1)
+            .unwrap_or(curr_obj.start_date);
+        new_obj.skip_until(last_scheduled.max(chrono::Utc::now().naive_utc()));
+    }
+
+    match new_obj.db_update(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Maintenance Plan updated successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error updating Maintenance Plan: {}", e);
+            HttpResponse::Ok().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error updating Maintenance Plan: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "MaintenanceSchedule",
+    path = "/plan/delete/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Maintenance Plan ID"),
+    ),
+    responses (
+        (status = 200, description = "Maintenance Plan deleted successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid Maintenance Plan ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error deleting Maintenance Plan", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn delete_maintenance_plan(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Maintenance Plan ID format".to_string(),
+            });
+        }
+    };
+
+    if maintenance_plan_model::MaintenancePlanModel::db_read_by_id_matching_community(
+        role, conn, id,
+    )
+    .is_err()
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match maintenance_plan_model::MaintenancePlanModel::db_delete_by_id(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Maintenance Plan deleted successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error deleting Maintenance Plan: {}", e);
+            HttpResponse::Ok().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error deleting Maintenance Plan: {}", e),
+            })
+        }
+    }
+}
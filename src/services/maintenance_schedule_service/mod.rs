@@ -1,4 +1,5 @@
 pub mod get_maintenance_schedule;
+pub mod maintenance_plan;
 pub mod upsert_maintenance_schedule;
 
 use super::prelude::*;
@@ -6,6 +7,12 @@ type MaintenanceScheduleListHttpResponse =
     HttpResponseObject<Vec<maintenance_schedule_model::MaintenanceScheduleModel>>;
 type MaintenanceScheduleGetHttpResponse =
     HttpResponseObject<maintenance_schedule_model::MaintenanceScheduleModel>;
+type MaintenanceExpenseReportHttpResponse =
+    HttpResponseObject<maintenance_schedule_model::MaintenanceExpenseReport>;
+type MaintenancePlanListHttpResponse =
+    HttpResponseObject<Vec<maintenance_plan_model::MaintenancePlanModel>>;
+type MaintenancePlanGetHttpResponse =
+    HttpResponseObject<maintenance_plan_model::MaintenancePlanModel>;
 
 #[derive(OpenApi)]
 #[openapi(
@@ -13,14 +20,74 @@ type MaintenanceScheduleGetHttpResponse =
         get_maintenance_schedule::get_maintenance_schedules,
         get_maintenance_schedule::count_maintenance_schedule,
         get_maintenance_schedule::get_maintenance_schedule_by_id,
+        get_maintenance_schedule::get_maintenance_expense_report,
         upsert_maintenance_schedule::new_maintenance_schedule,
         upsert_maintenance_schedule::update_maintenance_schedule,
         upsert_maintenance_schedule::delete_maintenance_schedule,
+        maintenance_plan::get_maintenance_plans,
+        maintenance_plan::get_maintenance_plan_by_id,
+        maintenance_plan::new_maintenance_plan,
+        maintenance_plan::update_maintenance_plan,
+        maintenance_plan::delete_maintenance_plan,
     ),
     components(schemas(
         maintenance_schedule_model::MaintenanceScheduleModel,
         maintenance_schedule_model::MaintenanceScheduleModelNew,
         maintenance_schedule_model::MaintenanceScheduleStatus,
+        maintenance_plan_model::MaintenancePlanModel,
+        maintenance_plan_model::MaintenancePlanModelNew,
+        maintenance_plan_model::MaintenancePlanFrequency,
+        maintenance_schedule_model::MaintenanceExpenseReport,
+        maintenance_schedule_model::MaintenanceVendorExpense,
     ))
 )]
 pub struct MaintenanceScheduleApi;
+
+/// Emails every active resident of the maintenance's community. Returns how many emails were queued.
+pub(crate) async fn notify_maintenance_residents(
+    conn: &mut PgConnection,
+    rabbitmq: &RabbitMqClient,
+    maintenance: &maintenance_schedule_model::MaintenanceScheduleModel,
+    template: SmtpTemplate,
+    subject: &str,
+) -> usize {
+    let community_id = match maintenance.community_id {
+        Some(community_id) => community_id,
+        None => return 0,
+    };
+
+    let residents =
+        match resident_model::ResidentModel::db_read_all_active_by_community(conn, community_id) {
+            Ok(residents) => residents,
+            Err(e) => {
+                log::error!(
+                    "Error reading residents of community {}: {}",
+                    community_id,
+                    e
+                );
+                return 0;
+            }
+        };
+
+    let mut sent = 0;
+
+    for resident in residents {
+        let email = SmtpEmailPayload::from_template(
+            resident.email.clone(),
+            subject.to_string(),
+            template,
+            maintenance.email_parameters(&resident),
+        );
+
+        match email.publish(rabbitmq).await {
+            Ok(_) => sent += 1,
+            Err(e) => log::error!(
+                "Error queueing Maintenance Schedule email to {}: {}",
+                resident.email,
+                e
+            ),
+        }
+    }
+
+    sent
+}
@@ -24,7 +24,7 @@ pub async fn new_maintenance_schedule(
 
     let body = body.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
@@ -56,6 +56,33 @@ pub async fn new_maintenance_schedule(
         return HttpResponse::BadRequest().json(validation_errors);
     }
 
+    if let Some(vendor_id) = body.vendor_id
+        && !matches!(
+            maintenance_schedule_model::MaintenanceScheduleModel::db_check_vendor(
+                conn,
+                body.community_id,
+                vendor_id
+            ),
+            Ok(true)
+        )
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid Vendor".to_string(),
+        });
+    }
+
+    if body.status == maintenance_schedule_model::MaintenanceScheduleStatus::Completed
+        && body.vendor_id.is_some()
+        && body.cost.is_none()
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Cost is required to complete a Maintenance Schedule with a Vendor"
+                .to_string(),
+        });
+    }
+
     let new_obj = maintenance_schedule_model::MaintenanceScheduleModel {
         id: maintenance_schedule_model::MaintenanceScheduleModel::new_id(conn),
         community_id: body.community_id,
@@ -65,6 +92,18 @@ pub async fn new_maintenance_schedule(
         details: body.details,
         created_at: chrono::Utc::now().naive_utc(),
         updated_at: chrono::Utc::now().naive_utc(),
+        incident_id: None,
+        plan_id: None,
+        completed_at: match body.status {
+            maintenance_schedule_model::MaintenanceScheduleStatus::Completed => {
+                Some(chrono::Utc::now().naive_utc())
+            }
+            _ => None,
+        },
+        vendor_id: body.vendor_id,
+        cost: body.cost,
+        invoice_number: body.invoice_number,
+        reminder_sent_at: None,
     };
 
     match new_obj.db_insert(conn) {
@@ -78,6 +117,26 @@ pub async fn new_maintenance_schedule(
         }
     };
 
+    let (template, subject) = match new_obj.status {
+        maintenance_schedule_model::MaintenanceScheduleStatus::Immediate => (
+            SmtpTemplate::MaintenanceImmediate,
+            "Manutenção Imediata - MyCondominium",
+        ),
+        _ => (
+            SmtpTemplate::MaintenanceScheduled,
+            "Manutenção Agendada - MyCondominium",
+        ),
+    };
+
+    if new_obj.status != maintenance_schedule_model::MaintenanceScheduleStatus::Completed {
+        match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+            Ok(rmq) => {
+                notify_maintenance_residents(conn, &rmq, &new_obj, template, subject).await;
+            }
+            Err(e) => log::error!("Error connecting to RabbitMQ: {}", e),
+        }
+    }
+
     HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
         error: false,
         message: "Maintenance Schedule created successfully".to_string(),
@@ -112,7 +171,7 @@ pub async fn update_maintenance_schedule(
     let conn = &mut establish_connection_pg(&conf);
     let body = body.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
@@ -168,6 +227,33 @@ pub async fn update_maintenance_schedule(
         return HttpResponse::BadRequest().json(validation_errors);
     }
 
+    if let Some(vendor_id) = body.vendor_id
+        && !matches!(
+            maintenance_schedule_model::MaintenanceScheduleModel::db_check_vendor(
+                conn,
+                body.community_id,
+                vendor_id
+            ),
+            Ok(true)
+        )
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid Vendor".to_string(),
+        });
+    }
+
+    if body.status == maintenance_schedule_model::MaintenanceScheduleStatus::Completed
+        && body.vendor_id.is_some()
+        && body.cost.is_none()
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Cost is required to complete a Maintenance Schedule with a Vendor"
+                .to_string(),
+        });
+    }
+
     let new_obj = maintenance_schedule_model::MaintenanceScheduleModel {
         id: curr_obj.id,
         community_id: body.community_id,
@@ -177,21 +263,102 @@ pub async fn update_maintenance_schedule(
         details: body.details,
         created_at: curr_obj.created_at,
         updated_at: chrono::Utc::now().naive_utc(),
+        incident_id: curr_obj.incident_id,
+        plan_id: curr_obj.plan_id,
+        completed_at: match (body.status, curr_obj.completed_at) {
+            (maintenance_schedule_model::MaintenanceScheduleStatus::Completed, None) => {
+                Some(chrono::Utc::now().naive_utc())
+            }
+            (_, completed_at) => completed_at,
+        },
+        vendor_id: body.vendor_id,
+        cost: body.cost,
+        invoice_number: body.invoice_number,
+        reminder_sent_at: curr_obj.reminder_sent_at,
     };
 
-    match new_obj.db_update(conn) {
-        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
-            error: false,
-            message: "Maintenance Schedule updated successfully".to_string(),
-        }),
-        Err(e) => {
-            log::error!("Error creating Maintenance Schedule: {}", e);
-            HttpResponse::Ok().json(HttpResponseObjectEmpty {
-                error: true,
-                message: format!("Error creating Maintenance Schedule: {}", e),
-            })
+    if let Err(e) = new_obj.db_update(conn) {
+        log::error!("Error creating Maintenance Schedule: {}", e);
+        return HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: true,
+            message: format!("Error creating Maintenance Schedule: {}", e),
+        });
+    }
+
+    if new_obj.scheduled_date != curr_obj.scheduled_date
+        && curr_obj.reminder_sent_at.is_some()
+        && let Err(e) = new_obj.db_reset_reminder(conn)
+    {
+        log::error!("Error resetting Maintenance Schedule reminder: {}", e);
+    }
+
+    if new_obj.status == maintenance_schedule_model::MaintenanceScheduleStatus::Immediate
+        && curr_obj.status != maintenance_schedule_model::MaintenanceScheduleStatus::Immediate
+    {
+        match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+            Ok(rmq) => {
+                notify_maintenance_residents(
+                    conn,
+                    &rmq,
+                    &new_obj,
+                    SmtpTemplate::MaintenanceImmediate,
+                    "Manutenção Imediata - MyCondominium",
+                )
+                .await;
+            }
+            Err(e) => log::error!("Error connecting to RabbitMQ: {}", e),
+        }
+    }
+
+    // Completing an occurrence of a recurring plan schedules the following one.
+    if new_obj.status == maintenance_schedule_model::MaintenanceScheduleStatus::Completed
+        && curr_obj.status != maintenance_schedule_model::MaintenanceScheduleStatus::Completed
+        && let Some(plan_id) = new_obj.plan_id
+    {
+        match maintenance_plan_model::MaintenancePlanModel::db_read_by_id(conn, plan_id) {
+            Ok(mut plan) => {
+                if let Err(e) = plan.db_materialise_next(conn) {
+                    log::error!(
+                        "Error scheduling next occurrence of plan {}: {}",
+                        plan_id,
+                        e
+                    );
+                }
+            }
+            Err(e) => log::error!("Error reading Maintenance Plan {}: {}", plan_id, e),
+        }
+    }
+
+    // Completing a work order resolves the incident it was created from.
+    if new_obj.status == maintenance_schedule_model::MaintenanceScheduleStatus::Completed
+        && curr_obj.status != maintenance_schedule_model::MaintenanceScheduleStatus::Completed
+        && let Some(incident_id) = new_obj.incident_id
+    {
+        match incident_model::IncidentModel::db_read_by_id(conn, incident_id) {
+            Ok(mut incident) if incident.is_open() => {
+                match incident.db_transition(
+                    conn,
+                    incident_model::IncidentStatus::Resolved,
+                    role.user_id,
+                ) {
+                    Ok(old_status) => {
+                        crate::services::incident_service::notify_incident_status_change(
+                            conn, &conf, &incident, old_status,
+                        )
+                        .await;
+                    }
+                    Err(e) => log::error!("Error resolving Incident {}: {}", incident_id, e),
+                }
+            }
+            Ok(_) => {}
+            Err(e) => log::error!("Error reading Incident {}: {}", incident_id, e),
         }
     }
+
+    HttpResponse::Ok().json(HttpResponseObjectEmpty {
+        error: false,
+        message: "Maintenance Schedule updated successfully".to_string(),
+    })
 }
 
 #[utoipa::path(
@@ -218,7 +385,7 @@ pub async fn delete_maintenance_schedule(
 ) -> HttpResponse {
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
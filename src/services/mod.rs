@@ -1,8 +1,11 @@
 pub mod admin_service;
+pub mod announcement_service;
 pub mod api;
+pub mod attachment_service;
 pub mod auth_service;
 pub mod common_area_service;
 pub mod community_service;
+pub mod election_service;
 pub mod incident_service;
 pub mod invoice_service;
 pub mod maintenance_schedule_service;
@@ -10,7 +13,10 @@ pub mod parcel_service;
 pub mod prelude;
 pub mod reservation_service;
 pub mod resident_service;
+pub mod unit_service;
 pub mod vehicle_service;
+pub mod vendor_service;
+pub mod visitor_service;
 
 pub use prelude::*;
 use utoipa::openapi::security::{ApiKey, ApiKeyValue};
@@ -37,6 +43,12 @@ use utoipa::openapi::security::{ApiKey, ApiKeyValue};
         (path = "/api/reservation", api = reservation_service::ReservationApi),
         (path = "/api/invoice", api = invoice_service::InvoiceApi),
         (path = "/api/incident", api = incident_service::IncidentApi),
+        (path = "/api/attachment", api = attachment_service::AttachmentApi),
+        (path = "/api/vendor", api = vendor_service::VendorApi),
+        (path = "/api/unit", api = unit_service::UnitApi),
+        (path = "/api/visitor", api = visitor_service::VisitorApi),
+        (path = "/api/announcement", api = announcement_service::AnnouncementApi),
+        (path = "/api/election", api = election_service::ElectionApi),
     )
 )]
 pub struct ApiDoc;
@@ -24,7 +24,7 @@ pub async fn new_parcel(
 
     let body = body.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
@@ -74,6 +74,9 @@ pub async fn new_parcel(
         arrival_date: body.arrival_date,
         received: body.received,
         received_at: body.received_at,
+        reminder_sent_at: None,
+        flagged_for_return: false,
+        flagged_for_return_at: None,
     };
 
     match new_obj.db_insert(conn) {
@@ -87,6 +90,24 @@ pub async fn new_parcel(
         }
     };
 
+    if !new_obj.received {
+        let email = SmtpEmailPayload::from_template(
+            resident.resident.email.clone(),
+            "Nova Encomenda - MyCondominium".to_string(),
+            SmtpTemplate::ParcelArrival,
+            new_obj.email_parameters(&resident.resident),
+        );
+
+        match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+            Ok(rmq) => {
+                if let Err(e) = email.publish(&rmq).await {
+                    log::error!("Error queueing Parcel arrival email: {}", e);
+                }
+            }
+            Err(e) => log::error!("Error connecting to RabbitMQ: {}", e),
+        }
+    }
+
     HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
         error: false,
         message: "Parcel created successfully".to_string(),
@@ -121,7 +142,7 @@ pub async fn update_parcel(
     let conn = &mut establish_connection_pg(&conf);
     let body = body.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -194,6 +215,9 @@ pub async fn update_parcel(
         arrival_date: body.arrival_date,
         received: body.received,
         received_at: body.received_at,
+        reminder_sent_at: curr_obj.reminder_sent_at,
+        flagged_for_return: curr_obj.flagged_for_return,
+        flagged_for_return_at: curr_obj.flagged_for_return_at,
     };
 
     if role.role == UserRoles::Resident {
@@ -205,6 +229,9 @@ pub async fn update_parcel(
             arrival_date: curr_obj.arrival_date,
             received: body.received,
             received_at: body.received_at,
+            reminder_sent_at: curr_obj.reminder_sent_at,
+            flagged_for_return: curr_obj.flagged_for_return,
+            flagged_for_return_at: curr_obj.flagged_for_return_at,
         };
     }
 
@@ -247,7 +274,7 @@ pub async fn delete_parcel(
 ) -> HttpResponse {
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
@@ -31,7 +31,7 @@ pub async fn get_residents(
 
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
@@ -137,7 +137,7 @@ pub async fn count_resident(
 ) -> HttpResponse {
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => match role.role {
             UserRoles::Root => (role, claims, token),
             UserRoles::Admin => (role, claims, token),
@@ -205,7 +205,7 @@ pub async fn get_resident_by_id(
         }
     };
 
-    let role = match authenticate_user(req.clone(), conn, conf) {
+    let role = match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 role
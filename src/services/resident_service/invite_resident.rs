@@ -1,10 +1,9 @@
 use super::*;
 use crate::internal::rabbitmq::rabbitmq_client::RabbitMqClient;
 use crate::internal::smtp::smtp_client::SmtpEmailPayload;
-use crate::internal::smtp::smtp_templates::{SmtpTemplate, SmtpTemplateData};
+use crate::internal::smtp::smtp_templates::SmtpTemplate;
 use crate::models::resident_model::ResidentInviteModel;
 use crate::utilities::user_utils::check_email_exist;
-use chrono::Datelike;
 use std::time::Duration;
 use tokio::time::sleep;
 
@@ -31,7 +30,7 @@ pub async fn new_resident_invite(
 
     let body = body.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
@@ -84,19 +83,22 @@ pub async fn new_resident_invite(
         }
     }
 
-    let new_id = resident_model::ResidentInviteModel::new_id(conn);
-
-    let new_obj = resident_model::ResidentInviteModel {
-        id: new_id,
-        email: body.email.clone(),
-        community_id: body.community_id,
-        key: format!(
-            "{}-{}",
-            new_id.to_string(),
-            uuid::Uuid::new_v4().to_string()
-        ),
-        created_at: chrono::Utc::now().naive_utc(),
-    };
+    if matches!(
+        resident_model::ResidentInviteModel::db_is_email_invited(conn, &body.email),
+        Ok(true)
+    ) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Resident already invited, resend the pending invitation instead".to_string(),
+        });
+    }
+
+    let new_obj = resident_model::ResidentInviteModel::new(
+        conn,
+        body.email.clone(),
+        body.community_id,
+        conf.invite.expiration_days,
+    );
 
     match new_obj.db_insert(conn) {
         Ok(_) => (),
@@ -113,23 +115,7 @@ pub async fn new_resident_invite(
         .await
         .unwrap();
 
-    let parameters: Vec<SmtpTemplateData> = vec![
-        SmtpTemplateData {
-            key: "{{COMMUNITY_NAME}}".to_string(),
-            value: community.name,
-        },
-        SmtpTemplateData {
-            key: "{{INVITE_LINK}}".to_string(),
-            value: format!(
-                "{}/redirect/onboarding/register/{}",
-                conf.smtp.base_url, new_obj.key
-            ),
-        },
-        SmtpTemplateData {
-            key: "{{CURRENT_YEAR}}".to_string(),
-            value: chrono::Utc::now().year().to_string(),
-        },
-    ];
+    let parameters = new_obj.email_parameters(community.name, &conf.smtp.base_url);
 
     let template_data = crate::internal::smtp::smtp_templates::smtp_get_template(
         SmtpTemplate::ResidentInvite,
@@ -184,7 +170,7 @@ pub async fn get_resident_invites(
 
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
@@ -267,7 +253,7 @@ pub async fn count_resident_invite(
 ) -> HttpResponse {
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => match role.role {
             UserRoles::Root => (role, claims, token),
             UserRoles::Admin => (role, claims, token),
@@ -338,7 +324,7 @@ pub async fn get_resident_invite_by_id(
         }
     };
 
-    let role = match authenticate_user(req.clone(), conn, conf) {
+    let role = match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 role
@@ -360,11 +346,8 @@ pub async fn get_resident_invite_by_id(
     match resident_model::ResidentInviteModel::db_read_by_id_matching_community(role, conn, id) {
         Ok(user_req) => {
             let invite = ResidentInviteModel {
-                id: user_req.id,
-                email: user_req.email,
-                community_id: user_req.community_id,
                 key: "".to_string(),
-                created_at: user_req.created_at,
+                ..user_req
             };
             HttpResponse::Ok().json(HttpResponseObject {
                 error: false,
@@ -404,17 +387,15 @@ pub async fn get_resident_invite_by_key(
 
     let conn = &mut establish_connection_pg(&conf);
 
-    match resident_model::ResidentInviteModel::table()
-        .filter(resident_invites::key.eq(&key))
-        .first::<resident_model::ResidentInviteModel>(conn)
-    {
+    match resident_model::ResidentInviteModel::db_read_pending_by_key(conn, &key) {
         Ok(user_req) => {
+            if let Err(e) = user_req.db_mark_opened(conn) {
+                log::error!("Error marking resident invite as opened: {}", e);
+            }
+
             let invite = ResidentInviteModel {
-                id: user_req.id,
-                email: user_req.email,
-                community_id: user_req.community_id,
                 key: "".to_string(),
-                created_at: user_req.created_at,
+                ..user_req
             };
             HttpResponse::Ok().json(HttpResponseObject {
                 error: false,
@@ -476,7 +457,7 @@ pub async fn delete_resident_invite(
         }
     };
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -552,9 +533,7 @@ pub async fn new_resident_by_invite(
 
     sleep(Duration::from_secs(5)).await;
 
-    let invite = match resident_model::ResidentInviteModel::table()
-        .filter(resident_invites::key.eq(&body.key))
-        .first::<resident_model::ResidentInviteModel>(conn)
+    let invite = match resident_model::ResidentInviteModel::db_read_pending_by_key(conn, &body.key)
     {
         Ok(inv) => inv,
         Err(_) => {
@@ -565,18 +544,30 @@ pub async fn new_resident_by_invite(
         }
     };
 
+    if let Err(e) = check_password_policy(
+        &body.password,
+        &conf.password_policy,
+        &[&body.first_name, &body.last_name, &invite.email],
+    ) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: e.to_string(),
+        });
+    }
+
     let new_obj = resident_model::ResidentModel {
         id: resident_model::ResidentModel::new_id_user(conn),
         first_name: body.first_name,
         last_name: body.last_name,
-        unit_number: body.unit_number,
         address: body.address,
         phone: body.phone,
-        email: invite.email,
+        email: invite.email.clone(),
         date_of_birth: body.date_of_birth,
         is_active: true,
         created_at: chrono::Utc::now().naive_utc(),
         updated_at: chrono::Utc::now().naive_utc(),
+        moved_in_at: Some(chrono::Utc::now().date_naive()),
+        moved_out_at: None,
     };
 
     match new_obj.db_insert(conn) {
@@ -589,6 +580,10 @@ pub async fn new_resident_by_invite(
         }
     };
 
+    if let Err(e) = resident_model::ResidentChecklistItemModel::db_seed_move_in(conn, new_obj.id) {
+        log::error!("Error creating resident move-in checklist: {}", e);
+    }
+
     let hashed_password = match hash_password(body.password) {
         Ok(passwd) => passwd,
         Err(e) => {
@@ -609,6 +604,7 @@ pub async fn new_resident_by_invite(
         password: hashed_password,
         created_at: chrono::Utc::now().naive_utc(),
         updated_at: chrono::Utc::now().naive_utc(),
+        service_account_id: None,
     };
 
     match new_obj_user.db_insert(conn) {
@@ -631,10 +627,10 @@ pub async fn new_resident_by_invite(
         updated_at: chrono::Utc::now().naive_utc(),
     };
 
-    match resident_model::ResidentInviteModel::db_delete_by_id(conn, invite.id) {
+    match invite.db_mark_accepted(conn) {
         Ok(_) => (),
         Err(e) => {
-            log::error!("Error deleting resident invite: {}", e);
+            log::error!("Error accepting resident invite: {}", e);
             return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
                 error: true,
                 message: "Error creating resident".to_string(),
@@ -659,3 +655,259 @@ pub async fn new_resident_by_invite(
         entity_id: Some(new_obj.id),
     })
 }
+
+#[utoipa::path(
+    put,
+    tag = "Resident",
+    path = "/invite/resend/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Resident Invite ID"),
+    ),
+    responses (
+        (status = 200, description = "Resident Invite resent successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Invalid Resident Invite ID format or invite already accepted", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error resending Resident Invite", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn resend_resident_invite(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Resident Invite ID format".to_string(),
+            });
+        }
+    };
+
+    let role = match authenticate_user(req.clone(), conn) {
+        Ok((role, _claims, _token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                role
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let mut invite =
+        match resident_model::ResidentInviteModel::db_read_by_id_matching_community(role, conn, id)
+        {
+            Ok(invite) => invite,
+            Err(_) => {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        };
+
+    if invite.status == resident_model::ResidentInviteStatus::Accepted {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Resident Invite already accepted".to_string(),
+        });
+    }
+
+    let community = match community_model::CommunityModel::db_read_by_id(conn, invite.community_id)
+    {
+        Ok(community) => community,
+        Err(e) => {
+            log::error!("Error resending resident invite: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error resending resident invite: {}", e),
+            });
+        }
+    };
+
+    if let Err(e) = invite.db_rotate_key(conn, conf.invite.expiration_days) {
+        log::error!("Error resending resident invite: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: format!("Error resending resident invite: {}", e),
+        });
+    }
+
+    let email = SmtpEmailPayload::from_template(
+        invite.email.clone(),
+        "Convite do MyCondominium".to_string(),
+        SmtpTemplate::ResidentInvite,
+        invite.email_parameters(community.name, &conf.smtp.base_url),
+    );
+
+    match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+        Ok(rmq) => {
+            if let Err(e) = email.publish(&rmq).await {
+                log::error!("Error sending resident invite: {}", e);
+            }
+        }
+        Err(e) => log::error!("Error connecting to RabbitMQ: {}", e),
+    }
+
+    HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+        error: false,
+        message: "Resident Invite resent successfully".to_string(),
+        entity_id: Some(invite.id),
+    })
+}
+
+#[utoipa::path(
+    post,
+    tag = "Resident",
+    path = "/invite/bulk",
+    request_body = resident_model::ResidentInviteBulkModel,
+    responses (
+        (status = 200, description = "Resident Invites processed", body = ResidentInviteBulkHttpResponse),
+        (status = 400, description = "Invalid Community ID or email list", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error inviting Residents", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_resident_invite_bulk(
+    body: web::Json<resident_model::ResidentInviteBulkModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    match authenticate_user(req.clone(), conn) {
+        Ok((role, _claims, _token)) => {
+            if !(role.role == UserRoles::Root
+                || (role.role == UserRoles::Admin && role.community_id == Some(body.community_id)))
+            {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let community = match community_model::CommunityModel::db_read_by_id(conn, body.community_id) {
+        Ok(community) => community,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid Community ID".to_string(),
+            });
+        }
+    };
+
+    let rmq = match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+        Ok(rmq) => rmq,
+        Err(e) => {
+            log::error!("Error connecting to RabbitMQ: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error inviting Residents".to_string(),
+            });
+        }
+    };
+
+    let mut results: Vec<resident_model::ResidentInviteBulkResult> = Vec::new();
+    let mut seen_emails: std::collections::HashSet<String> = std::collections::HashSet::new();
+
+    for email in body.emails {
+        let email = email.trim().to_lowercase();
+
+        let error = if !validator::ValidateEmail::validate_email(&email) {
+            Some("Invalid email")
+        } else if !seen_emails.insert(email.clone()) {
+            Some("Email repeated in the list")
+        } else if check_email_exist(conn, email.clone()).is_err() {
+            Some("Email already in use")
+        } else if matches!(
+            resident_model::ResidentInviteModel::db_is_email_invited(conn, &email),
+            Ok(true)
+        ) {
+            Some("Resident already invited")
+        } else {
+            None
+        };
+
+        if let Some(error) = error {
+            results.push(resident_model::ResidentInviteBulkResult {
+                email,
+                invite_id: None,
+                error: Some(error.to_string()),
+            });
+            continue;
+        }
+
+        let invite = resident_model::ResidentInviteModel::new(
+            conn,
+            email.clone(),
+            community.id,
+            conf.invite.expiration_days,
+        );
+
+        if let Err(e) = invite.db_insert(conn) {
+            log::error!("Error creating resident invitation: {}", e);
+            results.push(resident_model::ResidentInviteBulkResult {
+                email,
+                invite_id: None,
+                error: Some(format!("Error creating resident invitation: {}", e)),
+            });
+            continue;
+        }
+
+        let payload = SmtpEmailPayload::from_template(
+            invite.email.clone(),
+            "Convite do MyCondominium".to_string(),
+            SmtpTemplate::ResidentInvite,
+            invite.email_parameters(community.name.clone(), &conf.smtp.base_url),
+        );
+
+        if let Err(e) = payload.publish(&rmq).await {
+            log::error!("Error sending resident invite: {}", e);
+        }
+
+        results.push(resident_model::ResidentInviteBulkResult {
+            email,
+            invite_id: Some(invite.id),
+            error: None,
+        });
+    }
+
+    let invited = results.iter().filter(|r| r.invite_id.is_some()).count();
+
+    HttpResponse::Ok().json(HttpResponseObject {
+        error: invited != results.len(),
+        message: format!("Invited {} of {} Residents", invited, results.len()),
+        object: Some(results),
+    })
+}
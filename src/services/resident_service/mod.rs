@@ -1,5 +1,7 @@
 pub mod get_resident;
 pub mod invite_resident;
+pub mod resident_import;
+pub mod resident_lifecycle;
 pub mod upsert_resident;
 
 use super::prelude::*;
@@ -7,6 +9,12 @@ type ResidentListHttpResponse = HttpResponseObject<Vec<resident_model::ResidentM
 type ResidentGetHttpResponse = HttpResponseObject<resident_model::ResidentModel>;
 type ResidentInviteListHttpResponse = HttpResponseObject<Vec<resident_model::ResidentInviteModel>>;
 type ResidentInviteGetHttpResponse = HttpResponseObject<resident_model::ResidentInviteModel>;
+type ResidentChecklistListHttpResponse =
+    HttpResponseObject<Vec<resident_model::ResidentChecklistItemModel>>;
+type ResidentInviteBulkHttpResponse =
+    HttpResponseObject<Vec<resident_model::ResidentInviteBulkResult>>;
+type ResidentImportHttpResponse = HttpResponseObject<resident_model::ResidentImportReport>;
+type InvoiceListHttpResponse = HttpResponseObject<Vec<invoice_model::InvoiceModel>>;
 
 #[derive(OpenApi)]
 #[openapi(
@@ -24,14 +32,33 @@ type ResidentInviteGetHttpResponse = HttpResponseObject<resident_model::Resident
         invite_resident::get_resident_invite_by_key,
         invite_resident::delete_resident_invite,
         invite_resident::new_resident_by_invite,
+        invite_resident::resend_resident_invite,
+        invite_resident::new_resident_invite_bulk,
+        resident_lifecycle::move_out_resident,
+        resident_lifecycle::move_in_resident,
+        resident_lifecycle::get_resident_checklist,
+        resident_lifecycle::new_resident_checklist_item,
+        resident_lifecycle::complete_resident_checklist_item,
+        resident_import::import_residents,
     ),
     components(schemas(
         resident_model::ResidentModel,
         resident_model::ResidentModelNew,
         resident_model::ResidentInviteModel,
         resident_model::ResidentInviteModelNew,
+        resident_model::ResidentInviteBulkModel,
+        resident_model::ResidentInviteBulkResult,
+        resident_model::ResidentInviteStatus,
         resident_model::ResidentModelNewInvite,
-        resident_model::ResidentModelEdit
+        resident_model::ResidentModelEdit,
+        resident_model::ResidentMoveOutModel,
+        resident_model::ResidentMoveInModel,
+        resident_model::ResidentChecklistItemModel,
+        resident_model::ResidentChecklistItemModelNew,
+        resident_model::ResidentImportMode,
+        resident_model::ResidentImportReport,
+        resident_model::ResidentImportRowError,
+        attachment_model::AttachmentUploadForm
     ))
 )]
 pub struct ResidentApi;
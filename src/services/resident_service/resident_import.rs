@@ -0,0 +1,333 @@
+use super::*;
+use crate::utilities::import_utils::read_spreadsheet;
+use actix_multipart::Multipart;
+use chrono::Datelike;
+use futures_util::StreamExt;
+use std::collections::HashSet;
+
+#[utoipa::path(
+    post,
+    tag = "Resident",
+    path = "/import",
+    request_body(content = attachment_model::AttachmentUploadForm, content_type = "multipart/form-data"),
+    params(
+        ("community_id" = Uuid, Query, description = "Community the residents belong to"),
+        ("mode" = resident_model::ResidentImportMode, Query, description = "Create the accounts or send invitations"),
+        ("dry_run" = Option<bool>, Query, description = "Only validate the file and report per-row errors (default: false)"),
+    ),
+    responses (
+        (status = 200, description = "Residents imported successfully", body = ResidentImportHttpResponse),
+        (status = 400, description = "Invalid file or rows", body = ResidentImportHttpResponse),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error importing Residents", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn import_residents(
+    params: web::Query<resident_model::ResidentImportParams>,
+    mut payload: Multipart,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let params = params.into_inner();
+
+    let (_role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root
+                || (role.role == UserRoles::Admin && role.community_id == Some(params.community_id))
+            {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let community = match community_model::CommunityModel::db_read_by_id(conn, params.community_id)
+    {
+        Ok(community) => community,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid Community ID".to_string(),
+            });
+        }
+    };
+
+    let mut file: Option<(String, Vec<u8>)> = None;
+
+    while let Some(item) = payload.next().await {
+        let mut field = match item {
+            Ok(field) => field,
+            Err(e) => {
+                log::error!("Error reading multipart payload: {}", e);
+                return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Invalid multipart payload".to_string(),
+                });
+            }
+        };
+
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let file_name = field
+            .content_disposition()
+            .and_then(|cd| cd.get_filename())
+            .unwrap_or_default()
+            .to_string();
+
+        let mut data: Vec<u8> = Vec::new();
+
+        while let Some(chunk) = field.next().await {
+            match chunk {
+                Ok(bytes) => {
+                    if data.len() + bytes.len() > conf.attachment.max_size_bytes {
+                        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                            error: true,
+                            message: format!(
+                                "File exceeds the maximum size of {} bytes",
+                                conf.attachment.max_size_bytes
+                            ),
+                        });
+                    }
+                    data.extend_from_slice(&bytes);
+                }
+                Err(e) => {
+                    log::error!("Error reading multipart field: {}", e);
+                    return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                        error: true,
+                        message: "Invalid multipart payload".to_string(),
+                    });
+                }
+            }
+        }
+
+        file = Some((file_name, data));
+    }
+
+    let (file_name, data) = match file {
+        Some(file) => file,
+        None => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Missing file field".to_string(),
+            });
+        }
+    };
+
+    let rows = match read_spreadsheet(&file_name, &data)
+        .and_then(resident_model::ResidentImportRow::from_table)
+    {
+        Ok(rows) if !rows.is_empty() => rows,
+        Ok(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "File has no residents".to_string(),
+            });
+        }
+        Err(e) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: e,
+            });
+        }
+    };
+
+    let mut residents: Vec<resident_model::ResidentModelNew> = Vec::new();
+    let mut errors: Vec<resident_model::ResidentImportRowError> = Vec::new();
+    let mut seen_emails: HashSet<String> = HashSet::new();
+
+    for row in &rows {
+        let mut row_errors = Vec::new();
+
+        match row.to_resident_new(params.community_id, generate_password()) {
+            Ok(resident) => residents.push(resident),
+            Err(e) => row_errors.extend(e),
+        }
+
+        if !seen_emails.insert(row.email.clone()) {
+            row_errors.push("Email repeated in the file".to_string());
+        } else if check_email_exist(conn, row.email.clone()).is_err()
+            || matches!(
+                resident_model::ResidentInviteModel::db_is_email_invited(conn, &row.email),
+                Ok(true)
+            )
+        {
+            row_errors.push("Email already in use".to_string());
+        }
+
+        if !row_errors.is_empty() {
+            errors.push(resident_model::ResidentImportRowError {
+                row: row.row,
+                email: row.email.clone(),
+                errors: row_errors,
+            });
+        }
+    }
+
+    // Nothing is written unless every row is valid, so a corrected file can be sent again as is.
+    if params.dry_run || !errors.is_empty() {
+        let report = resident_model::ResidentImportReport {
+            dry_run: params.dry_run,
+            total_rows: rows.len(),
+            imported: 0,
+            errors,
+        };
+
+        return if report.errors.is_empty() {
+            HttpResponse::Ok().json(HttpResponseObject {
+                error: false,
+                message: "Import file is valid".to_string(),
+                object: Some(report),
+            })
+        } else {
+            HttpResponse::BadRequest().json(HttpResponseObject {
+                error: true,
+                message: "Import file has invalid rows".to_string(),
+                object: Some(report),
+            })
+        };
+    }
+
+    let rmq = match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+        Ok(rmq) => rmq,
+        Err(e) => {
+            log::error!("Error connecting to RabbitMQ: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Error importing Residents".to_string(),
+            });
+        }
+    };
+
+    let mut imported = 0;
+
+    for (row, resident) in rows.iter().zip(residents) {
+        let email = match params.mode {
+            resident_model::ResidentImportMode::Create => {
+                import_account(conn, &conf, &community, resident)
+            }
+            resident_model::ResidentImportMode::Invite => {
+                import_invite(conn, &conf, &community, resident)
+            }
+        };
+
+        match email {
+            Ok(email) => {
+                imported += 1;
+
+                if let Err(e) = email.publish(&rmq).await {
+                    log::error!("Error sending import email to {}: {}", email.to, e);
+                }
+            }
+            Err(e) => {
+                log::error!("Error importing Resident {}: {}", row.email, e);
+                errors.push(resident_model::ResidentImportRowError {
+                    row: row.row,
+                    email: row.email.clone(),
+                    errors: vec![format!("Error importing Resident: {}", e)],
+                });
+            }
+        }
+    }
+
+    HttpResponse::Ok().json(HttpResponseObject {
+        error: !errors.is_empty(),
+        message: format!("Imported {} of {} Residents", imported, rows.len()),
+        object: Some(resident_model::ResidentImportReport {
+            dry_run: false,
+            total_rows: rows.len(),
+            imported,
+            errors,
+        }),
+    })
+}
+
+/// Creates the account of an imported resident and builds the email with the link to set its
+/// password. The generated password is never sent, so nobody can sign in until it's set.
+fn import_account(
+    conn: &mut PgConnection,
+    conf: &MyCondominiumConfig,
+    community: &community_model::CommunityModel,
+    resident: resident_model::ResidentModelNew,
+) -> Result<SmtpEmailPayload, String> {
+    let hashed_password = hash_password(resident.password.clone()).map_err(|e| e.to_string())?;
+
+    let user =
+        resident_model::ResidentModel::db_create_account(conn, resident.clone(), hashed_password)
+            .map_err(|e| e.to_string())?;
+
+    let password_reset = auth_model::PasswordResetModel::new(
+        conn,
+        user.id,
+        resident.email.clone(),
+        chrono::Duration::hours(conf.invite.account_link_expiration_hours as i64),
+    );
+    password_reset.db_insert(conn).map_err(|e| e.to_string())?;
+
+    Ok(SmtpEmailPayload::from_template(
+        resident.email.clone(),
+        "Sua conta no MyCondominium".to_string(),
+        SmtpTemplate::ResidentAccountCreated,
+        vec![
+            SmtpTemplateData {
+                key: "{{USER_NAME}}".to_string(),
+                value: escape_html(&resident.first_name),
+            },
+            SmtpTemplateData {
+                key: "{{COMMUNITY_NAME}}".to_string(),
+                value: escape_html(&community.name),
+            },
+            SmtpTemplateData {
+                key: "{{USER_EMAIL}}".to_string(),
+                value: escape_html(&resident.email),
+            },
+            SmtpTemplateData {
+                key: "{{RESET_LINK}}".to_string(),
+                value: password_reset.link(&conf.smtp.base_url),
+            },
+            SmtpTemplateData {
+                key: "{{CURRENT_YEAR}}".to_string(),
+                value: chrono::Utc::now().year().to_string(),
+            },
+        ],
+    ))
+}
+
+/// Records an invitation for an imported resident and builds the invitation email.
+fn import_invite(
+    conn: &mut PgConnection,
+    conf: &MyCondominiumConfig,
+    community: &community_model::CommunityModel,
+    resident: resident_model::ResidentModelNew,
+) -> Result<SmtpEmailPayload, String> {
+    let invite = resident_model::ResidentInviteModel::new(
+        conn,
+        resident.email,
+        community.id,
+        conf.invite.expiration_days,
+    );
+
+    invite.db_insert(conn).map_err(|e| e.to_string())?;
+
+    Ok(SmtpEmailPayload::from_template(
+        invite.email.clone(),
+        "Convite do MyCondominium".to_string(),
+        SmtpTemplate::ResidentInvite,
+        invite.email_parameters(community.name.clone(), &conf.smtp.base_url),
+    ))
+}
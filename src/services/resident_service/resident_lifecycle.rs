@@ -0,0 +1,491 @@
+use super::*;
+
+#[utoipa::path(
+    put,
+    tag = "Resident",
+    path = "/move_out/{id}",
+    request_body = resident_model::ResidentMoveOutModel,
+    params(
+        ("id" = Uuid, Path, description = "Resident ID"),
+    ),
+    responses (
+        (status = 200, description = "Resident moved out successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Resident has open invoices, is already inactive or the date is invalid", body = InvoiceListHttpResponse),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error moving Resident out", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn move_out_resident(
+    id: web::Path<String>,
+    body: web::Json<resident_model::ResidentMoveOutModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Resident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let resident =
+        match resident_model::ResidentModel::db_read_by_id_matching_community(role, conn, id) {
+            Ok(res) => res.resident,
+            Err(_) => {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        };
+
+    if !resident.is_active {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Resident already moved out".to_string(),
+        });
+    }
+
+    let moved_out_at = body
+        .moved_out_at
+        .unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+    if let Err(message) =
+        resident.check_move_out_date(moved_out_at, chrono::Utc::now().date_naive())
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message,
+        });
+    }
+
+    // Sign-off is blocked while anything is still owed, the admin gets the list to settle.
+    match invoice_model::InvoiceModel::db_read_open_by_resident(conn, id) {
+        Ok(invoices) if !invoices.is_empty() => {
+            return HttpResponse::BadRequest().json(HttpResponseObject {
+                error: true,
+                message: "Resident has open invoices".to_string(),
+                object: Some(invoices),
+            });
+        }
+        Ok(_) => (),
+        Err(e) => {
+            log::error!("Error checking Resident invoices: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error checking Resident invoices: {}", e),
+            });
+        }
+    }
+
+    match resident_model::ResidentModel::db_move_out(conn, id, moved_out_at) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Resident moved out successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error moving Resident out: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error moving Resident out: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    tag = "Resident",
+    path = "/move_in/{id}",
+    request_body = resident_model::ResidentMoveInModel,
+    params(
+        ("id" = Uuid, Path, description = "Resident ID"),
+    ),
+    responses (
+        (status = 200, description = "Resident moved in successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Resident is already active or the date is invalid", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error moving Resident in", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn move_in_resident(
+    id: web::Path<String>,
+    body: web::Json<resident_model::ResidentMoveInModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Resident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let resident =
+        match resident_model::ResidentModel::db_read_by_id_matching_community(role, conn, id) {
+            Ok(res) => res.resident,
+            Err(_) => {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        };
+
+    if resident.is_active {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Resident is already active".to_string(),
+        });
+    }
+
+    let today = chrono::Utc::now().date_naive();
+    let moved_in_at = body.moved_in_at.unwrap_or(today);
+
+    if let Err(message) = resident.check_move_in_date(moved_in_at, today) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message,
+        });
+    }
+
+    match resident_model::ResidentModel::db_move_in(conn, id, moved_in_at) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Resident moved in successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error moving Resident in: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error moving Resident in: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    tag = "Resident",
+    path = "/checklist/list/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Resident ID"),
+    ),
+    responses(
+        (status = 200, description = "Got Resident checklist successfully", body = ResidentChecklistListHttpResponse),
+        (status = 400, description = "Invalid Resident ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_resident_checklist(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Resident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if resident_model::ResidentModel::db_read_by_id_matching_community(role, conn, id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match resident_model::ResidentChecklistItemModel::db_read_all_by_resident(conn, id) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Resident checklist successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Resident checklist: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Resident checklist: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Resident",
+    path = "/checklist/new/{id}",
+    request_body = resident_model::ResidentChecklistItemModelNew,
+    params(
+        ("id" = Uuid, Path, description = "Resident ID"),
+    ),
+    responses (
+        (status = 200, description = "Checklist item added successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Error adding checklist item", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error adding checklist item", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_resident_checklist_item(
+    id: web::Path<String>,
+    body: web::Json<resident_model::ResidentChecklistItemModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Resident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if resident_model::ResidentModel::db_read_by_id_matching_community(role, conn, id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let new_obj = resident_model::ResidentChecklistItemModel {
+        id: resident_model::ResidentChecklistItemModel::new_id(conn),
+        resident_id: id,
+        item: body.item,
+        completed_at: None,
+        completed_by: None,
+        created_at: chrono::Utc::now().naive_utc(),
+    };
+
+    match new_obj.db_insert(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+            error: false,
+            message: "Checklist item added successfully".to_string(),
+            entity_id: Some(new_obj.id),
+        }),
+        Err(e) => {
+            log::error!("Error adding checklist item: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error adding checklist item: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    tag = "Resident",
+    path = "/checklist/complete/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Checklist item ID"),
+    ),
+    responses (
+        (status = 200, description = "Checklist item completed successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Invalid checklist item ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error completing checklist item", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn complete_resident_checklist_item(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid checklist item ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let mut item = match resident_model::ResidentChecklistItemModel::db_read_by_id(conn, id) {
+        Ok(item) => item,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if resident_model::ResidentModel::db_read_by_id_matching_community(
+        role.clone(),
+        conn,
+        item.resident_id,
+    )
+    .is_err()
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if item.completed_at.is_some() {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Checklist item already completed".to_string(),
+        });
+    }
+
+    item.completed_at = Some(chrono::Utc::now().naive_utc());
+    item.completed_by = Some(role.user_id);
+
+    match item.db_update(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+            error: false,
+            message: "Checklist item completed successfully".to_string(),
+            entity_id: Some(item.id),
+        }),
+        Err(e) => {
+            log::error!("Error completing checklist item: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error completing checklist item: {}", e),
+            })
+        }
+    }
+}
@@ -24,7 +24,7 @@ pub async fn new_resident(
 
     let body = body.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf.clone()) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => {
             if role.role == UserRoles::Root || role.role == UserRoles::Admin {
                 (role, claims, token)
@@ -81,11 +81,21 @@ pub async fn new_resident(
         }
     }
 
+    if let Err(e) = check_password_policy(
+        &body.password,
+        &conf.password_policy,
+        &[&body.first_name, &body.last_name, &body.email],
+    ) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: e.to_string(),
+        });
+    }
+
     let new_obj = resident_model::ResidentModel {
         id: resident_model::ResidentModel::new_id_user(conn),
         first_name: body.first_name,
         last_name: body.last_name,
-        unit_number: body.unit_number,
         address: body.address,
         phone: body.phone,
         email: body.email,
@@ -93,6 +103,8 @@ pub async fn new_resident(
         is_active: body.is_active,
         created_at: chrono::Utc::now().naive_utc(),
         updated_at: chrono::Utc::now().naive_utc(),
+        moved_in_at: Some(chrono::Utc::now().date_naive()),
+        moved_out_at: None,
     };
 
     match new_obj.db_insert(conn) {
@@ -105,6 +117,10 @@ pub async fn new_resident(
         }
     };
 
+    if let Err(e) = resident_model::ResidentChecklistItemModel::db_seed_move_in(conn, new_obj.id) {
+        log::error!("Error creating resident move-in checklist: {}", e);
+    }
+
     let hashed_password = match hash_password(body.password) {
         Ok(passwd) => passwd,
         Err(e) => {
@@ -125,6 +141,7 @@ pub async fn new_resident(
         password: hashed_password,
         created_at: chrono::Utc::now().naive_utc(),
         updated_at: chrono::Utc::now().naive_utc(),
+        service_account_id: None,
     };
 
     match new_obj_user.db_insert(conn) {
@@ -192,7 +209,7 @@ pub async fn update_resident(
     let conn = &mut establish_connection_pg(&conf);
     let body = body.into_inner();
 
-    match authenticate_user(req.clone(), conn, conf) {
+    match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => match role.role {
             UserRoles::Root => {}
             UserRoles::Admin => {
@@ -272,14 +289,15 @@ pub async fn update_resident(
         id: curr_obj.id,
         first_name: body.first_name,
         last_name: body.last_name,
-        unit_number: body.unit_number,
         address: body.address,
         phone: body.phone,
         email: body.email,
         date_of_birth: body.date_of_birth,
-        is_active: body.is_active,
+        is_active: curr_obj.is_active,
         created_at: curr_obj.created_at,
         updated_at: chrono::Utc::now().naive_utc(),
+        moved_in_at: curr_obj.moved_in_at,
+        moved_out_at: curr_obj.moved_out_at,
     };
 
     match new_obj.db_update(conn) {
@@ -370,7 +388,7 @@ pub async fn delete_resident(
         }
     };
 
-    match authenticate_user(req.clone(), conn, conf) {
+    match authenticate_user(req.clone(), conn) {
         Ok((role, _claims, _token)) => {
             if role.role != UserRoles::Root {
                 if res_user_role.community_id.is_none() || role.community_id.is_none() {
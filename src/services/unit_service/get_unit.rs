@@ -0,0 +1,148 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "Unit",
+    path = "/list",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number for pagination (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Number of items per page for pagination (default: 10)"),
+        ("block" = Option<String>, Query, description = "Only return Units of this block"),
+    ),
+    responses(
+        (status = 200, description = "Got Units successfully", body = UnitListHttpResponse, headers(
+            ("X-Total-Pages" = i64, description = "Total number of pages"),
+            ("X-Remaining-Pages" = i64, description = "Remaining number of pages")
+        )),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_units(
+    query: web::Query<PaginationParams>,
+    filters: web::Query<unit_model::UnitFilterParams>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let page = query.page.unwrap_or(1);
+    let per_page = query.per_page.unwrap_or(10);
+    let offset = (page - 1) * per_page;
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let total_items =
+        match unit_model::UnitModel::db_count_all_matching(role.clone(), conn, &filters) {
+            Ok(res) => res,
+            Err(e) => {
+                log::error!("Error getting Units: {}", e);
+                return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Error getting Units".to_string(),
+                });
+            }
+        };
+
+    match unit_model::UnitModel::db_read_all_matching_by_range(
+        role, conn, &filters, per_page, offset,
+    ) {
+        Ok(res) => {
+            let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+            let remaining_pages = total_pages - page;
+
+            HttpResponse::Ok()
+                .insert_header((
+                    header::HeaderName::from_static("x-total-pages"),
+                    total_pages.to_string(),
+                ))
+                .insert_header((
+                    header::HeaderName::from_static("x-remaining-pages"),
+                    remaining_pages.to_string(),
+                ))
+                .json(HttpResponseObject {
+                    error: false,
+                    message: "Got Units successfully".to_string(),
+                    object: Some(res),
+                })
+        }
+        Err(e) => {
+            log::error!("Error getting Units: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Units: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    tag = "Unit",
+    path = "/get/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unit ID"),
+    ),
+    responses(
+        (status = 200, description = "Got Unit successfully", body = UnitGetHttpResponse),
+        (status = 400, description = "Invalid Unit ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_unit_by_id(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Unit ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match unit_model::UnitModel::db_read_by_id_matching_community(role, conn, id) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Unit successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Unit: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Unit: {}", e),
+            })
+        }
+    }
+}
@@ -0,0 +1,32 @@
+pub mod get_unit;
+pub mod unit_resident;
+pub mod upsert_unit;
+
+use super::prelude::*;
+type UnitListHttpResponse = HttpResponseObject<Vec<unit_model::UnitModel>>;
+type UnitGetHttpResponse = HttpResponseObject<unit_model::UnitModel>;
+type UnitResidentListHttpResponse = HttpResponseObject<Vec<unit_model::UnitResidentModel>>;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_unit::get_units,
+        get_unit::get_unit_by_id,
+        upsert_unit::new_unit,
+        upsert_unit::update_unit,
+        upsert_unit::delete_unit,
+        unit_resident::get_unit_residents,
+        unit_resident::new_unit_resident,
+        unit_resident::update_unit_resident,
+        unit_resident::delete_unit_resident,
+    ),
+    components(schemas(
+        unit_model::UnitModel,
+        unit_model::UnitModelNew,
+        unit_model::UnitResidentModel,
+        unit_model::UnitResidentModelNew,
+        unit_model::UnitResidentModelEdit,
+        unit_model::UnitRelation
+    ))
+)]
+pub struct UnitApi;
@@ -0,0 +1,389 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "Unit",
+    path = "/resident/list/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unit ID"),
+    ),
+    responses(
+        (status = 200, description = "Got Unit residents successfully", body = UnitResidentListHttpResponse),
+        (status = 400, description = "Invalid Unit ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_unit_residents(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Unit ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if unit_model::UnitModel::db_read_by_id_matching_community(role, conn, id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match unit_model::UnitResidentModel::db_read_all_by_unit(conn, id) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Unit residents successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Unit residents: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Unit residents: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Unit",
+    path = "/resident/new/{id}",
+    request_body = unit_model::UnitResidentModelNew,
+    params(
+        ("id" = Uuid, Path, description = "Unit ID"),
+    ),
+    responses (
+        (status = 200, description = "Resident linked to Unit successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Error linking Resident to Unit", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error linking Resident to Unit", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_unit_resident(
+    id: web::Path<String>,
+    body: web::Json<unit_model::UnitResidentModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Unit ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let unit = match unit_model::UnitModel::db_read_by_id_matching_community(role, conn, id) {
+        Ok(unit) => unit,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let resident = match resident_model::ResidentModel::db_get_user(conn, body.resident_id) {
+        Ok(resident) => resident,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid Resident".to_string(),
+            });
+        }
+    };
+
+    if resident.role.community_id != Some(unit.community_id) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Resident not in the same community".to_string(),
+        });
+    }
+
+    if matches!(
+        unit_model::UnitResidentModel::db_is_linked(conn, unit.id, body.resident_id),
+        Ok(true)
+    ) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Resident already linked to this Unit".to_string(),
+        });
+    }
+
+    let new_obj = unit_model::UnitResidentModel {
+        id: unit_model::UnitResidentModel::new_id(conn),
+        unit_id: unit.id,
+        resident_id: body.resident_id,
+        relation: body.relation,
+        created_at: chrono::Utc::now().naive_utc(),
+        start_date: body
+            .start_date
+            .unwrap_or_else(|| chrono::Utc::now().date_naive()),
+        end_date: body.end_date,
+        voting_proxy: body.voting_proxy,
+    };
+
+    if !new_obj.is_valid() {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid link period or voting proxy".to_string(),
+        });
+    }
+
+    match new_obj.db_insert(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+            error: false,
+            message: "Resident linked to Unit successfully".to_string(),
+            entity_id: Some(new_obj.id),
+        }),
+        Err(e) => {
+            log::error!("Error linking Resident to Unit: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error linking Resident to Unit: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    tag = "Unit",
+    path = "/resident/update/{id}",
+    request_body = unit_model::UnitResidentModelEdit,
+    params(
+        ("id" = Uuid, Path, description = "Unit resident link ID"),
+    ),
+    responses (
+        (status = 200, description = "Unit resident link updated successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Error updating Unit resident link", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error updating Unit resident link", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn update_unit_resident(
+    id: web::Path<String>,
+    body: web::Json<unit_model::UnitResidentModelEdit>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Unit resident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let mut link = match unit_model::UnitResidentModel::db_read_by_id(conn, id) {
+        Ok(link) => link,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if unit_model::UnitModel::db_read_by_id_matching_community(role, conn, link.unit_id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    if let Some(end_date) = body.end_date {
+        link.end_date = end_date;
+    }
+    if let Some(voting_proxy) = body.voting_proxy {
+        link.voting_proxy = voting_proxy;
+    }
+
+    if !link.is_valid() {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid link period or voting proxy".to_string(),
+        });
+    }
+
+    match link.db_update(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+            error: false,
+            message: "Unit resident link updated successfully".to_string(),
+            entity_id: Some(link.id),
+        }),
+        Err(e) => {
+            log::error!("Error updating Unit resident link: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error updating Unit resident link: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Unit",
+    path = "/resident/delete/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unit resident link ID"),
+    ),
+    responses (
+        (status = 200, description = "Resident unlinked from Unit successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid Unit resident ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error unlinking Resident from Unit", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn delete_unit_resident(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Unit resident ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let link = match unit_model::UnitResidentModel::db_read_by_id(conn, id) {
+        Ok(link) => link,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if unit_model::UnitModel::db_read_by_id_matching_community(role, conn, link.unit_id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match unit_model::UnitResidentModel::db_delete_by_id(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Resident unlinked from Unit successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error unlinking Resident from Unit: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error unlinking Resident from Unit: {}", e),
+            })
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use super::*;
+
+#[utoipa::path(
+    post,
+    tag = "Unit",
+    path = "/new",
+    request_body = unit_model::UnitModelNew,
+    responses (
+        (status = 200, description = "Unit added successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Error adding Unit", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error adding Unit", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_unit(
+    body: web::Json<unit_model::UnitModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if !(role.role == UserRoles::Root
+        || (role.role == UserRoles::Admin && role.community_id == Some(body.community_id)))
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    if !unit_model::UnitModel::has_valid_fraction(&body.ideal_fraction) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Ideal fraction must be greater than 0 and at most 1".to_string(),
+        });
+    }
+
+    let new_obj = unit_model::UnitModel {
+        id: unit_model::UnitModel::new_id(conn),
+        community_id: body.community_id,
+        block: body.block,
+        number: body.number,
+        ideal_fraction: body.ideal_fraction,
+        created_at: chrono::Utc::now().naive_utc(),
+        updated_at: chrono::Utc::now().naive_utc(),
+    };
+
+    match new_obj.db_insert(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+            error: false,
+            message: "Unit created successfully".to_string(),
+            entity_id: Some(new_obj.id),
+        }),
+        Err(e) => {
+            log::error!("Error creating Unit: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error creating Unit: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    tag = "Unit",
+    path = "/update/{id}",
+    request_body = unit_model::UnitModelNew,
+    params(
+        ("id" = Uuid, Path, description = "Unit ID"),
+    ),
+    responses (
+        (status = 200, description = "Unit updated successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Error updating Unit", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn update_unit(
+    id: web::Path<String>,
+    body: web::Json<unit_model::UnitModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Unit ID format".to_string(),
+            });
+        }
+    };
+
+    let curr_obj =
+        match unit_model::UnitModel::db_read_by_id_matching_community(role.clone(), conn, id) {
+            Ok(res) => res,
+            Err(e) => {
+                log::error!("Error reading Unit: {}", e);
+                return HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                    error: true,
+                    message: "Error updating Unit".to_string(),
+                });
+            }
+        };
+
+    if !(role.role == UserRoles::Root || curr_obj.community_id == body.community_id) {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    if !unit_model::UnitModel::has_valid_fraction(&body.ideal_fraction) {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Ideal fraction must be greater than 0 and at most 1".to_string(),
+        });
+    }
+
+    let new_obj = unit_model::UnitModel {
+        id: curr_obj.id,
+        community_id: body.community_id,
+        block: body.block,
+        number: body.number,
+        ideal_fraction: body.ideal_fraction,
+        created_at: curr_obj.created_at,
+        updated_at: chrono::Utc::now().naive_utc(),
+    };
+
+    match new_obj.db_update(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Unit updated successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error updating Unit: {}", e);
+            HttpResponse::Ok().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error updating Unit: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Unit",
+    path = "/delete/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Unit ID"),
+    ),
+    responses (
+        (status = 200, description = "Unit deleted successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid Unit ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error deleting Unit", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn delete_unit(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Unit ID format".to_string(),
+            });
+        }
+    };
+
+    if unit_model::UnitModel::db_read_by_id_matching_community(role, conn, id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match unit_model::UnitModel::db_delete_by_id(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Unit deleted successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error deleting Unit: {}", e);
+            HttpResponse::Ok().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error deleting Unit: {}", e),
+            })
+        }
+    }
+}
@@ -32,7 +32,7 @@ pub async fn get_vehicles(
 
     let conn = &mut establish_connection_pg(&conf);
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -142,7 +142,7 @@ pub async fn get_vehicle_by_id(
         }
     };
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -167,3 +167,71 @@ pub async fn get_vehicle_by_id(
         }
     }
 }
+
+#[utoipa::path(
+    get,
+    tag = "Vehicle",
+    path = "/access/{plate}",
+    params(
+        ("plate" = String, Path, description = "License plate, old or Mercosul format"),
+    ),
+    responses(
+        (status = 200, description = "Checked Vehicle access successfully", body = VehicleAccessHttpResponse),
+        (status = 400, description = "Invalid license plate", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_vehicle_access(
+    plate: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let plate = plate.into_inner();
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if normalize_plate(&plate).is_none() {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid license plate".to_string(),
+        });
+    }
+
+    match vehicle_model::VehicleModel::db_access_lookup(role, conn, plate) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Checked Vehicle access successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error checking Vehicle access: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error checking Vehicle access: {}", e),
+            })
+        }
+    }
+}
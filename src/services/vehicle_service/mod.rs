@@ -1,19 +1,33 @@
 pub mod get_vehicle;
+pub mod parking_spot;
 pub mod upsert_vehicle;
 
 use super::prelude::*;
 type VehicleListHttpResponse = HttpResponseObject<Vec<vehicle_model::VehicleModel>>;
 type VehicleGetHttpResponse = HttpResponseObject<vehicle_model::VehicleModel>;
+type VehicleAccessHttpResponse = HttpResponseObject<vehicle_model::VehicleAccessResult>;
+type ParkingSpotListHttpResponse = HttpResponseObject<Vec<parking_spot_model::ParkingSpotModel>>;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         get_vehicle::get_vehicles,
         get_vehicle::get_vehicle_by_id,
+        get_vehicle::get_vehicle_access,
         upsert_vehicle::new_vehicle,
         upsert_vehicle::update_vehicle,
         upsert_vehicle::delete_vehicle,
+        parking_spot::get_parking_spots,
+        parking_spot::new_parking_spot,
+        parking_spot::update_parking_spot,
+        parking_spot::delete_parking_spot,
     ),
-    components(schemas(vehicle_model::VehicleModel, vehicle_model::VehicleModelNew))
+    components(schemas(
+        vehicle_model::VehicleModel,
+        vehicle_model::VehicleModelNew,
+        vehicle_model::VehicleAccessResult,
+        parking_spot_model::ParkingSpotModel,
+        parking_spot_model::ParkingSpotModelNew,
+    ))
 )]
 pub struct VehicleApi;
@@ -0,0 +1,321 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "Vehicle",
+    path = "/parking_spot/list",
+    responses(
+        (status = 200, description = "Got Parking Spots successfully", body = ParkingSpotListHttpResponse),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_parking_spots(
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match parking_spot_model::ParkingSpotModel::db_read_all_matching_community(role, conn) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Parking Spots successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Parking Spots: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Parking Spots: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    tag = "Vehicle",
+    path = "/parking_spot/new",
+    request_body = parking_spot_model::ParkingSpotModelNew,
+    responses (
+        (status = 200, description = "Parking Spot added successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Error adding Parking Spot", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error adding Parking Spot", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_parking_spot(
+    body: web::Json<parking_spot_model::ParkingSpotModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if !(role.role == UserRoles::Root
+        || (role.role == UserRoles::Admin && role.community_id == Some(body.community_id)))
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    if let Some(unit_id) = body.unit_id
+        && !matches!(unit_model::UnitModel::db_read_by_id(conn, unit_id), Ok(unit) if unit.community_id == body.community_id)
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid Unit".to_string(),
+        });
+    }
+
+    let new_obj = parking_spot_model::ParkingSpotModel {
+        id: parking_spot_model::ParkingSpotModel::new_id(conn),
+        community_id: body.community_id,
+        code: body.code,
+        notes: body.notes,
+        created_at: chrono::Utc::now().naive_utc(),
+        updated_at: chrono::Utc::now().naive_utc(),
+        unit_id: body.unit_id,
+    };
+
+    match new_obj.db_insert(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+            error: false,
+            message: "Parking Spot created successfully".to_string(),
+            entity_id: Some(new_obj.id),
+        }),
+        Err(e) => {
+            log::error!("Error creating Parking Spot: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error creating Parking Spot: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    tag = "Vehicle",
+    path = "/parking_spot/update/{id}",
+    request_body = parking_spot_model::ParkingSpotModelNew,
+    params(
+        ("id" = Uuid, Path, description = "Parking Spot ID"),
+    ),
+    responses (
+        (status = 200, description = "Parking Spot updated successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Error updating Parking Spot", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn update_parking_spot(
+    id: web::Path<String>,
+    body: web::Json<parking_spot_model::ParkingSpotModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Parking Spot ID format".to_string(),
+            });
+        }
+    };
+
+    let curr_obj = match parking_spot_model::ParkingSpotModel::db_read_by_id_matching_community(
+        role.clone(),
+        conn,
+        id,
+    ) {
+        Ok(res) => res,
+        Err(e) => {
+            log::error!("Error updating Parking Spot: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Error updating Parking Spot".to_string(),
+            });
+        }
+    };
+
+    if !(role.role == UserRoles::Root || curr_obj.community_id == body.community_id) {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    if let Some(unit_id) = body.unit_id
+        && !matches!(unit_model::UnitModel::db_read_by_id(conn, unit_id), Ok(unit) if unit.community_id == body.community_id)
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid Unit".to_string(),
+        });
+    }
+
+    let new_obj = parking_spot_model::ParkingSpotModel {
+        id: curr_obj.id,
+        community_id: body.community_id,
+        code: body.code,
+        notes: body.notes,
+        created_at: curr_obj.created_at,
+        updated_at: chrono::Utc::now().naive_utc(),
+        unit_id: body.unit_id,
+    };
+
+    match new_obj.db_update(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Parking Spot updated successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error updating Parking Spot: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error updating Parking Spot: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Vehicle",
+    path = "/parking_spot/delete/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Parking Spot ID"),
+    ),
+    responses (
+        (status = 200, description = "Parking Spot deleted successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid Parking Spot ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error deleting Parking Spot", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn delete_parking_spot(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Parking Spot ID format".to_string(),
+            });
+        }
+    };
+
+    if parking_spot_model::ParkingSpotModel::db_read_by_id_matching_community(role, conn, id)
+        .is_err()
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match parking_spot_model::ParkingSpotModel::db_delete_by_id(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Parking Spot deleted successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error deleting Parking Spot: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error deleting Parking Spot: {}", e),
+            })
+        }
+    }
+}
@@ -21,9 +21,9 @@ pub async fn new_vehicle(
     conf: web::Data<Arc<MyCondominiumConfig>>,
 ) -> HttpResponse {
     let conn = &mut establish_connection_pg(&conf);
-    let body = body.into_inner();
+    let mut body = body.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -37,6 +37,16 @@ pub async fn new_vehicle(
         return HttpResponse::BadRequest().json(validation_errors);
     }
 
+    body.license_plate = match normalize_plate(&body.license_plate) {
+        Some(plate) => plate,
+        None => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid license plate".to_string(),
+            });
+        }
+    };
+
     // Check if the resident belongs to the same community as the admin
     if role.role == UserRoles::Admin {
         // Fetch the resident's role and community ID
@@ -72,6 +82,22 @@ pub async fn new_vehicle(
         });
     }
 
+    if let Some(parking_spot_id) = body.parking_spot_id
+        && !matches!(
+            vehicle_model::VehicleModel::db_check_parking_spot(
+                conn,
+                body.resident_id,
+                parking_spot_id
+            ),
+            Ok(true)
+        )
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Parking spot does not belong to the resident's unit".to_string(),
+        });
+    }
+
     let new_vehicle = vehicle_model::VehicleModel {
         id: Uuid::new_v4(),
         resident_id: body.resident_id,
@@ -80,6 +106,8 @@ pub async fn new_vehicle(
         color: body.color,
         created_at: chrono::Utc::now().naive_utc(),
         updated_at: chrono::Utc::now().naive_utc(),
+        parking_spot_id: body.parking_spot_id,
+        authorized: body.authorized.unwrap_or(true),
     };
 
     match new_vehicle.db_insert(conn) {
@@ -124,9 +152,9 @@ pub async fn update_vehicle(
 ) -> HttpResponse {
     let conn = &mut establish_connection_pg(&conf);
     let id = id.into_inner();
-    let body = body.into_inner();
+    let mut body = body.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
@@ -140,6 +168,16 @@ pub async fn update_vehicle(
         return HttpResponse::BadRequest().json(validation_errors);
     }
 
+    body.license_plate = match normalize_plate(&body.license_plate) {
+        Some(plate) => plate,
+        None => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid license plate".to_string(),
+            });
+        }
+    };
+
     let id = match Uuid::parse_str(&id) {
         Ok(uuid) => uuid,
         Err(_) => {
@@ -186,6 +224,22 @@ pub async fn update_vehicle(
         });
     }
 
+    if let Some(parking_spot_id) = body.parking_spot_id
+        && !matches!(
+            vehicle_model::VehicleModel::db_check_parking_spot(
+                conn,
+                body.resident_id,
+                parking_spot_id
+            ),
+            Ok(true)
+        )
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Parking spot does not belong to the resident's unit".to_string(),
+        });
+    }
+
     let existing_vehicle = match vehicle_model::VehicleModel::db_read_by_id(conn, id) {
         Ok(vehicle) => vehicle,
         Err(e) => {
@@ -205,8 +259,21 @@ pub async fn update_vehicle(
         color: body.color,
         created_at: existing_vehicle.created_at,
         updated_at: chrono::Utc::now().naive_utc(),
+        parking_spot_id: body.parking_spot_id,
+        authorized: body.authorized.unwrap_or(existing_vehicle.authorized),
     };
 
+    if updated_vehicle.parking_spot_id.is_none()
+        && existing_vehicle.parking_spot_id.is_some()
+        && let Err(e) = updated_vehicle.db_release_parking_spot(conn)
+    {
+        log::error!("Error releasing parking spot: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: format!("Error releasing parking spot: {}", e),
+        });
+    }
+
     match updated_vehicle.db_update(conn) {
         Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
             error: false,
@@ -247,7 +314,7 @@ pub async fn delete_vehicle(
     let conn = &mut establish_connection_pg(&conf);
     let id = id.into_inner();
 
-    let (role, _claims, _token) = match authenticate_user(req.clone(), conn, conf) {
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
         Ok((role, claims, token)) => (role, claims, token),
         Err(_) => {
             return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
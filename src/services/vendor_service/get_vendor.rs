@@ -0,0 +1,163 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "Vendor",
+    path = "/list",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number for pagination (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Number of items per page for pagination (default: 10)"),
+        ("service_type" = Option<String>, Query, description = "Only return Vendors offering this service"),
+        ("expiring_within_days" = Option<i64>, Query, description = "Only return Vendors whose contract or insurance expires within this many days"),
+    ),
+    responses(
+        (status = 200, description = "Got Vendors successfully", body = VendorListHttpResponse, headers(
+            ("X-Total-Pages" = i64, description = "Total number of pages"),
+            ("X-Remaining-Pages" = i64, description = "Remaining number of pages")
+        )),
+        (status = 400, description = "Invalid filters", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_vendors(
+    query: web::Query<PaginationParams>,
+    filters: web::Query<vendor_model::VendorFilterParams>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let page = query.page.unwrap_or(1);
+    let per_page = query.per_page.unwrap_or(10);
+    let offset = (page - 1) * per_page;
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if let Err(validation_errors) = filters.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let total_items =
+        match vendor_model::VendorModel::db_count_all_matching(role.clone(), conn, &filters) {
+            Ok(res) => res,
+            Err(e) => {
+                log::error!("Error getting Vendors: {}", e);
+                return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Error getting Vendors".to_string(),
+                });
+            }
+        };
+
+    match vendor_model::VendorModel::db_read_all_matching_by_range(
+        role, conn, &filters, per_page, offset,
+    ) {
+        Ok(res) => {
+            let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+            let remaining_pages = total_pages - page;
+
+            HttpResponse::Ok()
+                .insert_header((
+                    header::HeaderName::from_static("x-total-pages"),
+                    total_pages.to_string(),
+                ))
+                .insert_header((
+                    header::HeaderName::from_static("x-remaining-pages"),
+                    remaining_pages.to_string(),
+                ))
+                .json(HttpResponseObject {
+                    error: false,
+                    message: "Got Vendors successfully".to_string(),
+                    object: Some(res),
+                })
+        }
+        Err(e) => {
+            log::error!("Error getting Vendors: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Vendors: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    tag = "Vendor",
+    path = "/get/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Vendor ID"),
+    ),
+    responses(
+        (status = 200, description = "Got Vendor successfully", body = VendorGetHttpResponse),
+        (status = 400, description = "Invalid Vendor ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_vendor_by_id(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Vendor ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match vendor_model::VendorModel::db_read_by_id_matching_community(role, conn, id) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Vendor successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Vendor: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Vendor: {}", e),
+            })
+        }
+    }
+}
@@ -0,0 +1,20 @@
+pub mod get_vendor;
+pub mod upsert_vendor;
+
+use super::prelude::*;
+
+type VendorListHttpResponse = HttpResponseObject<Vec<vendor_model::VendorModel>>;
+type VendorGetHttpResponse = HttpResponseObject<vendor_model::VendorModel>;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_vendor::get_vendors,
+        get_vendor::get_vendor_by_id,
+        upsert_vendor::new_vendor,
+        upsert_vendor::update_vendor,
+        upsert_vendor::delete_vendor,
+    ),
+    components(schemas(vendor_model::VendorModel, vendor_model::VendorModelNew))
+)]
+pub struct VendorApi;
@@ -0,0 +1,247 @@
+use super::*;
+
+#[utoipa::path(
+    post,
+    tag = "Vendor",
+    path = "/new",
+    request_body = vendor_model::VendorModelNew,
+    responses (
+        (status = 200, description = "Vendor added successfully", body = HttpResponseObjectEmptyEntity),
+        (status = 400, description = "Error adding Vendor", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error adding Vendor", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_vendor(
+    body: web::Json<vendor_model::VendorModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if !(role.role == UserRoles::Root
+        || (role.role == UserRoles::Admin && role.community_id == Some(body.community_id)))
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let new_obj = vendor_model::VendorModel {
+        id: vendor_model::VendorModel::new_id(conn),
+        community_id: body.community_id,
+        company_name: body.company_name,
+        contact_name: body.contact_name,
+        email: body.email,
+        phone: body.phone,
+        service_type: body.service_type,
+        contract_start_date: body.contract_start_date,
+        contract_end_date: body.contract_end_date,
+        insurance_expiry_date: body.insurance_expiry_date,
+        notes: body.notes,
+        created_at: chrono::Utc::now().naive_utc(),
+        updated_at: chrono::Utc::now().naive_utc(),
+    };
+
+    match new_obj.db_insert(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmptyEntity {
+            error: false,
+            message: "Vendor created successfully".to_string(),
+            entity_id: Some(new_obj.id),
+        }),
+        Err(e) => {
+            log::error!("Error creating Vendor: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error creating Vendor: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    tag = "Vendor",
+    path = "/update/{id}",
+    request_body = vendor_model::VendorModelNew,
+    params(
+        ("id" = Uuid, Path, description = "Vendor ID"),
+    ),
+    responses (
+        (status = 200, description = "Vendor updated successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Error updating Vendor", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn update_vendor(
+    id: web::Path<String>,
+    body: web::Json<vendor_model::VendorModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Vendor ID format".to_string(),
+            });
+        }
+    };
+
+    let curr_obj =
+        match vendor_model::VendorModel::db_read_by_id_matching_community(role.clone(), conn, id) {
+            Ok(res) => res,
+            Err(e) => {
+                log::error!("Error reading Vendor: {}", e);
+                return HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                    error: true,
+                    message: "Error updating Vendor".to_string(),
+                });
+            }
+        };
+
+    if !(role.role == UserRoles::Root || curr_obj.community_id == body.community_id) {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let new_obj = vendor_model::VendorModel {
+        id: curr_obj.id,
+        community_id: body.community_id,
+        company_name: body.company_name,
+        contact_name: body.contact_name,
+        email: body.email,
+        phone: body.phone,
+        service_type: body.service_type,
+        contract_start_date: body.contract_start_date,
+        contract_end_date: body.contract_end_date,
+        insurance_expiry_date: body.insurance_expiry_date,
+        notes: body.notes,
+        created_at: curr_obj.created_at,
+        updated_at: chrono::Utc::now().naive_utc(),
+    };
+
+    match new_obj.db_update(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Vendor updated successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error updating Vendor: {}", e);
+            HttpResponse::Ok().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error updating Vendor: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Vendor",
+    path = "/delete/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Vendor ID"),
+    ),
+    responses (
+        (status = 200, description = "Vendor deleted successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid Vendor ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error deleting Vendor", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn delete_vendor(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Vendor ID format".to_string(),
+            });
+        }
+    };
+
+    if vendor_model::VendorModel::db_read_by_id_matching_community(role, conn, id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match vendor_model::VendorModel::db_delete_by_id(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Vendor deleted successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error deleting Vendor: {}", e);
+            HttpResponse::Ok().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error deleting Vendor: {}", e),
+            })
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use super::*;
+
+#[utoipa::path(
+    get,
+    tag = "Visitor",
+    path = "/list",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number for pagination (default: 1)"),
+        ("per_page" = Option<i64>, Query, description = "Number of items per page for pagination (default: 10)"),
+        ("status" = Option<visitor_model::VisitorStatus>, Query, description = "Only return Visitors with this status"),
+        ("date" = Option<NaiveDate>, Query, description = "Only return Visitors expected on this day"),
+    ),
+    responses(
+        (status = 200, description = "Got Visitors successfully", body = VisitorListHttpResponse, headers(
+            ("X-Total-Pages" = i64, description = "Total number of pages"),
+            ("X-Remaining-Pages" = i64, description = "Remaining number of pages")
+        )),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_visitors(
+    query: web::Query<PaginationParams>,
+    filters: web::Query<visitor_model::VisitorFilterParams>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let page = query.page.unwrap_or(1);
+    let per_page = query.per_page.unwrap_or(10);
+    let offset = (page - 1) * per_page;
+
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let total_items =
+        match visitor_model::VisitorModel::db_count_all_matching(role.clone(), conn, &filters) {
+            Ok(res) => res,
+            Err(e) => {
+                log::error!("Error getting Visitors: {}", e);
+                return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Error getting Visitors".to_string(),
+                });
+            }
+        };
+
+    match visitor_model::VisitorModel::db_read_all_matching_by_range(
+        role, conn, &filters, per_page, offset,
+    ) {
+        Ok(res) => {
+            let total_pages = (total_items as f64 / per_page as f64).ceil() as i64;
+            let remaining_pages = total_pages - page;
+
+            HttpResponse::Ok()
+                .insert_header((
+                    header::HeaderName::from_static("x-total-pages"),
+                    total_pages.to_string(),
+                ))
+                .insert_header((
+                    header::HeaderName::from_static("x-remaining-pages"),
+                    remaining_pages.to_string(),
+                ))
+                .json(HttpResponseObject {
+                    error: false,
+                    message: "Got Visitors successfully".to_string(),
+                    object: Some(res),
+                })
+        }
+        Err(e) => {
+            log::error!("Error getting Visitors: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Visitors: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    tag = "Visitor",
+    path = "/get/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Visitor ID"),
+    ),
+    responses(
+        (status = 200, description = "Got Visitor successfully", body = VisitorGetHttpResponse),
+        (status = 400, description = "Invalid Visitor ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Internal server error", body = HttpResponseObjectEmptyError)
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn get_visitor_by_id(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Visitor ID format".to_string(),
+            });
+        }
+    };
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    match visitor_model::VisitorModel::db_read_by_id_matching_resident(role, conn, id) {
+        Ok(res) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Got Visitor successfully".to_string(),
+            object: Some(res),
+        }),
+        Err(e) => {
+            log::error!("Error getting Visitor: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error getting Visitor: {}", e),
+            })
+        }
+    }
+}
@@ -0,0 +1,27 @@
+pub mod get_visitor;
+pub mod upsert_visitor;
+pub mod visitor_desk;
+
+use super::prelude::*;
+type VisitorListHttpResponse = HttpResponseObject<Vec<visitor_model::VisitorModel>>;
+type VisitorGetHttpResponse = HttpResponseObject<visitor_model::VisitorModel>;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_visitor::get_visitors,
+        get_visitor::get_visitor_by_id,
+        upsert_visitor::new_visitor,
+        upsert_visitor::cancel_visitor,
+        upsert_visitor::delete_visitor,
+        visitor_desk::check_in_visitor,
+        visitor_desk::check_out_visitor,
+    ),
+    components(schemas(
+        visitor_model::VisitorModel,
+        visitor_model::VisitorModelNew,
+        visitor_model::VisitorCheckInModel,
+        visitor_model::VisitorStatus
+    ))
+)]
+pub struct VisitorApi;
@@ -0,0 +1,302 @@
+use super::*;
+
+#[utoipa::path(
+    post,
+    tag = "Visitor",
+    path = "/new",
+    request_body = visitor_model::VisitorModelNew,
+    responses (
+        (status = 200, description = "Visitor registered successfully", body = VisitorGetHttpResponse),
+        (status = 400, description = "Error registering Visitor", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error registering Visitor", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn new_visitor(
+    body: web::Json<visitor_model::VisitorModelNew>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    // Residents always register their own visitors, the administration picks the resident.
+    let resident_id = match role.role {
+        UserRoles::Resident => match UserModel::db_read_by_id(conn, role.user_id) {
+            Ok(user) => user.entity_id,
+            Err(_) => {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        },
+        UserRoles::Admin | UserRoles::Root => match body.resident_id {
+            Some(resident_id) => resident_id,
+            None => {
+                return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Resident ID is required".to_string(),
+                });
+            }
+        },
+    };
+
+    let resident = match resident_model::ResidentModel::db_get_user(conn, resident_id) {
+        Ok(resident) => resident,
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if !(role.role == UserRoles::Root
+        || (role.role == UserRoles::Admin && role.community_id == resident.role.community_id)
+        || (role.role == UserRoles::Resident && role.user_id == resident.user.id))
+    {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    if body.expected_until <= body.expected_from
+        || body.expected_until <= chrono::Utc::now().naive_utc()
+    {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Invalid arrival window".to_string(),
+        });
+    }
+
+    let license_plate = match body.license_plate {
+        Some(plate) => match normalize_plate(&plate) {
+            Some(plate) => Some(plate),
+            None => {
+                return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Invalid license plate".to_string(),
+                });
+            }
+        },
+        None => None,
+    };
+
+    let access_code = match visitor_model::VisitorModel::db_new_access_code(conn) {
+        Ok(code) => code,
+        Err(e) => {
+            log::error!("Error generating Visitor access code: {}", e);
+            return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error generating Visitor access code: {}", e),
+            });
+        }
+    };
+
+    let new_obj = visitor_model::VisitorModel {
+        id: visitor_model::VisitorModel::new_id(conn),
+        resident_id,
+        name: body.name,
+        document: body.document,
+        license_plate,
+        expected_from: body.expected_from,
+        expected_until: body.expected_until,
+        access_code,
+        status: visitor_model::VisitorStatus::Expected,
+        checked_in_at: None,
+        checked_in_by: None,
+        checked_out_at: None,
+        created_at: chrono::Utc::now().naive_utc(),
+        updated_at: chrono::Utc::now().naive_utc(),
+    };
+
+    match new_obj.db_insert(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObject {
+            error: false,
+            message: "Visitor registered successfully".to_string(),
+            object: Some(new_obj),
+        }),
+        Err(e) => {
+            log::error!("Error registering Visitor: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error registering Visitor: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    put,
+    tag = "Visitor",
+    path = "/cancel/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Visitor ID"),
+    ),
+    responses (
+        (status = 200, description = "Visitor cancelled successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Visitor is not expected", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error cancelling Visitor", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn cancel_visitor(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => (role, claims, token),
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Visitor ID format".to_string(),
+            });
+        }
+    };
+
+    let mut visitor =
+        match visitor_model::VisitorModel::db_read_by_id_matching_resident(role, conn, id) {
+            Ok(visitor) => visitor,
+            Err(_) => {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        };
+
+    if visitor.status != visitor_model::VisitorStatus::Expected {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Visitor is not expected".to_string(),
+        });
+    }
+
+    visitor.status = visitor_model::VisitorStatus::Cancelled;
+    visitor.updated_at = chrono::Utc::now().naive_utc();
+
+    match visitor.db_update(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Visitor cancelled successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error cancelling Visitor: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error cancelling Visitor: {}", e),
+            })
+        }
+    }
+}
+
+#[utoipa::path(
+    delete,
+    tag = "Visitor",
+    path = "/delete/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Visitor ID"),
+    ),
+    responses (
+        (status = 200, description = "Visitor deleted successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Invalid Visitor ID format", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error deleting Visitor", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn delete_visitor(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Visitor ID format".to_string(),
+            });
+        }
+    };
+
+    if visitor_model::VisitorModel::db_read_by_id_matching_resident(role, conn, id).is_err() {
+        return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Unauthorized".to_string(),
+        });
+    }
+
+    match visitor_model::VisitorModel::db_delete_by_id(conn, id) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Visitor deleted successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error deleting Visitor: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmpty {
+                error: true,
+                message: format!("Error deleting Visitor: {}", e),
+            })
+        }
+    }
+}
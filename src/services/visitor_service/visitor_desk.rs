@@ -0,0 +1,210 @@
+use super::*;
+
+#[utoipa::path(
+    post,
+    tag = "Visitor",
+    path = "/check_in",
+    request_body = visitor_model::VisitorCheckInModel,
+    responses (
+        (status = 200, description = "Visitor checked in successfully", body = VisitorGetHttpResponse),
+        (status = 400, description = "Invalid or expired access code", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error checking in Visitor", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn check_in_visitor(
+    body: web::Json<visitor_model::VisitorCheckInModel>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let body = body.into_inner();
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    if let Err(validation_errors) = body.validate() {
+        return HttpResponse::BadRequest().json(validation_errors);
+    }
+
+    let mut visitor = match visitor_model::VisitorModel::db_read_by_access_code(
+        role.clone(),
+        conn,
+        &body.access_code,
+    ) {
+        Ok(visitor) => visitor,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Invalid access code".to_string(),
+            });
+        }
+    };
+
+    let now = chrono::Utc::now().naive_utc();
+
+    if now < visitor.expected_from {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Visitor is not expected yet".to_string(),
+        });
+    }
+
+    if now > visitor.expected_until {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Access code expired".to_string(),
+        });
+    }
+
+    visitor.status = visitor_model::VisitorStatus::CheckedIn;
+    visitor.checked_in_at = Some(now);
+    visitor.checked_in_by = Some(role.user_id);
+    visitor.updated_at = now;
+
+    if let Err(e) = visitor.db_update(conn) {
+        log::error!("Error checking in Visitor: {}", e);
+        return HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: format!("Error checking in Visitor: {}", e),
+        });
+    }
+
+    match resident_model::ResidentModel::db_read_by_id(conn, visitor.resident_id) {
+        Ok(resident) => {
+            let email = SmtpEmailPayload::from_template(
+                resident.email.clone(),
+                "Chegada de Visitante - MyCondominium".to_string(),
+                SmtpTemplate::VisitorArrival,
+                visitor.email_parameters(&resident),
+            );
+
+            match RabbitMqClient::new(&conf.rabbitmq, "mycondominium_smtp".to_string()).await {
+                Ok(rmq) => {
+                    if let Err(e) = email.publish(&rmq).await {
+                        log::error!("Error queueing Visitor arrival email: {}", e);
+                    }
+                }
+                Err(e) => log::error!("Error connecting to RabbitMQ: {}", e),
+            }
+        }
+        Err(e) => log::error!("Error reading Visitor resident: {}", e),
+    }
+
+    HttpResponse::Ok().json(HttpResponseObject {
+        error: false,
+        message: "Visitor checked in successfully".to_string(),
+        object: Some(visitor),
+    })
+}
+
+#[utoipa::path(
+    put,
+    tag = "Visitor",
+    path = "/check_out/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Visitor ID"),
+    ),
+    responses (
+        (status = 200, description = "Visitor checked out successfully", body = HttpResponseObjectEmpty),
+        (status = 400, description = "Visitor is not checked in", body = HttpResponseObjectEmptyError),
+        (status = 401, description = "Unauthorized", body = HttpResponseObjectEmptyError),
+        (status = 500, description = "Error checking out Visitor", body = HttpResponseObjectEmptyError),
+    ),
+    security(
+        ("Token" = [])
+    )
+)]
+pub async fn check_out_visitor(
+    id: web::Path<String>,
+    req: HttpRequest,
+    conf: web::Data<Arc<MyCondominiumConfig>>,
+) -> HttpResponse {
+    let conn = &mut establish_connection_pg(&conf);
+
+    let (role, _claims, _token) = match authenticate_user(req.clone(), conn) {
+        Ok((role, claims, token)) => {
+            if role.role == UserRoles::Root || role.role == UserRoles::Admin {
+                (role, claims, token)
+            } else {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        }
+        Err(_) => {
+            return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: "Unauthorized".to_string(),
+            });
+        }
+    };
+
+    let id = match Uuid::parse_str(&id) {
+        Ok(uuid) => uuid,
+        Err(_) => {
+            return HttpResponse::BadRequest().json(HttpResponseObjectEmpty {
+                error: true,
+                message: "Invalid Visitor ID format".to_string(),
+            });
+        }
+    };
+
+    let mut visitor =
+        match visitor_model::VisitorModel::db_read_by_id_matching_resident(role, conn, id) {
+            Ok(visitor) => visitor,
+            Err(_) => {
+                return HttpResponse::Unauthorized().json(HttpResponseObjectEmptyError {
+                    error: true,
+                    message: "Unauthorized".to_string(),
+                });
+            }
+        };
+
+    if visitor.status != visitor_model::VisitorStatus::CheckedIn {
+        return HttpResponse::BadRequest().json(HttpResponseObjectEmptyError {
+            error: true,
+            message: "Visitor is not checked in".to_string(),
+        });
+    }
+
+    let now = chrono::Utc::now().naive_utc();
+    visitor.status = visitor_model::VisitorStatus::CheckedOut;
+    visitor.checked_out_at = Some(now);
+    visitor.updated_at = now;
+
+    match visitor.db_update(conn) {
+        Ok(_) => HttpResponse::Ok().json(HttpResponseObjectEmpty {
+            error: false,
+            message: "Visitor checked out successfully".to_string(),
+        }),
+        Err(e) => {
+            log::error!("Error checking out Visitor: {}", e);
+            HttpResponse::InternalServerError().json(HttpResponseObjectEmptyError {
+                error: true,
+                message: format!("Error checking out Visitor: {}", e),
+            })
+        }
+    }
+}
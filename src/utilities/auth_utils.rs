@@ -1,8 +1,12 @@
+use crate::internal::config::model::ConfigAuth;
+use crate::models::api_key_model::ApiKeyModel;
 use crate::models::auth_model::TokenClaims;
 use crate::models::auth_token_model;
 use crate::models::auth_token_model::{AuthTokenModel, FromUaParser, UserAgent};
+use crate::models::user_model::UserModel;
 use crate::models::user_role_model::UserRoleModel;
 use crate::services::DatabaseTrait;
+use crate::utilities::jwt_utils::JwtKeys;
 use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl};
 use std::io::ErrorKind;
 use user_agent_parser::UserAgentParser;
@@ -19,6 +23,56 @@ pub fn hash_password(password: String) -> Result<String, std::io::Error> {
     }
 }
 
+/// Generates a random password for accounts whose owners set their own through a reset link.
+///
+/// It always has a lowercase and an uppercase letter, a digit and a symbol, so it satisfies any
+/// password policy on character classes.
+pub fn generate_password() -> String {
+    use password_hash::rand_core::RngCore;
+
+    const CLASSES: [&[u8]; 4] = [
+        b"abcdefghijklmnopqrstuvwxyz",
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+        b"0123456789",
+        b"!#$%&*+-=?@_",
+    ];
+    let alphabet = CLASSES.concat();
+    let pick = |chars: &[u8]| {
+        let i = password_hash::rand_core::OsRng.next_u32() as usize % chars.len();
+        chars[i]
+    };
+
+    let mut password: Vec<u8> = CLASSES.iter().map(|class| pick(class)).collect();
+    password.extend((password.len()..24).map(|_| pick(&alphabet)));
+
+    for i in (1..password.len()).rev() {
+        let j = password_hash::rand_core::OsRng.next_u32() as usize % (i + 1);
+        password.swap(i, j);
+    }
+
+    password.into_iter().map(char::from).collect()
+}
+
+/// Generates a random opaque token, hex encoded, for single-use links and challenges.
+pub fn generate_token() -> String {
+    use password_hash::rand_core::RngCore;
+
+    let mut bytes = [0u8; 32];
+    password_hash::rand_core::OsRng.fill_bytes(&mut bytes);
+    to_hex(&bytes)
+}
+
+/// Hashes an opaque token with SHA-256 so only its digest has to be stored.
+pub fn hash_token(token: &str) -> String {
+    use sha2::Digest;
+
+    to_hex(&sha2::Sha256::digest(token.as_bytes()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 /// Verifies if a given plain-text password matches the provided hashed password.
 ///
 /// This function takes a plain-text password and a hashed password string generated using the
@@ -88,7 +142,12 @@ pub fn generate_jwt_token(
             ));
         }
     };
-    generate_jwt_token_internal(user_id, token_id, secret_key, exp_days)
+    generate_jwt_token_internal(
+        user_id,
+        token_id,
+        secret_key,
+        chrono::Duration::days(exp_days),
+    )
 }
 
 pub fn generate_jwt_token_no_env(
@@ -97,14 +156,38 @@ pub fn generate_jwt_token_no_env(
     secret_key: String,
     exp_days: i64,
 ) -> Result<String, std::io::Error> {
-    generate_jwt_token_internal(user_id, token_id, secret_key, exp_days)
+    generate_jwt_token_internal(
+        user_id,
+        token_id,
+        secret_key,
+        chrono::Duration::days(exp_days),
+    )
+}
+
+/// Generates a short-lived access token for the session, to be renewed with its refresh token.
+///
+/// The token is signed with the active key of the configuration, see [`JwtKeys`].
+pub fn generate_access_token(
+    user_id: uuid::Uuid,
+    token_id: uuid::Uuid,
+    conf: &ConfigAuth,
+    keys: &JwtKeys,
+) -> Result<String, std::io::Error> {
+    let exp =
+        chrono::Utc::now() + chrono::Duration::minutes(conf.access_token_expiration_minutes as i64);
+
+    keys.encode(&TokenClaims {
+        token_id,
+        user_id,
+        exp: exp.timestamp() as usize,
+    })
 }
 
 fn generate_jwt_token_internal(
     user_id: uuid::Uuid,
     token_id: uuid::Uuid,
     secret_key: String,
-    exp_days: i64,
+    lifetime: chrono::Duration,
 ) -> Result<String, std::io::Error> {
     // Convert the UUID to a string representation
     //let user_id_str = user_id.to_string(); // UUID as a string
@@ -112,7 +195,7 @@ fn generate_jwt_token_internal(
     // Get the current time in seconds since UNIX EPOCH
     let now = chrono::Utc::now();
 
-    let expiration_time = now + chrono::Duration::days(exp_days);
+    let expiration_time = now + lifetime;
 
     // Convert the expiration time to a Unix timestamp (seconds)
     let exp_timestamp = expiration_time.timestamp() as usize;
@@ -143,13 +226,13 @@ pub fn validate_token(token: &str) -> jsonwebtoken::errors::Result<TokenClaims>
 }
 
 pub fn validate_token_from_header(
-    req: actix_web::HttpRequest,
-    secret_key: String,
+    req: &actix_web::HttpRequest,
+    keys: &JwtKeys,
 ) -> Result<TokenClaims, std::io::Error> {
     match req.headers().get("X-Auth-Token") {
         Some(header) => {
             let token = header.to_str().unwrap_or("");
-            match validate_token_no_env(token, secret_key) {
+            match keys.decode::<TokenClaims>(token) {
                 Ok(claims) => Ok(claims),
                 Err(_) => Err(std::io::Error::new(
                     std::io::ErrorKind::PermissionDenied,
@@ -222,15 +305,128 @@ pub fn parse_user_agent(ua_str: String) -> Result<UserAgent, std::io::Error> {
     Ok(ua)
 }
 
+/// Address of the client, taken from `X-Forwarded-For` only when the proxy in front is trusted.
+pub fn client_ip(req: &actix_web::HttpRequest, trust_forwarded_for: bool) -> String {
+    let ip = if trust_forwarded_for {
+        req.connection_info()
+            .realip_remote_addr()
+            .map(|ip| ip.to_string())
+    } else {
+        req.peer_addr().map(|addr| addr.ip().to_string())
+    };
+
+    ip.unwrap_or_else(|| "unknown".to_string())
+        .chars()
+        .take(45)
+        .collect()
+}
+
+/// Reads the role of the user, refusing residents who have moved out.
+fn read_active_user_role(
+    conn: &mut PgConnection,
+    user_id: uuid::Uuid,
+) -> Result<UserRoleModel, std::io::Error> {
+    match UserModel::db_is_active(conn, user_id) {
+        Ok(true) => (),
+        Ok(false) => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                "User is not active".to_string(),
+            ));
+        }
+        Err(e) => {
+            log::error!("Error checking user status: {}", e);
+            return Err(std::io::Error::other(
+                "Error checking user status".to_string(),
+            ));
+        }
+    }
+
+    UserRoleModel::table()
+        .filter(crate::schema::user_roles::user_id.eq(user_id))
+        .first::<UserRoleModel>(conn)
+        .map_err(|e| {
+            log::error!("Error getting user role: {}", e);
+            std::io::Error::other("Error getting user role".to_string())
+        })
+}
+
+/// Authenticates a request made with an `X-Api-Key` header.
+///
+/// The key stands in for a session: the claims and token carry the key ID, and the token isn't
+/// stored, so handlers keep working unchanged.
+fn authenticate_api_key(
+    req: &actix_web::HttpRequest,
+    conn: &mut PgConnection,
+    key: &str,
+) -> Result<(UserRoleModel, TokenClaims, AuthTokenModel), std::io::Error> {
+    let mut api_key = match ApiKeyModel::db_read_valid_by_key(conn, key) {
+        Ok(api_key) => api_key,
+        Err(_) => {
+            log::warn!("Invalid or expired API key");
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                "Invalid API key".to_string(),
+            ));
+        }
+    };
+
+    if !api_key.allows(req.method().as_str(), req.path()) {
+        log::warn!(
+            "API key {} is not scoped for {} {}",
+            api_key.id,
+            req.method(),
+            req.path()
+        );
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "API key is not allowed to access this resource".to_string(),
+        ));
+    }
+
+    if let Err(e) = api_key.db_touch(conn) {
+        log::error!("Error updating API key last use: {}", e);
+    }
+
+    let role = read_active_user_role(conn, api_key.user_id)?;
+    let claims = TokenClaims {
+        token_id: api_key.id,
+        user_id: api_key.user_id,
+        exp: api_key.expires_at.and_utc().timestamp() as usize,
+    };
+    let token = AuthTokenModel {
+        user_id: api_key.user_id,
+        id: api_key.id,
+        time_added: api_key.created_at,
+        active: true,
+        time_last_used: api_key.last_used_at.unwrap_or(api_key.created_at),
+        device: Some(api_key.name),
+        browser: None,
+        version: None,
+        cpu_arch: None,
+    };
+
+    Ok((role, claims, token))
+}
+
+/// Authenticates a request with its `X-Auth-Token` session token or, when there is none, its
+/// `X-Api-Key` header.
 pub fn authenticate_user(
     req: actix_web::HttpRequest,
     conn: &mut PgConnection,
-    conf: actix_web::web::Data<std::sync::Arc<crate::MyCondominiumConfig>>,
 ) -> Result<(UserRoleModel, TokenClaims, AuthTokenModel), std::io::Error> {
-    match validate_token_from_header(req, conf.auth.token_secret_key.clone()) {
+    if req.headers().get("X-Auth-Token").is_none()
+        && let Some(header) = req.headers().get("X-Api-Key")
+    {
+        return authenticate_api_key(&req, conn, header.to_str().unwrap_or(""));
+    }
+
+    let keys = JwtKeys::from_request(&req)?;
+
+    match validate_token_from_header(&req, keys) {
         Ok(claims) => {
             match auth_token_model::AuthTokenModel::db_read_by_id(conn, claims.token_id) {
-                Ok(token) => {
+                Ok(mut token) => {
                     if !token.active {
                         return Err(std::io::Error::new(
                             std::io::ErrorKind::PermissionDenied,
@@ -238,16 +434,11 @@ pub fn authenticate_user(
                         ));
                     }
 
-                    match UserRoleModel::table()
-                        .filter(crate::schema::user_roles::user_id.eq(claims.user_id))
-                        .first::<UserRoleModel>(conn)
-                    {
-                        Ok(role) => Ok((role, claims, token)),
-                        Err(e) => {
-                            log::error!("Error getting user role: {}", e);
-                            Err(std::io::Error::other("Error getting user role".to_string()))
-                        }
+                    if let Err(e) = token.db_touch(conn) {
+                        log::error!("Error updating token last use: {}", e);
                     }
+
+                    read_active_user_role(conn, claims.user_id).map(|role| (role, claims, token))
                 }
                 Err(e) => {
                     log::error!("Error reading token from database: {}", e);
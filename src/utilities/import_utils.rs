@@ -0,0 +1,78 @@
+use calamine::{Data, DataType, Reader};
+use chrono::NaiveDate;
+use std::io::Cursor;
+
+/// Reads the first sheet of a CSV, XLSX, XLS or ODS file as rows of trimmed cells, picking the
+/// format from the file extension.
+pub fn read_spreadsheet(file_name: &str, data: &[u8]) -> Result<Vec<Vec<String>>, String> {
+    let extension = file_name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "csv" => read_csv(data),
+        "xlsx" | "xlsm" | "xls" | "ods" => read_workbook(data),
+        _ => Err("Unsupported file type, expected CSV, XLSX, XLS or ODS".to_string()),
+    }
+}
+
+fn read_csv(data: &[u8]) -> Result<Vec<Vec<String>>, String> {
+    // Spreadsheets exported with a Brazilian locale use semicolons as separators.
+    let first_line = data.split(|b| *b == b'\n').next().unwrap_or_default();
+    let delimiter = if first_line.iter().filter(|b| **b == b';').count()
+        > first_line.iter().filter(|b| **b == b',').count()
+    {
+        b';'
+    } else {
+        b','
+    };
+
+    csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .delimiter(delimiter)
+        .trim(csv::Trim::All)
+        .from_reader(data)
+        .records()
+        .map(|record| {
+            record
+                .map(|record| record.iter().map(|cell| cell.to_string()).collect())
+                .map_err(|e| format!("Invalid CSV: {}", e))
+        })
+        .collect()
+}
+
+fn read_workbook(data: &[u8]) -> Result<Vec<Vec<String>>, String> {
+    let mut workbook = calamine::open_workbook_auto_from_rs(Cursor::new(data.to_vec()))
+        .map_err(|e| format!("Invalid spreadsheet: {}", e))?;
+
+    let range = match workbook.worksheet_range_at(0) {
+        Some(range) => range.map_err(|e| format!("Invalid spreadsheet: {}", e))?,
+        None => return Err("Spreadsheet has no sheets".to_string()),
+    };
+
+    Ok(range
+        .rows()
+        .map(|row| row.iter().map(cell_to_string).collect())
+        .collect())
+}
+
+fn cell_to_string(cell: &Data) -> String {
+    match cell {
+        Data::DateTime(_) | Data::DateTimeIso(_) => cell
+            .as_date()
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_default(),
+        // Phone numbers typed into numeric cells come back as floats.
+        Data::Float(f) if f.fract() == 0.0 => format!("{}", *f as i64),
+        _ => cell.to_string().trim().to_string(),
+    }
+}
+
+/// Parses dates written either as `YYYY-MM-DD` or as `DD/MM/YYYY`.
+pub fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(value, "%d/%m/%Y"))
+        .ok()
+}
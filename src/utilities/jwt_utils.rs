@@ -0,0 +1,218 @@
+use crate::internal::config::model::{ConfigAuth, ConfigSigningAlgorithm};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use jsonwebtoken::jwk::{
+    AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+    OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use simple_asn1::ASN1Block;
+
+/// Keys that sign and verify the JWTs issued by the API.
+///
+/// Without an active key, tokens are signed with HS256 and the shared `token_secret_key`. Once a
+/// key is active, tokens carry its `kid` and only tokens signed by one of the configured keys are
+/// accepted, which lets a new key take over while tokens signed by the previous ones still verify.
+pub struct JwtKeys {
+    secret: String,
+    active: Option<(String, Algorithm, EncodingKey)>,
+    jwks: JwkSet,
+}
+
+impl JwtKeys {
+    /// Loads the signing keys of the configuration from their PEM files.
+    pub fn new(conf: &ConfigAuth) -> Result<Self, std::io::Error> {
+        let mut jwks = JwkSet { keys: Vec::new() };
+
+        for key in &conf.signing_keys {
+            let pem = std::fs::read(&key.public_key_path).map_err(|e| {
+                std::io::Error::other(format!(
+                    "Error reading public key {}: {}",
+                    key.public_key_path, e
+                ))
+            })?;
+            jwks.keys
+                .push(public_key_to_jwk(&key.kid, key.algorithm, &pem)?);
+        }
+
+        let active = match &conf.active_key_id {
+            Some(kid) => {
+                let key = conf
+                    .signing_keys
+                    .iter()
+                    .find(|key| &key.kid == kid)
+                    .ok_or_else(|| {
+                        std::io::Error::other(format!("Active key {} is not configured", kid))
+                    })?;
+                let path = key.private_key_path.as_ref().ok_or_else(|| {
+                    std::io::Error::other(format!("Active key {} has no private key", kid))
+                })?;
+                let pem = std::fs::read(path).map_err(|e| {
+                    std::io::Error::other(format!("Error reading private key {}: {}", path, e))
+                })?;
+
+                let (algorithm, encoding_key) = match key.algorithm {
+                    ConfigSigningAlgorithm::RS256 => {
+                        (Algorithm::RS256, EncodingKey::from_rsa_pem(&pem))
+                    }
+                    ConfigSigningAlgorithm::EdDSA => {
+                        (Algorithm::EdDSA, EncodingKey::from_ed_pem(&pem))
+                    }
+                };
+                let encoding_key = encoding_key.map_err(|e| {
+                    std::io::Error::other(format!("Invalid private key {}: {}", path, e))
+                })?;
+
+                Some((kid.clone(), algorithm, encoding_key))
+            }
+            None => None,
+        };
+
+        Ok(JwtKeys {
+            secret: conf.token_secret_key.clone(),
+            active,
+            jwks,
+        })
+    }
+
+    pub fn encode<T: Serialize>(&self, claims: &T) -> Result<String, std::io::Error> {
+        let token = match &self.active {
+            Some((kid, algorithm, encoding_key)) => {
+                let mut header = Header::new(*algorithm);
+                header.kid = Some(kid.clone());
+                jsonwebtoken::encode(&header, claims, encoding_key)
+            }
+            None => jsonwebtoken::encode(
+                &Header::new(Algorithm::HS256),
+                claims,
+                &EncodingKey::from_secret(self.secret.as_bytes()),
+            ),
+        };
+
+        token.map_err(|e| std::io::Error::other(format!("Error signing token: {}", e)))
+    }
+
+    pub fn decode<T: DeserializeOwned>(&self, token: &str) -> Result<T, std::io::Error> {
+        let header = jsonwebtoken::decode_header(token).map_err(|e| {
+            std::io::Error::new(std::io::ErrorKind::PermissionDenied, e.to_string())
+        })?;
+
+        let (algorithm, decoding_key) = match (&header.kid, &self.active) {
+            (Some(kid), _) => {
+                let jwk = self.jwks.find(kid).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::PermissionDenied,
+                        format!("Unknown signing key {}", kid),
+                    )
+                })?;
+                let algorithm = match jwk.algorithm {
+                    AlgorithmParameters::RSA(_) => Algorithm::RS256,
+                    _ => Algorithm::EdDSA,
+                };
+                let decoding_key = DecodingKey::from_jwk(jwk)
+                    .map_err(|e| std::io::Error::other(format!("Invalid key {}: {}", kid, e)))?;
+
+                (algorithm, decoding_key)
+            }
+            (None, None) => (
+                Algorithm::HS256,
+                DecodingKey::from_secret(self.secret.as_bytes()),
+            ),
+            (None, Some(_)) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::PermissionDenied,
+                    "Token is not signed with a known key".to_string(),
+                ));
+            }
+        };
+
+        let mut validation = Validation::new(algorithm);
+        validation.validate_exp = true;
+
+        jsonwebtoken::decode::<T>(token, &decoding_key, &validation)
+            .map(|decoded| decoded.claims)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::PermissionDenied, e.to_string()))
+    }
+
+    /// Keys loaded at startup and shared with every request, see `main`.
+    pub fn from_request(req: &actix_web::HttpRequest) -> Result<&JwtKeys, std::io::Error> {
+        req.app_data::<actix_web::web::Data<JwtKeys>>()
+            .map(|keys| keys.get_ref())
+            .ok_or_else(|| std::io::Error::other("Signing keys are not loaded".to_string()))
+    }
+
+    /// Public keys accepted for verification, as published at the JWKS endpoint.
+    pub fn jwks(&self) -> &JwkSet {
+        &self.jwks
+    }
+}
+
+/// Converts a PEM public key (SPKI, or PKCS#1 for RSA) into its JWK representation.
+pub fn public_key_to_jwk(
+    kid: &str,
+    algorithm: ConfigSigningAlgorithm,
+    pem: &[u8],
+) -> Result<Jwk, std::io::Error> {
+    let invalid =
+        |reason: &str| std::io::Error::other(format!("Invalid public key {}: {}", kid, reason));
+
+    let pem = pem::parse(pem).map_err(|e| invalid(&e.to_string()))?;
+    let key = match pem.tag() {
+        "PUBLIC KEY" => match parse_der(pem.contents())?.as_slice() {
+            [ASN1Block::Sequence(_, items)] => match items.as_slice() {
+                [_, ASN1Block::BitString(_, _, key)] => key.clone(),
+                _ => return Err(invalid("malformed SubjectPublicKeyInfo")),
+            },
+            _ => return Err(invalid("malformed SubjectPublicKeyInfo")),
+        },
+        "RSA PUBLIC KEY" => pem.contents().to_vec(),
+        tag => return Err(invalid(&format!("unsupported PEM block {}", tag))),
+    };
+
+    let (key_algorithm, parameters) = match algorithm {
+        ConfigSigningAlgorithm::RS256 => match parse_der(&key)?.as_slice() {
+            [ASN1Block::Sequence(_, items)] => match items.as_slice() {
+                [ASN1Block::Integer(_, n), ASN1Block::Integer(_, e)] => (
+                    KeyAlgorithm::RS256,
+                    AlgorithmParameters::RSA(RSAKeyParameters {
+                        key_type: RSAKeyType::RSA,
+                        n: URL_SAFE_NO_PAD.encode(n.to_bytes_be().1),
+                        e: URL_SAFE_NO_PAD.encode(e.to_bytes_be().1),
+                    }),
+                ),
+                _ => return Err(invalid("not an RSA key")),
+            },
+            _ => return Err(invalid("not an RSA key")),
+        },
+        ConfigSigningAlgorithm::EdDSA => {
+            if key.len() != 32 {
+                return Err(invalid("not an Ed25519 key"));
+            }
+
+            (
+                KeyAlgorithm::EdDSA,
+                AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                    key_type: OctetKeyPairType::OctetKeyPair,
+                    curve: EllipticCurve::Ed25519,
+                    x: URL_SAFE_NO_PAD.encode(&key),
+                }),
+            )
+        }
+    };
+
+    Ok(Jwk {
+        common: CommonParameters {
+            public_key_use: Some(PublicKeyUse::Signature),
+            key_algorithm: Some(key_algorithm),
+            key_id: Some(kid.to_string()),
+            ..Default::default()
+        },
+        algorithm: parameters,
+    })
+}
+
+fn parse_der(der: &[u8]) -> Result<Vec<ASN1Block>, std::io::Error> {
+    simple_asn1::from_der(der).map_err(|e| std::io::Error::other(format!("Invalid DER: {}", e)))
+}
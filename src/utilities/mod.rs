@@ -1,2 +1,8 @@
 pub mod auth_utils;
+pub mod import_utils;
+pub mod jwt_utils;
+pub mod oidc_utils;
+pub mod password_utils;
+pub mod plate_utils;
+pub mod totp_utils;
 pub mod user_utils;
@@ -0,0 +1,255 @@
+use crate::internal::config::model::ConfigOidcProvider;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+/// Time allowed for each request to an identity provider.
+const PROVIDER_TIMEOUT_SECONDS: u64 = 10;
+
+/// Time discovery documents and key sets are reused before they are fetched again.
+const PROVIDER_CACHE_SECONDS: u64 = 3600;
+
+/// Values fetched from providers, keyed by URL, with the time they were fetched.
+type ProviderCache<T> = LazyLock<Mutex<HashMap<String, (Instant, T)>>>;
+
+static METADATA_CACHE: ProviderCache<OidcProviderMetadata> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+static JWKS_CACHE: ProviderCache<JwkSet> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Endpoints of a provider, as published at `/.well-known/openid-configuration`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct OidcProviderMetadata {
+    pub issuer: String,
+    pub authorization_endpoint: String,
+    pub token_endpoint: String,
+    pub jwks_uri: String,
+}
+
+/// Claims of an ID token used to find the user it belongs to.
+#[derive(Deserialize, Debug, Clone)]
+pub struct OidcIdTokenClaims {
+    pub sub: String,
+    pub nonce: Option<String>,
+    pub email: Option<String>,
+    #[serde(default)]
+    pub email_verified: bool,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+fn provider_error(message: String) -> std::io::Error {
+    std::io::Error::other(message)
+}
+
+fn read_cache<T: Clone>(cache: &ProviderCache<T>, key: &str) -> Option<T> {
+    let cache = cache.lock().unwrap_or_else(|e| e.into_inner());
+
+    cache
+        .get(key)
+        .filter(|(fetched_at, _)| {
+            fetched_at.elapsed() < Duration::from_secs(PROVIDER_CACHE_SECONDS)
+        })
+        .map(|(_, value)| value.clone())
+}
+
+fn write_cache<T>(cache: &ProviderCache<T>, key: &str, value: T) {
+    cache
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key.to_string(), (Instant::now(), value));
+}
+
+fn http_client() -> Result<reqwest::Client, std::io::Error> {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(PROVIDER_TIMEOUT_SECONDS))
+        .build()
+        .map_err(|e| provider_error(format!("Error creating HTTP client: {}", e)))
+}
+
+/// PKCE `S256` challenge of a code verifier.
+pub fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+/// Reads the endpoints of the provider, reusing them for `PROVIDER_CACHE_SECONDS`.
+pub async fn discover(issuer: &str) -> Result<OidcProviderMetadata, std::io::Error> {
+    if let Some(metadata) = read_cache(&METADATA_CACHE, issuer) {
+        return Ok(metadata);
+    }
+
+    let url = format!(
+        "{}/.well-known/openid-configuration",
+        issuer.trim_end_matches('/')
+    );
+
+    let metadata: OidcProviderMetadata = http_client()?
+        .get(&url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| provider_error(format!("Error discovering {}: {}", issuer, e)))?
+        .json()
+        .await
+        .map_err(|e| provider_error(format!("Invalid metadata from {}: {}", issuer, e)))?;
+
+    if metadata.issuer.trim_end_matches('/') != issuer.trim_end_matches('/') {
+        return Err(provider_error(format!(
+            "Issuer mismatch: expected {}, got {}",
+            issuer, metadata.issuer
+        )));
+    }
+
+    write_cache(&METADATA_CACHE, issuer, metadata.clone());
+    Ok(metadata)
+}
+
+/// URL the user is sent to in order to sign in at the provider.
+pub fn authorization_url(
+    metadata: &OidcProviderMetadata,
+    provider: &ConfigOidcProvider,
+    state: &str,
+    nonce: &str,
+    code_verifier: &str,
+) -> Result<String, std::io::Error> {
+    let scope = provider.scopes.join(" ");
+    let code_challenge = pkce_challenge(code_verifier);
+
+    reqwest::Url::parse_with_params(
+        &metadata.authorization_endpoint,
+        &[
+            ("response_type", "code"),
+            ("client_id", provider.client_id.as_str()),
+            ("redirect_uri", provider.redirect_uri.as_str()),
+            ("scope", scope.as_str()),
+            ("state", state),
+            ("nonce", nonce),
+            ("code_challenge", code_challenge.as_str()),
+            ("code_challenge_method", "S256"),
+        ],
+    )
+    .map(|url| url.to_string())
+    .map_err(|e| provider_error(format!("Invalid authorization endpoint: {}", e)))
+}
+
+/// Exchanges an authorization code for the ID token of the user.
+pub async fn exchange_code(
+    metadata: &OidcProviderMetadata,
+    provider: &ConfigOidcProvider,
+    code: &str,
+    code_verifier: &str,
+) -> Result<String, std::io::Error> {
+    let mut form = vec![
+        ("grant_type", "authorization_code"),
+        ("code", code),
+        ("redirect_uri", provider.redirect_uri.as_str()),
+        ("client_id", provider.client_id.as_str()),
+        ("code_verifier", code_verifier),
+    ];
+    if let Some(secret) = &provider.client_secret {
+        form.push(("client_secret", secret.as_str()));
+    }
+
+    let response: TokenResponse = http_client()?
+        .post(&metadata.token_endpoint)
+        .form(&form)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| provider_error(format!("Error exchanging code at {}: {}", provider.name, e)))?
+        .json()
+        .await
+        .map_err(|e| provider_error(format!("Invalid token response: {}", e)))?;
+
+    Ok(response.id_token)
+}
+
+pub async fn fetch_jwks(metadata: &OidcProviderMetadata) -> Result<JwkSet, std::io::Error> {
+    http_client()?
+        .get(&metadata.jwks_uri)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| provider_error(format!("Error fetching keys: {}", e)))?
+        .json()
+        .await
+        .map_err(|e| provider_error(format!("Invalid key set: {}", e)))
+}
+
+/// Keys that may have signed `id_token`, reusing the provider's key set for
+/// `PROVIDER_CACHE_SECONDS` unless the token is signed with a key it doesn't have yet.
+pub async fn signing_keys(
+    metadata: &OidcProviderMetadata,
+    id_token: &str,
+) -> Result<JwkSet, std::io::Error> {
+    let kid = jsonwebtoken::decode_header(id_token)
+        .ok()
+        .and_then(|header| header.kid);
+
+    if let Some(jwks) = read_cache(&JWKS_CACHE, &metadata.jwks_uri)
+        && kid.as_deref().is_none_or(|kid| jwks.find(kid).is_some())
+    {
+        return Ok(jwks);
+    }
+
+    let jwks = fetch_jwks(metadata).await?;
+    write_cache(&JWKS_CACHE, &metadata.jwks_uri, jwks.clone());
+    Ok(jwks)
+}
+
+/// Verifies the signature, issuer, audience, expiry and nonce of an ID token.
+pub fn validate_id_token(
+    id_token: &str,
+    jwks: &JwkSet,
+    issuer: &str,
+    client_id: &str,
+    nonce: &str,
+) -> Result<OidcIdTokenClaims, std::io::Error> {
+    let invalid = |message: String| {
+        std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            format!("Invalid ID token: {}", message),
+        )
+    };
+
+    let header = jsonwebtoken::decode_header(id_token).map_err(|e| invalid(e.to_string()))?;
+
+    // Shared-secret algorithms would let anyone who knows the client secret forge tokens.
+    if matches!(
+        header.alg,
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+    ) {
+        return Err(invalid(format!("unsupported algorithm {:?}", header.alg)));
+    }
+
+    let jwk = match &header.kid {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    }
+    .ok_or_else(|| invalid("unknown signing key".to_string()))?;
+    let key = DecodingKey::from_jwk(jwk).map_err(|e| invalid(e.to_string()))?;
+
+    let mut validation = Validation::new(header.alg);
+    validation.set_issuer(&[issuer]);
+    validation.set_audience(&[client_id]);
+    validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+
+    let claims = jsonwebtoken::decode::<OidcIdTokenClaims>(id_token, &key, &validation)
+        .map_err(|e| invalid(e.to_string()))?
+        .claims;
+
+    if claims.nonce.as_deref() != Some(nonce) {
+        return Err(invalid("nonce mismatch".to_string()));
+    }
+
+    Ok(claims)
+}
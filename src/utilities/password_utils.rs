@@ -0,0 +1,93 @@
+use crate::internal::config::model::ConfigPasswordPolicy;
+use crate::utilities::auth_utils::check_password;
+use std::io::ErrorKind;
+
+/// Commonly used passwords, one per line in lowercase, checked when `reject_common` is set.
+const COMMON_PASSWORDS: &str = include_str!("../../res/common_passwords.txt");
+
+/// Shortest name or email fragment that is looked for inside a password.
+const MIN_PERSONAL_INFO_LENGTH: usize = 3;
+
+/// Checks a new password against the policy.
+///
+/// `personal_info` holds the user's names and email, which the password may not contain.
+pub fn check_password_policy(
+    password: &str,
+    policy: &ConfigPasswordPolicy,
+    personal_info: &[&str],
+) -> Result<(), std::io::Error> {
+    let invalid = |message: String| Err(std::io::Error::new(ErrorKind::InvalidInput, message));
+    let length = password.chars().count();
+
+    if length < policy.min_length {
+        return invalid(format!(
+            "Password must have at least {} characters",
+            policy.min_length
+        ));
+    }
+    if length > policy.max_length {
+        return invalid(format!(
+            "Password must have at most {} characters",
+            policy.max_length
+        ));
+    }
+    if policy.require_lowercase && !password.chars().any(|c| c.is_lowercase()) {
+        return invalid("Password must contain a lowercase letter".to_string());
+    }
+    if policy.require_uppercase && !password.chars().any(|c| c.is_uppercase()) {
+        return invalid("Password must contain an uppercase letter".to_string());
+    }
+    if policy.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+        return invalid("Password must contain a digit".to_string());
+    }
+    if policy.require_symbol && password.chars().all(|c| c.is_alphanumeric()) {
+        return invalid("Password must contain a symbol".to_string());
+    }
+
+    let lowercase = password.to_lowercase();
+
+    if policy.reject_personal_info
+        && personal_info
+            .iter()
+            .map(|info| info.trim().to_lowercase())
+            .map(|info| match info.split_once('@') {
+                Some((local, _)) => local.to_string(),
+                None => info,
+            })
+            .any(|info| {
+                info.chars().count() >= MIN_PERSONAL_INFO_LENGTH && lowercase.contains(&info)
+            })
+    {
+        return invalid("Password must not contain your name or email".to_string());
+    }
+    if policy.reject_common && is_common_password(&lowercase) {
+        return invalid("Password is too common".to_string());
+    }
+
+    Ok(())
+}
+
+pub fn is_common_password(password: &str) -> bool {
+    let password = password.to_lowercase();
+
+    COMMON_PASSWORDS.lines().any(|common| common == password)
+}
+
+/// Fails when the password matches one of the given hashes of previous passwords.
+///
+/// Hashes that can't be parsed are skipped, as they can't match any password.
+pub fn check_password_reuse(
+    password: &str,
+    previous_hashes: &[String],
+) -> Result<(), std::io::Error> {
+    for hash in previous_hashes {
+        if check_password(password.to_string(), hash.clone()).unwrap_or(false) {
+            return Err(std::io::Error::new(
+                ErrorKind::InvalidInput,
+                "Password was used recently, choose a different one",
+            ));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+/// Normalises a Brazilian license plate to upper case without separators, accepting both the
+/// old `AAA9999` format and the Mercosul `AAA9A99` format. Returns `None` for anything else.
+pub fn normalize_plate(plate: &str) -> Option<String> {
+    let plate: String = plate
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    let bytes = plate.as_bytes();
+    if bytes.len() != 7
+        || !bytes[..3].iter().all(u8::is_ascii_alphabetic)
+        || !bytes[3].is_ascii_digit()
+        || !bytes[5..].iter().all(u8::is_ascii_digit)
+    {
+        return None;
+    }
+
+    if bytes[4].is_ascii_digit() || (b'A'..=b'J').contains(&bytes[4]) {
+        Some(plate)
+    } else {
+        None
+    }
+}
+
+/// Returns the normalised plate together with its counterpart in the other format, since a
+/// vehicle registered before the Mercosul migration keeps the same plate with the fifth digit
+/// swapped for a letter (0 → A, 1 → B, ... 9 → J).
+pub fn equivalent_plates(plate: &str) -> Vec<String> {
+    let Some(plate) = normalize_plate(plate) else {
+        return Vec::new();
+    };
+
+    let mut counterpart = plate.clone().into_bytes();
+    counterpart[4] = match counterpart[4] {
+        d @ b'0'..=b'9' => b'A' + (d - b'0'),
+        l => b'0' + (l - b'A'),
+    };
+
+    vec![plate, String::from_utf8(counterpart).unwrap()]
+}
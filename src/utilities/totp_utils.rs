@@ -0,0 +1,83 @@
+use totp_rs::{Algorithm, Secret, TOTP};
+
+/// Issuer shown by authenticator apps next to the account.
+pub const TOTP_ISSUER: &str = "MyCondominium";
+const TOTP_DIGITS: usize = 6;
+const TOTP_STEP: u64 = 30;
+/// Steps accepted before and after the current one, to tolerate clock drift.
+const TOTP_SKEW: u64 = 1;
+pub const RECOVERY_CODE_COUNT: usize = 10;
+
+/// Generates a new base32 encoded TOTP secret (160 bits, as recommended by RFC 4226).
+pub fn generate_totp_secret() -> String {
+    Secret::generate_secret().to_encoded().to_string()
+}
+
+fn build_totp(secret: &str, account_name: &str) -> Result<TOTP, String> {
+    let secret = Secret::Encoded(secret.to_string())
+        .to_bytes()
+        .map_err(|e| format!("Invalid TOTP secret: {}", e))?;
+
+    TOTP::new(
+        Algorithm::SHA1,
+        TOTP_DIGITS,
+        TOTP_SKEW as u8,
+        TOTP_STEP,
+        secret,
+        Some(TOTP_ISSUER.to_string()),
+        account_name.replace(':', ""),
+    )
+    .map_err(|e| format!("Invalid TOTP parameters: {}", e))
+}
+
+/// Builds the `otpauth://` URI authenticator apps read from the enrolment QR code.
+pub fn totp_provisioning_uri(secret: &str, account_name: &str) -> Result<String, String> {
+    Ok(build_totp(secret, account_name)?.get_url())
+}
+
+/// Checks a TOTP code at the given Unix time and returns the time step it matched.
+///
+/// Steps up to `last_used_step` are rejected, so a code can't be replayed within its window.
+pub fn verify_totp_code(
+    secret: &str,
+    code: &str,
+    time: u64,
+    last_used_step: Option<i64>,
+) -> Option<i64> {
+    let totp = build_totp(secret, "").ok()?;
+    let code = code.trim();
+    let current_step = time / TOTP_STEP;
+
+    (current_step.saturating_sub(TOTP_SKEW)..=current_step + TOTP_SKEW)
+        .map(|step| step as i64)
+        .filter(|step| last_used_step.is_none_or(|last| *step > last))
+        .find(|step| totp.generate(*step as u64 * TOTP_STEP) == code)
+}
+
+/// Generates a set of single-use recovery codes formatted as `XXXXX-XXXXX`.
+pub fn generate_recovery_codes() -> Vec<String> {
+    use password_hash::rand_core::RngCore;
+
+    // Letters and digits that can't be mistaken for each other when copied by hand.
+    const ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+    (0..RECOVERY_CODE_COUNT)
+        .map(|_| {
+            let code: String = (0..10)
+                .map(|_| {
+                    let i = password_hash::rand_core::OsRng.next_u32() as usize % ALPHABET.len();
+                    ALPHABET[i] as char
+                })
+                .collect();
+            format!("{}-{}", &code[..5], &code[5..])
+        })
+        .collect()
+}
+
+/// Normalises a recovery code typed by the user so it can be hashed and compared.
+pub fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
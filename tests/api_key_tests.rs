@@ -0,0 +1,79 @@
+use mycondominium_backend::models::api_key_model::{ApiKeyModel, ApiKeyNewModel, normalize_scopes};
+use mycondominium_backend::models::service_account_model::ServiceAccountNewModel;
+use mycondominium_backend::utilities::auth_utils::hash_token;
+use validator::Validate;
+
+fn api_key(scopes: &str) -> ApiKeyModel {
+    let now = chrono::Utc::now().naive_utc();
+
+    ApiKeyModel {
+        id: uuid::Uuid::new_v4(),
+        user_id: uuid::Uuid::new_v4(),
+        name: "Gate control".to_string(),
+        prefix: "mcd_0123abcd".to_string(),
+        key_hash: hash_token("mcd_0123abcd"),
+        scopes: scopes.to_string(),
+        created_at: now,
+        expires_at: now + chrono::Duration::days(30),
+        last_used_at: None,
+    }
+}
+
+#[test]
+fn test_normalize_scopes() {
+    let scopes = vec![
+        " Visitor:Write ".to_string(),
+        "invoice:read".to_string(),
+        "visitor:write".to_string(),
+    ];
+    assert_eq!(
+        normalize_scopes(&scopes).unwrap(),
+        "visitor:write invoice:read"
+    );
+
+    assert!(normalize_scopes(&["auth:write".to_string()]).is_err());
+    assert!(normalize_scopes(&["visitor:delete".to_string()]).is_err());
+    assert!(normalize_scopes(&["visitor".to_string()]).is_err());
+}
+
+#[test]
+fn test_api_key_scopes() {
+    let key = api_key("visitor:write invoice:read");
+
+    assert!(key.allows("GET", "/api/visitor/list"));
+    assert!(key.allows("POST", "/api/visitor/new"));
+    assert!(key.allows("GET", "/api/invoice/list"));
+    assert!(!key.allows("POST", "/api/invoice/new"));
+    assert!(!key.allows("GET", "/api/resident/list"));
+    assert!(!key.allows("GET", "/api/visitors/list"));
+}
+
+#[test]
+fn test_api_key_never_reaches_auth() {
+    let key = api_key("auth:write visitor:write");
+
+    assert!(!key.allows("GET", "/api/auth/api_key/list"));
+    assert!(!key.allows("POST", "/api/auth/change_password"));
+    assert!(!key.allows("GET", "/swagger-ui/"));
+}
+
+#[test]
+fn test_service_account_validates_its_first_key() {
+    let mut account = ServiceAccountNewModel {
+        community_id: uuid::Uuid::new_v4(),
+        name: "Gate controller".to_string(),
+        api_key: ApiKeyNewModel {
+            name: "Gate control".to_string(),
+            scopes: vec!["visitor:write".to_string()],
+            expires_in_days: 30,
+        },
+    };
+    assert!(account.validate().is_ok());
+
+    account.api_key.expires_in_days = 400;
+    assert!(account.validate().is_err());
+
+    account.api_key.expires_in_days = 30;
+    account.name = String::new();
+    assert!(account.validate().is_err());
+}
@@ -1,8 +1,12 @@
 use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
 use diesel_migrations::MigrationHarness;
+use mycondominium_backend::internal::config::model::ConfigAuth;
+use mycondominium_backend::internal::user_types::UserTypes;
 use mycondominium_backend::models::resident_model::ResidentModel;
+use mycondominium_backend::models::user_model::UserModel;
 use mycondominium_backend::services::{DatabaseTrait, MyCondominiumConfig, check_email_exist};
 use mycondominium_backend::utilities::auth_utils::*;
+use mycondominium_backend::utilities::jwt_utils::JwtKeys;
 use mycondominium_backend::{MIGRATIONS, establish_connection_pg, schema};
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -41,7 +45,42 @@ fn test_jwt_token() {
 }
 
 #[test]
-fn test_email_exist() {
+fn test_access_token_lifetime() {
+    let user_id = uuid::Uuid::new_v4();
+    let token_id = uuid::Uuid::new_v4();
+    let secret_key = "1a855a3f6a0de874ae624013646e1c8f13e2bbe69f31dd286c99c85e95a43285".to_string();
+
+    let conf = ConfigAuth {
+        token_expiration_days: 30,
+        token_secret_key: secret_key.clone(),
+        access_token_expiration_minutes: 15,
+        active_key_id: None,
+        signing_keys: Vec::new(),
+    };
+
+    let keys = JwtKeys::new(&conf).unwrap();
+    let token = generate_access_token(user_id, token_id, &conf, &keys).unwrap();
+    let claims = validate_token_no_env(&token, secret_key).unwrap();
+    let lifetime = claims.exp as i64 - chrono::Utc::now().timestamp();
+
+    assert_eq!(claims.token_id, token_id);
+    assert!((14 * 60..=15 * 60).contains(&lifetime));
+}
+
+#[test]
+fn test_token_hash() {
+    let token = generate_token();
+
+    assert_eq!(token.len(), 64);
+    assert_ne!(token, generate_token());
+    assert_eq!(hash_token(&token), hash_token(&token));
+    assert_eq!(
+        hash_token("abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+fn load_config() -> Arc<MyCondominiumConfig> {
     let config_path = &PathBuf::from("config.yaml");
 
     if !config_path.exists() || !config_path.is_file() {
@@ -65,7 +104,12 @@ fn test_email_exist() {
         }
     };
 
-    let conf: Arc<MyCondominiumConfig> = Arc::new(conf);
+    Arc::new(conf)
+}
+
+#[test]
+fn test_email_exist() {
+    let conf = load_config();
 
     let conn = &mut establish_connection_pg(&conf);
     conn.run_pending_migrations(MIGRATIONS)
@@ -87,7 +131,6 @@ fn test_email_exist() {
         id: ResidentModel::new_id_user(conn),
         first_name: "John".to_string(),
         last_name: "Doe".to_string(),
-        unit_number: None,
         address: None,
         phone: None,
         email: "example@example.com".to_string(),
@@ -95,6 +138,8 @@ fn test_email_exist() {
         is_active: false,
         created_at: Default::default(),
         updated_at: Default::default(),
+        moved_in_at: None,
+        moved_out_at: None,
     };
 
     resident.db_insert(conn);
@@ -105,3 +150,59 @@ fn test_email_exist() {
     assert!(result.is_err());
     assert!(result_ne.is_ok());
 }
+
+#[test]
+fn test_inactive_resident_is_refused() {
+    let conf = load_config();
+
+    let conn = &mut establish_connection_pg(&conf);
+    conn.run_pending_migrations(MIGRATIONS)
+        .expect("Failed to run database migrations");
+
+    if let Ok(res) = ResidentModel::table()
+        .filter(schema::residents::email.eq("moved-out@example.com".to_string()))
+        .first::<ResidentModel>(conn)
+    {
+        diesel::delete(schema::users::table.filter(schema::users::entity_id.eq(res.id)))
+            .execute(conn)
+            .unwrap();
+        ResidentModel::db_delete_by_id(conn, res.id).unwrap();
+    }
+
+    let now = chrono::Utc::now().naive_utc();
+    let mut resident = ResidentModel {
+        id: ResidentModel::new_id_user(conn),
+        first_name: "Jane".to_string(),
+        last_name: "Doe".to_string(),
+        address: None,
+        phone: None,
+        email: "moved-out@example.com".to_string(),
+        date_of_birth: None,
+        is_active: true,
+        created_at: now,
+        updated_at: now,
+        moved_in_at: None,
+        moved_out_at: None,
+    };
+    resident.db_insert(conn).unwrap();
+
+    let user = UserModel {
+        id: resident.id,
+        entity_id: resident.id,
+        entity_type: UserTypes::Resident,
+        admin_id: None,
+        resident_id: Some(resident.id),
+        password: hash_password("ABCDEF123456".to_string()).unwrap(),
+        created_at: now,
+        updated_at: now,
+        service_account_id: None,
+    };
+    user.db_insert(conn).unwrap();
+
+    assert!(UserModel::db_is_active(conn, user.id).unwrap());
+
+    resident.is_active = false;
+    resident.db_update(conn).unwrap();
+
+    assert!(!UserModel::db_is_active(conn, user.id).unwrap());
+}
@@ -0,0 +1,69 @@
+use chrono::NaiveDate;
+use mycondominium_backend::models::announcement_model::{AnnouncementAudience, AnnouncementModel};
+use mycondominium_backend::models::election_model::ElectionModel;
+use mycondominium_backend::models::unit_model::{UnitRelation, UnitResidentModel};
+use uuid::Uuid;
+
+fn at(day: u32, hour: u32) -> chrono::NaiveDateTime {
+    NaiveDate::from_ymd_opt(2025, 6, day)
+        .unwrap()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+}
+
+#[test]
+fn test_election_is_open_between_start_and_end() {
+    let election = ElectionModel {
+        id: Uuid::new_v4(),
+        community_id: Some(Uuid::new_v4()),
+        title: "Assembleia geral".to_string(),
+        description: None,
+        start_date: at(10, 9),
+        end_date: at(12, 18),
+        created_at: at(1, 0),
+    };
+
+    assert!(!election.is_open(at(10, 8)));
+    assert!(election.is_open(at(10, 9)));
+    assert!(election.is_open(at(12, 17)));
+    assert!(!election.is_open(at(12, 18)));
+}
+
+#[test]
+fn test_owner_announcements_leave_other_residents_out() {
+    assert!(AnnouncementAudience::AllResidents.includes(false));
+    assert!(AnnouncementAudience::AllResidents.includes(true));
+    assert!(AnnouncementAudience::Owners.includes(true));
+    assert!(!AnnouncementAudience::Owners.includes(false));
+    assert_eq!(
+        AnnouncementAudience::default(),
+        AnnouncementAudience::AllResidents
+    );
+}
+
+#[test]
+fn test_owner_announcements_follow_ownership_when_sent() {
+    let announcement = AnnouncementModel {
+        id: Uuid::new_v4(),
+        title: "Obras na fachada".to_string(),
+        community_id: Some(Uuid::new_v4()),
+        message: "Assembleia extraordinária".to_string(),
+        sent_at: at(15, 10),
+        audience: AnnouncementAudience::Owners,
+    };
+    let ownership = |start_day: u32, end_day: Option<u32>| UnitResidentModel {
+        id: Uuid::new_v4(),
+        unit_id: Uuid::new_v4(),
+        resident_id: Uuid::new_v4(),
+        relation: UnitRelation::Owner,
+        created_at: at(start_day, 0),
+        start_date: at(start_day, 0).date(),
+        end_date: end_day.map(|day| at(day, 0).date()),
+        voting_proxy: false,
+    };
+
+    assert!(announcement.is_addressed_to(&[ownership(1, None)]));
+    assert!(!announcement.is_addressed_to(&[ownership(1, Some(14))]));
+    assert!(!announcement.is_addressed_to(&[ownership(16, None)]));
+    assert!(!announcement.is_addressed_to(&[]));
+}
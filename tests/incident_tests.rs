@@ -0,0 +1,55 @@
+use chrono::NaiveDate;
+use mycondominium_backend::models::incident_model::{
+    IncidentModel, IncidentPriority, IncidentStatus,
+};
+use uuid::Uuid;
+
+fn at(day: u32, hour: u32) -> chrono::NaiveDateTime {
+    NaiveDate::from_ymd_opt(2025, 6, day)
+        .unwrap()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+}
+
+fn incident(status: IncidentStatus) -> IncidentModel {
+    IncidentModel {
+        id: Uuid::new_v4(),
+        resident_id: Uuid::new_v4(),
+        community_id: Uuid::new_v4(),
+        name: "Elevator stuck".to_string(),
+        description: "The elevator stopped between floors".to_string(),
+        status,
+        report_date: at(1, 9),
+        resolution_date: None,
+        notes: None,
+        assignee_id: None,
+        category_id: None,
+        priority: IncidentPriority::Medium,
+        response_date: None,
+        response_sla_breached: false,
+        resolution_sla_breached: false,
+    }
+}
+
+#[test]
+fn test_incident_progress_is_tracked() {
+    let mut incident = incident(IncidentStatus::Ongoing);
+    incident.track_progress(at(1, 10));
+    assert_eq!(incident.response_date, Some(at(1, 10)));
+    assert_eq!(incident.resolution_date, None);
+
+    incident.status = IncidentStatus::Resolved;
+    incident.track_progress(at(2, 10));
+    assert_eq!(incident.response_date, Some(at(1, 10)));
+    assert_eq!(incident.resolution_date, Some(at(2, 10)));
+}
+
+#[test]
+fn test_reopened_incident_is_not_resolved() {
+    let mut incident = incident(IncidentStatus::Resolved);
+    incident.track_progress(at(2, 10));
+
+    incident.status = IncidentStatus::Ongoing;
+    incident.track_progress(at(3, 10));
+    assert_eq!(incident.resolution_date, None);
+}
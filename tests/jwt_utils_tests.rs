@@ -0,0 +1,107 @@
+use mycondominium_backend::internal::config::model::{
+    ConfigAuth, ConfigSigningAlgorithm, ConfigSigningKey,
+};
+use mycondominium_backend::models::auth_model::TokenClaims;
+use mycondominium_backend::utilities::jwt_utils::JwtKeys;
+use uuid::Uuid;
+
+fn ed25519_key() -> ConfigSigningKey {
+    ConfigSigningKey {
+        kid: "2025-07".to_string(),
+        algorithm: ConfigSigningAlgorithm::EdDSA,
+        public_key_path: "tests/keys/ed25519.pub.pem".to_string(),
+        private_key_path: Some("tests/keys/ed25519.pem".to_string()),
+    }
+}
+
+fn rsa_key() -> ConfigSigningKey {
+    ConfigSigningKey {
+        kid: "2025-01".to_string(),
+        algorithm: ConfigSigningAlgorithm::RS256,
+        public_key_path: "tests/keys/rsa.pub.pem".to_string(),
+        private_key_path: Some("tests/keys/rsa.pem".to_string()),
+    }
+}
+
+fn auth_config(active_key_id: Option<&str>, signing_keys: Vec<ConfigSigningKey>) -> ConfigAuth {
+    ConfigAuth {
+        token_expiration_days: 30,
+        token_secret_key: "secret".to_string(),
+        access_token_expiration_minutes: 15,
+        active_key_id: active_key_id.map(|kid| kid.to_string()),
+        signing_keys,
+    }
+}
+
+fn claims() -> TokenClaims {
+    TokenClaims {
+        token_id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        exp: (chrono::Utc::now().timestamp() + 900) as usize,
+    }
+}
+
+#[test]
+fn test_sign_with_active_key() {
+    let keys = JwtKeys::new(&auth_config(Some("2025-07"), vec![ed25519_key()])).unwrap();
+    let claims = claims();
+
+    let token = keys.encode(&claims).unwrap();
+    let header = jsonwebtoken::decode_header(&token).unwrap();
+
+    assert_eq!(header.alg, jsonwebtoken::Algorithm::EdDSA);
+    assert_eq!(header.kid.as_deref(), Some("2025-07"));
+    assert_eq!(
+        keys.decode::<TokenClaims>(&token).unwrap().user_id,
+        claims.user_id
+    );
+}
+
+#[test]
+fn test_previous_key_still_verifies() {
+    let old_keys = JwtKeys::new(&auth_config(Some("2025-01"), vec![rsa_key()])).unwrap();
+    let token = old_keys.encode(&claims()).unwrap();
+
+    let mut rotated_rsa_key = rsa_key();
+    rotated_rsa_key.private_key_path = None;
+    let keys = JwtKeys::new(&auth_config(
+        Some("2025-07"),
+        vec![ed25519_key(), rotated_rsa_key],
+    ))
+    .unwrap();
+
+    assert!(keys.decode::<TokenClaims>(&token).is_ok());
+
+    // Once the old key is dropped from the configuration its tokens are rejected.
+    let keys = JwtKeys::new(&auth_config(Some("2025-07"), vec![ed25519_key()])).unwrap();
+    assert!(keys.decode::<TokenClaims>(&token).is_err());
+}
+
+#[test]
+fn test_secret_tokens_rejected_once_key_is_active() {
+    let secret_keys = JwtKeys::new(&auth_config(None, Vec::new())).unwrap();
+    let token = secret_keys.encode(&claims()).unwrap();
+
+    assert!(secret_keys.decode::<TokenClaims>(&token).is_ok());
+
+    let keys = JwtKeys::new(&auth_config(Some("2025-07"), vec![ed25519_key()])).unwrap();
+    assert!(keys.decode::<TokenClaims>(&token).is_err());
+}
+
+#[test]
+fn test_jwks_publishes_public_keys() {
+    let keys = JwtKeys::new(&auth_config(
+        Some("2025-07"),
+        vec![ed25519_key(), rsa_key()],
+    ))
+    .unwrap();
+    let jwks = serde_json::to_value(keys.jwks()).unwrap();
+
+    assert_eq!(jwks["keys"][0]["kid"], "2025-07");
+    assert_eq!(jwks["keys"][0]["kty"], "OKP");
+    assert_eq!(jwks["keys"][0]["crv"], "Ed25519");
+    assert_eq!(jwks["keys"][1]["kty"], "RSA");
+    assert_eq!(jwks["keys"][1]["alg"], "RS256");
+    assert_eq!(jwks["keys"][1]["e"], "AQAB");
+    assert!(jwks["keys"][1].get("d").is_none());
+}
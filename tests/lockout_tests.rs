@@ -0,0 +1,65 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use mycondominium_backend::internal::config::model::ConfigLockout;
+use mycondominium_backend::models::sign_in_attempt_model::AccountLockoutModel;
+use uuid::Uuid;
+
+fn now() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2025, 7, 11)
+        .unwrap()
+        .and_hms_opt(16, 0, 0)
+        .unwrap()
+}
+
+fn lockout() -> AccountLockoutModel {
+    AccountLockoutModel {
+        id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        failed_attempts: 0,
+        lockouts: 0,
+        locked_until: None,
+        updated_at: now(),
+    }
+}
+
+#[test]
+fn test_lockout_after_max_failed_attempts() {
+    let conf = ConfigLockout::default();
+    let mut lockout = lockout();
+
+    for _ in 1..conf.max_failed_attempts {
+        assert!(!lockout.register_failure(now(), &conf));
+        assert!(!lockout.is_locked(now()));
+    }
+
+    assert!(lockout.register_failure(now(), &conf));
+    assert!(lockout.is_locked(now()));
+    assert_eq!(lockout.failed_attempts, 0);
+    assert_eq!(
+        lockout.locked_until,
+        Some(now() + Duration::minutes(conf.lockout_minutes as i64))
+    );
+
+    // The lockout lifts by itself once its time has passed.
+    assert!(!lockout.is_locked(now() + Duration::minutes(conf.lockout_minutes as i64)));
+}
+
+#[test]
+fn test_lockout_is_progressive_and_capped() {
+    let conf = ConfigLockout {
+        max_failed_attempts: 1,
+        lockout_minutes: 15,
+        max_lockout_minutes: 100,
+        ..ConfigLockout::default()
+    };
+    let mut lockout = lockout();
+
+    let lengths: Vec<i64> = (0..5)
+        .map(|_| {
+            assert!(lockout.register_failure(now(), &conf));
+            (lockout.locked_until.unwrap() - now()).num_minutes()
+        })
+        .collect();
+
+    assert_eq!(lengths, vec![15, 30, 60, 100, 100]);
+    assert_eq!(lockout.lockouts, 5);
+}
@@ -0,0 +1,37 @@
+use mycondominium_backend::internal::config::model::ConfigMagicLink;
+use mycondominium_backend::internal::smtp::smtp_templates::{
+    SmtpTemplate, SmtpTemplateData, smtp_get_template,
+};
+
+fn parameter(key: &str, value: &str) -> SmtpTemplateData {
+    SmtpTemplateData {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn test_magic_link_disabled_by_default() {
+    assert!(!ConfigMagicLink::default().enabled);
+}
+
+#[test]
+fn test_magic_link_template() {
+    let body = smtp_get_template(
+        SmtpTemplate::MagicLink,
+        vec![
+            parameter("{{USER_NAME}}", "Maria"),
+            parameter(
+                "{{LOGIN_LINK}}",
+                "https://domain.com/redirect/onboarding/magic_link/abc",
+            ),
+            parameter("{{EXPIRATION_MINUTES}}", "15"),
+            parameter("{{CURRENT_YEAR}}", "2025"),
+        ],
+    );
+
+    assert!(body.contains("Olá Maria"));
+    assert!(body.contains("href=\"https://domain.com/redirect/onboarding/magic_link/abc\""));
+    assert!(body.contains("válido por 15 minutos"));
+    assert!(!body.contains("{{"));
+}
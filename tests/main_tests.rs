@@ -4,6 +4,7 @@ use diesel_migrations::MigrationHarness;
 use mycondominium_backend::internal::scheduled_tasks::scheduled_tasks_service::scheduled_tasks_service;
 use mycondominium_backend::routes::routes::*;
 use mycondominium_backend::services::MyCondominiumConfig;
+use mycondominium_backend::utilities::jwt_utils::JwtKeys;
 use mycondominium_backend::{MIGRATIONS, establish_connection_pg};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
@@ -56,11 +57,19 @@ struct LoginRequest {
     password: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct TokenPair {
+    access_token: String,
+    access_token_expires_at: String,
+    refresh_token: String,
+    refresh_token_expires_at: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct LoginResponse {
     error: bool,
     message: String,
-    object: String,
+    object: TokenPair,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -171,7 +180,7 @@ async fn login(test_client: &TestClient, email: &str, password: &str) -> Option<
             .json()
             .await
             .expect("Failed to parse login response");
-        Some(login_res.object)
+        Some(login_res.object.access_token)
     } else {
         None
     }
@@ -840,6 +849,14 @@ async fn start_server() {
         }
     };
 
+    let jwt_keys = match JwtKeys::new(&conf.auth) {
+        Ok(keys) => web::Data::new(keys),
+        Err(e) => {
+            log::error!("Failed to load token signing keys: {}", e);
+            panic!("Failed to load token signing keys: {}", e);
+        }
+    };
+
     let conf: Arc<MyCondominiumConfig> = Arc::new(conf);
 
     let conn = &mut establish_connection_pg(&conf);
@@ -859,6 +876,7 @@ async fn start_server() {
         App::new()
             .wrap(Logger::default())
             .app_data(web::Data::new(conf_clone.clone()))
+            .app_data(jwt_keys.clone())
             .service(resident_route())
             .service(admin_route())
             .service(auth_route())
@@ -0,0 +1,78 @@
+use chrono::NaiveDate;
+use mycondominium_backend::models::maintenance_plan_model::{
+    MaintenancePlanFrequency, MaintenancePlanModel,
+};
+
+fn plan(frequency: MaintenancePlanFrequency, frequency_interval: i32) -> MaintenancePlanModel {
+    let start_date = NaiveDate::from_ymd_opt(2025, 1, 31)
+        .unwrap()
+        .and_hms_opt(9, 0, 0)
+        .unwrap();
+
+    MaintenancePlanModel {
+        id: uuid::Uuid::new_v4(),
+        community_id: uuid::Uuid::new_v4(),
+        description: "Elevator inspection".to_string(),
+        details: None,
+        frequency,
+        frequency_interval,
+        start_date,
+        end_date: None,
+        active: true,
+        occurrence_count: 0,
+        created_at: start_date,
+        updated_at: start_date,
+        vendor_id: None,
+    }
+}
+
+#[test]
+fn test_monthly_occurrences_do_not_drift() {
+    let plan = plan(MaintenancePlanFrequency::Monthly, 1);
+
+    assert_eq!(
+        plan.occurrence(1).unwrap().date(),
+        NaiveDate::from_ymd_opt(2025, 2, 28).unwrap()
+    );
+    assert_eq!(
+        plan.occurrence(2).unwrap().date(),
+        NaiveDate::from_ymd_opt(2025, 3, 31).unwrap()
+    );
+}
+
+#[test]
+fn test_interval_and_yearly_occurrences() {
+    let quarterly = plan(MaintenancePlanFrequency::Monthly, 3);
+    let yearly = plan(MaintenancePlanFrequency::Yearly, 1);
+    let weekly = plan(MaintenancePlanFrequency::Weekly, 2);
+
+    assert_eq!(
+        quarterly.occurrence(1).unwrap().date(),
+        NaiveDate::from_ymd_opt(2025, 4, 30).unwrap()
+    );
+    assert_eq!(
+        yearly.occurrence(1).unwrap().date(),
+        NaiveDate::from_ymd_opt(2026, 1, 31).unwrap()
+    );
+    assert_eq!(
+        weekly.occurrence(1).unwrap().date(),
+        NaiveDate::from_ymd_opt(2025, 2, 14).unwrap()
+    );
+}
+
+#[test]
+fn test_next_occurrence_respects_end_date_and_skip() {
+    let mut plan = plan(MaintenancePlanFrequency::Monthly, 1);
+    plan.end_date = plan.occurrence(2);
+
+    plan.skip_until(plan.occurrence(1).unwrap());
+    assert_eq!(plan.occurrence_count, 2);
+    assert_eq!(plan.next_occurrence(), plan.occurrence(2));
+
+    plan.occurrence_count = 3;
+    assert_eq!(plan.next_occurrence(), None);
+
+    plan.occurrence_count = 0;
+    plan.active = false;
+    assert_eq!(plan.next_occurrence(), None);
+}
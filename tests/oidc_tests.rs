@@ -0,0 +1,278 @@
+use actix_web::{App, HttpResponse, HttpServer, web};
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use mycondominium_backend::internal::config::model::{ConfigOidcProvider, ConfigSigningAlgorithm};
+use mycondominium_backend::utilities::jwt_utils::public_key_to_jwk;
+use mycondominium_backend::utilities::oidc_utils::{
+    authorization_url, discover, exchange_code, fetch_jwks, pkce_challenge, signing_keys,
+    validate_id_token,
+};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const CLIENT_ID: &str = "mycondominium";
+const KID: &str = "mock-key";
+
+#[derive(Serialize)]
+struct IdTokenClaims {
+    iss: String,
+    sub: String,
+    aud: String,
+    exp: i64,
+    nonce: String,
+    email: String,
+    email_verified: bool,
+}
+
+/// Provider that signs in the same user for any code whose PKCE verifier matches.
+struct MockProvider {
+    issuer: String,
+    /// Code challenge and nonce of the pending authorization, keyed by code.
+    pending: std::sync::Mutex<HashMap<String, (String, String)>>,
+    /// Requests for the discovery document and the key set.
+    metadata_requests: AtomicUsize,
+    jwks_requests: AtomicUsize,
+}
+
+fn sign_id_token(claims: &IdTokenClaims) -> String {
+    let mut header = Header::new(Algorithm::EdDSA);
+    header.kid = Some(KID.to_string());
+    let key = EncodingKey::from_ed_pem(include_bytes!("keys/ed25519.pem")).unwrap();
+
+    jsonwebtoken::encode(&header, claims, &key).unwrap()
+}
+
+fn id_token_claims(issuer: &str, nonce: &str) -> IdTokenClaims {
+    IdTokenClaims {
+        iss: issuer.to_string(),
+        sub: "248289761001".to_string(),
+        aud: CLIENT_ID.to_string(),
+        exp: chrono::Utc::now().timestamp() + 300,
+        nonce: nonce.to_string(),
+        email: "maria@example.com".to_string(),
+        email_verified: true,
+    }
+}
+
+fn jwks() -> JwkSet {
+    JwkSet {
+        keys: vec![
+            public_key_to_jwk(
+                KID,
+                ConfigSigningAlgorithm::EdDSA,
+                include_bytes!("keys/ed25519.pub.pem"),
+            )
+            .unwrap(),
+        ],
+    }
+}
+
+async fn metadata(provider: web::Data<MockProvider>) -> HttpResponse {
+    provider.metadata_requests.fetch_add(1, Ordering::SeqCst);
+    HttpResponse::Ok().json(serde_json::json!({
+        "issuer": provider.issuer,
+        "authorization_endpoint": format!("{}/authorize", provider.issuer),
+        "token_endpoint": format!("{}/token", provider.issuer),
+        "jwks_uri": format!("{}/jwks", provider.issuer),
+    }))
+}
+
+async fn keys(provider: web::Data<MockProvider>) -> HttpResponse {
+    provider.jwks_requests.fetch_add(1, Ordering::SeqCst);
+    HttpResponse::Ok().json(jwks())
+}
+
+async fn token(
+    provider: web::Data<MockProvider>,
+    form: web::Form<HashMap<String, String>>,
+) -> HttpResponse {
+    let pending = provider
+        .pending
+        .lock()
+        .unwrap()
+        .remove(&form["code"])
+        .filter(|(challenge, _)| *challenge == pkce_challenge(&form["code_verifier"]));
+
+    match pending {
+        Some((_, nonce)) if form["client_id"] == CLIENT_ID => {
+            HttpResponse::Ok().json(serde_json::json!({
+                "access_token": "mock-access-token",
+                "token_type": "Bearer",
+                "id_token": sign_id_token(&id_token_claims(&provider.issuer, &nonce)),
+            }))
+        }
+        _ => HttpResponse::BadRequest().json(serde_json::json!({ "error": "invalid_grant" })),
+    }
+}
+
+async fn start_mock_provider() -> web::Data<MockProvider> {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let provider = web::Data::new(MockProvider {
+        issuer: format!("http://{}", listener.local_addr().unwrap()),
+        pending: std::sync::Mutex::new(HashMap::new()),
+        metadata_requests: AtomicUsize::new(0),
+        jwks_requests: AtomicUsize::new(0),
+    });
+
+    let data = provider.clone();
+    let server = HttpServer::new(move || {
+        App::new()
+            .app_data(data.clone())
+            .route("/.well-known/openid-configuration", web::get().to(metadata))
+            .route("/jwks", web::get().to(keys))
+            .route("/token", web::post().to(token))
+    })
+    .workers(1)
+    .listen(listener)
+    .unwrap()
+    .run();
+    actix_web::rt::spawn(server);
+
+    provider
+}
+
+fn provider_config(issuer: &str) -> ConfigOidcProvider {
+    ConfigOidcProvider {
+        name: "mock".to_string(),
+        issuer: issuer.to_string(),
+        client_id: CLIENT_ID.to_string(),
+        client_secret: None,
+        redirect_uri: "https://domain.com/redirect/oidc/mock".to_string(),
+        scopes: vec!["openid".to_string(), "email".to_string()],
+    }
+}
+
+#[test]
+fn test_pkce_challenge_rfc_vector() {
+    // RFC 7636, appendix B.
+    assert_eq!(
+        pkce_challenge("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+        "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+    );
+}
+
+#[actix_web::test]
+async fn test_authorization_code_flow_with_mock_provider() {
+    let provider = start_mock_provider().await;
+    let config = provider_config(&provider.issuer);
+
+    let metadata = discover(&provider.issuer).await.unwrap();
+    let url = authorization_url(&metadata, &config, "state-1", "nonce-1", "verifier-1").unwrap();
+    let query: HashMap<String, String> = reqwest::Url::parse(&url)
+        .unwrap()
+        .query_pairs()
+        .into_owned()
+        .collect();
+
+    assert!(url.starts_with(&format!("{}/authorize?", provider.issuer)));
+    assert_eq!(query["client_id"], CLIENT_ID);
+    assert_eq!(query["scope"], "openid email");
+    assert_eq!(query["state"], "state-1");
+    assert_eq!(query["code_challenge_method"], "S256");
+
+    // The provider redirects back with a code once the user signs in.
+    provider.pending.lock().unwrap().insert(
+        "code-1".to_string(),
+        (query["code_challenge"].clone(), query["nonce"].clone()),
+    );
+
+    let id_token = exchange_code(&metadata, &config, "code-1", "verifier-1")
+        .await
+        .unwrap();
+    let jwks = fetch_jwks(&metadata).await.unwrap();
+    let claims =
+        validate_id_token(&id_token, &jwks, &metadata.issuer, CLIENT_ID, "nonce-1").unwrap();
+
+    assert_eq!(claims.sub, "248289761001");
+    assert_eq!(claims.email.as_deref(), Some("maria@example.com"));
+    assert!(claims.email_verified);
+
+    // Codes are single use.
+    assert!(
+        exchange_code(&metadata, &config, "code-1", "verifier-1")
+            .await
+            .is_err()
+    );
+}
+
+#[actix_web::test]
+async fn test_code_exchange_requires_pkce_verifier() {
+    let provider = start_mock_provider().await;
+    let config = provider_config(&provider.issuer);
+    let metadata = discover(&provider.issuer).await.unwrap();
+
+    provider.pending.lock().unwrap().insert(
+        "code-2".to_string(),
+        (pkce_challenge("verifier-2"), "nonce-2".to_string()),
+    );
+
+    assert!(
+        exchange_code(&metadata, &config, "code-2", "another-verifier")
+            .await
+            .is_err()
+    );
+}
+
+#[actix_web::test]
+async fn test_provider_documents_are_cached() {
+    let provider = start_mock_provider().await;
+
+    let metadata = discover(&provider.issuer).await.unwrap();
+    discover(&provider.issuer).await.unwrap();
+    assert_eq!(provider.metadata_requests.load(Ordering::SeqCst), 1);
+
+    let id_token = sign_id_token(&id_token_claims(&provider.issuer, "nonce"));
+    signing_keys(&metadata, &id_token).await.unwrap();
+    signing_keys(&metadata, &id_token).await.unwrap();
+    assert_eq!(provider.jwks_requests.load(Ordering::SeqCst), 1);
+
+    // A key the cached set doesn't have yet means the provider rotated its keys.
+    let mut header = Header::new(Algorithm::EdDSA);
+    header.kid = Some("rotated-key".to_string());
+    let rotated = jsonwebtoken::encode(
+        &header,
+        &id_token_claims(&provider.issuer, "nonce"),
+        &EncodingKey::from_ed_pem(include_bytes!("keys/ed25519.pem")).unwrap(),
+    )
+    .unwrap();
+    signing_keys(&metadata, &rotated).await.unwrap();
+    assert_eq!(provider.jwks_requests.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_validate_id_token_rejects_invalid_tokens() {
+    let issuer = "https://issuer.example.com";
+    let jwks = jwks();
+    let valid = sign_id_token(&id_token_claims(issuer, "nonce"));
+
+    assert!(validate_id_token(&valid, &jwks, issuer, CLIENT_ID, "nonce").is_ok());
+    assert!(validate_id_token(&valid, &jwks, issuer, CLIENT_ID, "other-nonce").is_err());
+    assert!(validate_id_token(&valid, &jwks, issuer, "other-client", "nonce").is_err());
+    assert!(
+        validate_id_token(
+            &valid,
+            &jwks,
+            "https://evil.example.com",
+            CLIENT_ID,
+            "nonce"
+        )
+        .is_err()
+    );
+
+    let mut expired = id_token_claims(issuer, "nonce");
+    expired.exp = chrono::Utc::now().timestamp() - 3600;
+    assert!(
+        validate_id_token(&sign_id_token(&expired), &jwks, issuer, CLIENT_ID, "nonce").is_err()
+    );
+
+    let mut header = Header::new(Algorithm::HS256);
+    header.kid = Some(KID.to_string());
+    let forged = jsonwebtoken::encode(
+        &header,
+        &id_token_claims(issuer, "nonce"),
+        &EncodingKey::from_secret(b"secret"),
+    )
+    .unwrap();
+    assert!(validate_id_token(&forged, &jwks, issuer, CLIENT_ID, "nonce").is_err());
+}
@@ -0,0 +1,75 @@
+use mycondominium_backend::internal::config::model::ConfigPasswordPolicy;
+use mycondominium_backend::utilities::auth_utils::{generate_password, hash_password};
+use mycondominium_backend::utilities::password_utils::{
+    check_password_policy, check_password_reuse, is_common_password,
+};
+
+const PERSONAL_INFO: [&str; 3] = ["Maria", "Oliveira", "maria.oliveira@example.com"];
+
+#[test]
+fn test_password_policy_length() {
+    let policy = ConfigPasswordPolicy::default();
+
+    assert!(check_password_policy("Xk7#pQ", &policy, &[]).is_err());
+    assert!(check_password_policy("Xk7#pQ2v", &policy, &[]).is_ok());
+    assert!(check_password_policy(&"Xk7#pQ2v".repeat(20), &policy, &[]).is_err());
+}
+
+#[test]
+fn test_password_policy_character_classes() {
+    let policy = ConfigPasswordPolicy {
+        require_lowercase: true,
+        require_uppercase: true,
+        require_digit: true,
+        require_symbol: true,
+        ..ConfigPasswordPolicy::default()
+    };
+
+    assert!(check_password_policy("xk7#pq2vz", &policy, &[]).is_err());
+    assert!(check_password_policy("XK7#PQ2VZ", &policy, &[]).is_err());
+    assert!(check_password_policy("Xk#pQvZrt", &policy, &[]).is_err());
+    assert!(check_password_policy("Xk7pQ2vZr", &policy, &[]).is_err());
+    assert!(check_password_policy("Xk7#pQ2vZ", &policy, &[]).is_ok());
+
+    // Generated passwords have to pass whatever classes are required.
+    for _ in 0..50 {
+        assert!(check_password_policy(&generate_password(), &policy, &PERSONAL_INFO).is_ok());
+    }
+}
+
+#[test]
+fn test_password_policy_personal_info() {
+    let policy = ConfigPasswordPolicy::default();
+
+    assert!(check_password_policy("MARIA-2025!", &policy, &PERSONAL_INFO).is_err());
+    assert!(check_password_policy("x-oliveira-x", &policy, &PERSONAL_INFO).is_err());
+    assert!(check_password_policy("1maria.oliveira1", &policy, &PERSONAL_INFO).is_err());
+    assert!(check_password_policy("Xk7#pQ2vZ", &policy, &PERSONAL_INFO).is_ok());
+
+    let policy = ConfigPasswordPolicy {
+        reject_personal_info: false,
+        ..policy
+    };
+    assert!(check_password_policy("MARIA-2025!", &policy, &PERSONAL_INFO).is_ok());
+}
+
+#[test]
+fn test_password_policy_common_passwords() {
+    let policy = ConfigPasswordPolicy::default();
+
+    assert!(is_common_password("Password123"));
+    assert!(is_common_password("senha123"));
+    assert!(!is_common_password("Xk7#pQ2vZ"));
+    assert!(check_password_policy("Qwerty123", &policy, &[]).is_err());
+}
+
+#[test]
+fn test_password_reuse() {
+    let previous = vec![
+        hash_password("Xk7#pQ2vZ".to_string()).unwrap(),
+        "not a hash".to_string(),
+    ];
+
+    assert!(check_password_reuse("Xk7#pQ2vZ", &previous).is_err());
+    assert!(check_password_reuse("Yk8$pQ3wZ", &previous).is_ok());
+}
@@ -0,0 +1,23 @@
+use mycondominium_backend::utilities::plate_utils::*;
+
+#[test]
+fn test_normalize_plate() {
+    assert_eq!(normalize_plate("abc-1234"), Some("ABC1234".to_string()));
+    assert_eq!(normalize_plate(" bra 2e19 "), Some("BRA2E19".to_string()));
+    assert_eq!(normalize_plate("ABC1K34"), None);
+    assert_eq!(normalize_plate("AB12345"), None);
+    assert_eq!(normalize_plate("ABC123"), None);
+}
+
+#[test]
+fn test_equivalent_plates() {
+    assert_eq!(
+        equivalent_plates("ABC-1234"),
+        vec!["ABC1234".to_string(), "ABC1C34".to_string()]
+    );
+    assert_eq!(
+        equivalent_plates("bra2e19"),
+        vec!["BRA2E19".to_string(), "BRA2419".to_string()]
+    );
+    assert!(equivalent_plates("invalid").is_empty());
+}
@@ -0,0 +1,50 @@
+use chrono::NaiveDate;
+use mycondominium_backend::models::resident_model::ResidentImportRow;
+use mycondominium_backend::utilities::import_utils::{parse_date, read_spreadsheet};
+use uuid::Uuid;
+
+const CSV: &str = "First Name;Last Name;Email;Phone;Date of Birth
+Maria;Silva;MARIA@example.com;11999990000;15/03/1980
+;;;;
+João;;joao.example.com;;1990-13-01
+";
+
+#[test]
+fn test_read_semicolon_csv() {
+    let rows =
+        ResidentImportRow::from_table(read_spreadsheet("residents.CSV", CSV.as_bytes()).unwrap())
+            .unwrap();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].row, 2);
+    assert_eq!(rows[0].email, "maria@example.com");
+    assert_eq!(rows[0].phone.as_deref(), Some("11999990000"));
+    assert_eq!(rows[1].row, 4);
+    assert_eq!(rows[1].address, None);
+}
+
+#[test]
+fn test_row_validation() {
+    let rows =
+        ResidentImportRow::from_table(read_spreadsheet("residents.csv", CSV.as_bytes()).unwrap())
+            .unwrap();
+    let community_id = Uuid::new_v4();
+
+    let resident = rows[0]
+        .to_resident_new(community_id, "generated-password".to_string())
+        .unwrap();
+    assert_eq!(resident.date_of_birth, NaiveDate::from_ymd_opt(1980, 3, 15));
+    assert_eq!(resident.community_id, Some(community_id));
+
+    let errors = rows[1]
+        .to_resident_new(community_id, "generated-password".to_string())
+        .unwrap_err();
+    assert_eq!(errors.len(), 3);
+}
+
+#[test]
+fn test_rejects_unknown_files() {
+    assert!(read_spreadsheet("residents.txt", b"email").is_err());
+    assert!(ResidentImportRow::from_table(vec![vec!["email".to_string()]]).is_err());
+    assert_eq!(parse_date("2024-02-30"), None);
+}
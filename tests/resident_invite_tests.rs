@@ -0,0 +1,62 @@
+use chrono::{Duration, NaiveDate};
+use diesel::prelude::*;
+use mycondominium_backend::models::resident_model::{ResidentInviteModel, ResidentInviteStatus};
+use mycondominium_backend::schema::resident_invites;
+use uuid::Uuid;
+
+fn invite(status: ResidentInviteStatus) -> ResidentInviteModel {
+    let created_at = NaiveDate::from_ymd_opt(2025, 7, 1)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+
+    ResidentInviteModel {
+        id: Uuid::new_v4(),
+        email: "resident@example.com".to_string(),
+        community_id: Uuid::new_v4(),
+        key: "key".to_string(),
+        created_at,
+        status,
+        expires_at: created_at + Duration::days(7),
+        opened_at: None,
+        accepted_at: None,
+    }
+}
+
+#[test]
+fn test_invite_pending_until_expiry() {
+    let sent = invite(ResidentInviteStatus::Sent);
+
+    assert!(sent.is_pending(sent.created_at + Duration::days(6)));
+    assert!(!sent.is_pending(sent.expires_at));
+    assert!(invite(ResidentInviteStatus::Opened).is_pending(sent.created_at));
+}
+
+#[test]
+fn test_invite_not_pending_once_closed() {
+    let accepted = invite(ResidentInviteStatus::Accepted);
+    let expired = invite(ResidentInviteStatus::Expired);
+
+    assert!(!accepted.is_pending(accepted.created_at));
+    assert!(!expired.is_pending(expired.created_at));
+}
+
+#[test]
+fn test_pending_and_overdue_filters() {
+    let now = invite(ResidentInviteStatus::Sent).created_at;
+
+    let pending = diesel::debug_query::<diesel::pg::Pg, _>(
+        &resident_invites::table.filter(ResidentInviteModel::pending(now)),
+    )
+    .to_string();
+    assert!(pending.contains(r#""resident_invites"."status" = ANY($1)"#));
+    assert!(pending.contains(r#""resident_invites"."expires_at" > $2"#));
+    assert!(pending.contains("[Sent, Opened]"));
+
+    let overdue = diesel::debug_query::<diesel::pg::Pg, _>(
+        &resident_invites::table.filter(ResidentInviteModel::overdue(now)),
+    )
+    .to_string();
+    assert!(overdue.contains(r#""resident_invites"."status" = ANY($1)"#));
+    assert!(overdue.contains(r#""resident_invites"."expires_at" <= $2"#));
+}
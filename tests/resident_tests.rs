@@ -0,0 +1,47 @@
+use chrono::NaiveDate;
+use mycondominium_backend::models::resident_model::ResidentModel;
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+}
+
+fn resident(moved_in_at: Option<NaiveDate>, moved_out_at: Option<NaiveDate>) -> ResidentModel {
+    let now = date(1, 1).and_hms_opt(0, 0, 0).unwrap();
+
+    ResidentModel {
+        id: uuid::Uuid::new_v4(),
+        first_name: "Jane".to_string(),
+        last_name: "Doe".to_string(),
+        address: None,
+        phone: None,
+        email: "jane@example.com".to_string(),
+        date_of_birth: None,
+        is_active: moved_out_at.is_none(),
+        created_at: now,
+        updated_at: now,
+        moved_in_at,
+        moved_out_at,
+    }
+}
+
+#[test]
+fn test_move_out_date() {
+    let res = resident(Some(date(3, 1)), None);
+    let today = date(6, 15);
+
+    assert!(res.check_move_out_date(date(6, 15), today).is_ok());
+    assert!(res.check_move_out_date(date(3, 1), today).is_ok());
+    assert!(res.check_move_out_date(date(6, 16), today).is_err());
+    assert!(res.check_move_out_date(date(2, 28), today).is_err());
+}
+
+#[test]
+fn test_move_in_date() {
+    let res = resident(Some(date(3, 1)), Some(date(5, 1)));
+    let today = date(6, 15);
+
+    assert!(res.check_move_in_date(date(5, 1), today).is_ok());
+    assert!(res.check_move_in_date(date(6, 15), today).is_ok());
+    assert!(res.check_move_in_date(date(6, 16), today).is_err());
+    assert!(res.check_move_in_date(date(4, 30), today).is_err());
+}
@@ -0,0 +1,35 @@
+use chrono::NaiveDate;
+use mycondominium_backend::models::auth_token_model::AuthTokenModel;
+use uuid::Uuid;
+
+fn session() -> AuthTokenModel {
+    let time_added = NaiveDate::from_ymd_opt(2025, 7, 8)
+        .unwrap()
+        .and_hms_opt(10, 0, 0)
+        .unwrap();
+
+    AuthTokenModel {
+        user_id: Uuid::new_v4(),
+        id: Uuid::new_v4(),
+        time_added,
+        active: true,
+        time_last_used: time_added,
+        device: Some("iPhone".to_string()),
+        browser: Some("Mobile Safari".to_string()),
+        version: Some("18".to_string()),
+        cpu_arch: None,
+    }
+}
+
+#[test]
+fn test_session_result_flags_current_session() {
+    let current = session();
+    let other = session();
+
+    assert!(current.to_session_result(current.id).current);
+    assert!(!other.to_session_result(current.id).current);
+    assert_eq!(
+        other.to_session_result(current.id).device.as_deref(),
+        Some("iPhone")
+    );
+}
@@ -0,0 +1,83 @@
+use chrono::{Duration, NaiveDate};
+use mycondominium_backend::models::two_factor_model::{AuthChallengeModel, CHALLENGE_MAX_ATTEMPTS};
+use mycondominium_backend::utilities::totp_utils::{
+    RECOVERY_CODE_COUNT, generate_recovery_codes, generate_totp_secret, normalize_recovery_code,
+    totp_provisioning_uri, verify_totp_code,
+};
+use totp_rs::{Algorithm, Secret, TOTP};
+use uuid::Uuid;
+
+// Secret from the RFC 6238 test vectors ("12345678901234567890").
+const SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+#[test]
+fn test_verify_totp_code_rfc_vector() {
+    // RFC 6238 expects 94287082 at T = 59 for SHA1, of which the last six digits are used.
+    assert_eq!(verify_totp_code(SECRET, "287082", 59, None), Some(1));
+    assert_eq!(verify_totp_code(SECRET, "287083", 59, None), None);
+}
+
+#[test]
+fn test_verify_totp_code_rejects_replay() {
+    let step = verify_totp_code(SECRET, "287082", 59, None).unwrap();
+
+    assert_eq!(verify_totp_code(SECRET, "287082", 59, Some(step)), None);
+}
+
+#[test]
+fn test_generated_secret_round_trip() {
+    let secret = generate_totp_secret();
+    let totp = TOTP::new(
+        Algorithm::SHA1,
+        6,
+        1,
+        30,
+        Secret::Encoded(secret.clone()).to_bytes().unwrap(),
+        None,
+        String::new(),
+    )
+    .unwrap();
+    let time = 1_750_000_000;
+
+    assert!(verify_totp_code(&secret, &totp.generate(time), time, None).is_some());
+
+    let uri = totp_provisioning_uri(&secret, "admin@example.com").unwrap();
+    assert!(uri.starts_with("otpauth://totp/MyCondominium:admin%40example.com?"));
+    assert!(uri.contains(&format!("secret={}", secret)));
+}
+
+#[test]
+fn test_recovery_codes_format() {
+    let codes = generate_recovery_codes();
+
+    assert_eq!(codes.len(), RECOVERY_CODE_COUNT);
+    assert!(
+        codes
+            .iter()
+            .all(|code| code.len() == 11 && &code[5..6] == "-")
+    );
+    assert_eq!(normalize_recovery_code(" abcde-fghjk "), "ABCDEFGHJK");
+}
+
+#[test]
+fn test_challenge_validity() {
+    let created_at = NaiveDate::from_ymd_opt(2025, 7, 7)
+        .unwrap()
+        .and_hms_opt(9, 0, 0)
+        .unwrap();
+    let mut challenge = AuthChallengeModel {
+        id: Uuid::new_v4(),
+        user_id: Uuid::new_v4(),
+        token_hash: String::new(),
+        enrolment_required: false,
+        attempts: 0,
+        created_at,
+        expires_at: created_at + Duration::minutes(5),
+    };
+
+    assert!(challenge.is_valid(created_at + Duration::minutes(4)));
+    assert!(!challenge.is_valid(challenge.expires_at));
+
+    challenge.attempts = CHALLENGE_MAX_ATTEMPTS;
+    assert!(!challenge.is_valid(created_at));
+}
@@ -0,0 +1,89 @@
+use chrono::NaiveDate;
+use mycondominium_backend::models::invoice_model::InvoiceChargeType;
+use mycondominium_backend::models::unit_model::{
+    UnitRelation, UnitResidentModel, UnitResidentModelEdit,
+};
+use uuid::Uuid;
+
+fn link(
+    relation: UnitRelation,
+    start_date: NaiveDate,
+    end_date: Option<NaiveDate>,
+    voting_proxy: bool,
+) -> UnitResidentModel {
+    UnitResidentModel {
+        id: Uuid::new_v4(),
+        unit_id: Uuid::nil(),
+        resident_id: Uuid::new_v4(),
+        relation,
+        created_at: start_date.and_hms_opt(0, 0, 0).unwrap(),
+        start_date,
+        end_date,
+        voting_proxy,
+    }
+}
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+}
+
+#[test]
+fn test_charge_responsibility_follows_tenancy() {
+    let owner = link(UnitRelation::Owner, date(1, 1), None, false);
+    let tenant = link(UnitRelation::Tenant, date(3, 1), Some(date(8, 31)), false);
+    let links = vec![owner.clone(), tenant.clone()];
+
+    let responsible = |charge_type, on| UnitResidentModel::responsible_for(&links, charge_type, on);
+
+    assert_eq!(
+        responsible(InvoiceChargeType::Ordinary, date(2, 10)),
+        Some(owner.resident_id)
+    );
+    assert_eq!(
+        responsible(InvoiceChargeType::Ordinary, date(5, 10)),
+        Some(tenant.resident_id)
+    );
+    assert_eq!(
+        responsible(InvoiceChargeType::Extraordinary, date(5, 10)),
+        Some(owner.resident_id)
+    );
+    assert_eq!(
+        responsible(InvoiceChargeType::Ordinary, date(9, 10)),
+        Some(owner.resident_id)
+    );
+}
+
+#[test]
+fn test_voter_is_proxy_tenant_or_owner() {
+    let owner = link(UnitRelation::Owner, date(1, 1), None, false);
+    let tenant = link(UnitRelation::Tenant, date(1, 1), None, false);
+    let proxy = link(UnitRelation::Tenant, date(1, 1), None, true);
+
+    assert_eq!(
+        UnitResidentModel::voter_for(&[owner.clone(), tenant], date(6, 1)),
+        Some(owner.resident_id)
+    );
+    assert_eq!(
+        UnitResidentModel::voter_for(&[owner, proxy.clone()], date(6, 1)),
+        Some(proxy.resident_id)
+    );
+}
+
+#[test]
+fn test_link_validity() {
+    assert!(link(UnitRelation::Tenant, date(1, 1), Some(date(12, 31)), true).is_valid());
+    assert!(!link(UnitRelation::Owner, date(1, 1), None, true).is_valid());
+    assert!(!link(UnitRelation::Tenant, date(6, 1), Some(date(5, 31)), false).is_valid());
+}
+
+#[test]
+fn test_link_edit_can_reopen_period() {
+    let edit = |json: &str| serde_json::from_str::<UnitResidentModelEdit>(json).unwrap();
+
+    assert_eq!(edit("{}").end_date, None);
+    assert_eq!(edit(r#"{"end_date": null}"#).end_date, Some(None));
+    assert_eq!(
+        edit(r#"{"end_date": "2025-12-31"}"#).end_date,
+        Some(Some(date(12, 31)))
+    );
+}
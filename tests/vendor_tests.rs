@@ -0,0 +1,25 @@
+use mycondominium_backend::models::vendor_model::{
+    VENDOR_MAX_EXPIRING_WITHIN_DAYS, VendorFilterParams,
+};
+use validator::Validate;
+
+fn filters(expiring_within_days: Option<i64>) -> VendorFilterParams {
+    VendorFilterParams {
+        service_type: None,
+        expiring_within_days,
+    }
+}
+
+#[test]
+fn test_expiring_within_days_is_bounded() {
+    assert!(filters(None).validate().is_ok());
+    assert!(filters(Some(30)).validate().is_ok());
+    assert!(
+        filters(Some(VENDOR_MAX_EXPIRING_WITHIN_DAYS))
+            .validate()
+            .is_ok()
+    );
+
+    assert!(filters(Some(-1)).validate().is_err());
+    assert!(filters(Some(i64::MAX)).validate().is_err());
+}